@@ -0,0 +1,278 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UnlockedAchievements>()
+            .add_message::<AchievementUnlocked>()
+            .add_systems(Startup, load_unlocked)
+            .add_systems(
+                Update,
+                (
+                    evaluate_achievements
+                        .run_if(on_message::<ScoreChanged>.or(state_changed::<GameState>)),
+                    save_unlocked,
+                    spawn_achievement_toasts,
+                    fade_achievement_toasts,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Achievement {
+    NoDeaths,
+    Combo10,
+    FastClear,
+}
+
+impl Achievement {
+    const ALL: [Achievement; 3] = [
+        Achievement::NoDeaths,
+        Achievement::Combo10,
+        Achievement::FastClear,
+    ];
+
+    /// Returns the name shown in the unlock toast.
+    fn name(self) -> &'static str {
+        match self {
+            Achievement::NoDeaths => "No Deaths",
+            Achievement::Combo10 => "10x Combo",
+            Achievement::FastClear => "Clear in under 60s",
+        }
+    }
+
+    /// Returns the stable identifier written to the save file.
+    fn key(self) -> &'static str {
+        match self {
+            Achievement::NoDeaths => "no_deaths",
+            Achievement::Combo10 => "combo_10",
+            Achievement::FastClear => "fast_clear",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Achievement> {
+        Achievement::ALL
+            .into_iter()
+            .find(|achievement| achievement.key() == key)
+    }
+
+    /// Returns true if the run described by `stats` earns this achievement.
+    fn is_earned(self, stats: &GameStats) -> bool {
+        match self {
+            Achievement::NoDeaths => stats.cleared && stats.balls_lost == 0,
+            Achievement::Combo10 => stats.max_combo >= ACHIEVEMENT_COMBO,
+            Achievement::FastClear => stats.cleared && stats.playtime < ACHIEVEMENT_FAST_CLEAR_SECS,
+        }
+    }
+}
+
+/// Achievements earned in any run so far.
+#[derive(Resource, Default)]
+struct UnlockedAchievements {
+    unlocked: Vec<Achievement>,
+}
+
+/// Sent once when an achievement is earned for the first time.
+#[derive(Message)]
+struct AchievementUnlocked {
+    achievement: Achievement,
+}
+
+/// Toast announcing an unlocked achievement; fades via `FadeOut`.
+#[derive(Component)]
+struct AchievementToast;
+
+/// Parses one achievement key per line, skipping unknown keys.
+fn parse_unlocked(contents: &str) -> Vec<Achievement> {
+    contents
+        .lines()
+        .filter_map(|line| Achievement::from_key(line.trim()))
+        .collect()
+}
+
+/// Loads previously unlocked achievements at startup.
+fn load_unlocked(mut unlocked: ResMut<UnlockedAchievements>) {
+    if let Ok(contents) = std::fs::read_to_string(ACHIEVEMENTS_PATH) {
+        unlocked.unlocked = parse_unlocked(&contents);
+    }
+}
+
+/// Unlocks every achievement the current stats satisfy that was not unlocked before.
+fn evaluate_achievements(
+    stats: Res<GameStats>,
+    mut unlocked: ResMut<UnlockedAchievements>,
+    mut unlocks: MessageWriter<AchievementUnlocked>,
+) {
+    for achievement in Achievement::ALL {
+        if unlocked.unlocked.contains(&achievement) || !achievement.is_earned(&stats) {
+            continue;
+        }
+        unlocked.unlocked.push(achievement);
+        unlocks.write(AchievementUnlocked { achievement });
+    }
+}
+
+/// Writes the unlocked list to disk after each new unlock.
+fn save_unlocked(
+    unlocked: Res<UnlockedAchievements>,
+    mut unlocks: MessageReader<AchievementUnlocked>,
+) {
+    if unlocks.read().count() == 0 {
+        return;
+    }
+
+    let contents: String = unlocked
+        .unlocked
+        .iter()
+        .map(|achievement| format!("{}\n", achievement.key()))
+        .collect();
+    let path = std::path::Path::new(ACHIEVEMENTS_PATH);
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::write(path, contents));
+    if let Err(error) = result {
+        warn!("Could not save achievements: {error}");
+    }
+}
+
+/// Shows a toast near the top of the screen for each unlock.
+fn spawn_achievement_toasts(
+    mut commands: Commands,
+    mut unlocks: MessageReader<AchievementUnlocked>,
+) {
+    for (index, unlock) in unlocks.read().enumerate() {
+        commands.spawn((
+            Text::new(format!(
+                "Achievement unlocked: {}",
+                unlock.achievement.name()
+            )),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0 + index as f32 * 30.0),
+                right: Val::Px(20.0),
+                ..default()
+            },
+            FadeOut {
+                timer: Timer::from_seconds(ACHIEVEMENT_TOAST_DURATION, TimerMode::Once),
+            },
+            UI_Z_TOAST,
+            AchievementToast,
+        ));
+    }
+}
+
+/// Fades achievement toasts out and despawns them once their timer ends.
+fn fade_achievement_toasts(
+    ui_time: Res<UiTime>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut FadeOut, &mut TextColor), With<AchievementToast>>,
+) {
+    for (entity, mut fade, mut color) in &mut query {
+        fade.timer.tick(ui_time.delta);
+        if fade.timer.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            color.0.set_alpha(fade.timer.fraction_remaining());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cleared_run() -> GameStats {
+        GameStats {
+            cleared: true,
+            playtime: 90.0,
+            ..default()
+        }
+    }
+
+    // --- Achievement::is_earned ---
+
+    #[test]
+    fn no_deaths_needs_a_clear_without_lost_balls() {
+        assert!(Achievement::NoDeaths.is_earned(&cleared_run()));
+
+        let lost_one = GameStats {
+            balls_lost: 1,
+            ..cleared_run()
+        };
+        assert!(!Achievement::NoDeaths.is_earned(&lost_one));
+        assert!(!Achievement::NoDeaths.is_earned(&GameStats::default()));
+    }
+
+    #[test]
+    fn fast_clear_needs_a_clear_under_the_limit() {
+        assert!(!Achievement::FastClear.is_earned(&cleared_run()));
+
+        let fast = GameStats {
+            playtime: ACHIEVEMENT_FAST_CLEAR_SECS - 1.0,
+            ..cleared_run()
+        };
+        assert!(Achievement::FastClear.is_earned(&fast));
+
+        let unfinished = GameStats {
+            cleared: false,
+            ..fast
+        };
+        assert!(!Achievement::FastClear.is_earned(&unfinished));
+    }
+
+    #[test]
+    fn combo_achievement_triggers_at_threshold() {
+        let below = GameStats {
+            max_combo: ACHIEVEMENT_COMBO - 1,
+            ..default()
+        };
+        let at = GameStats {
+            max_combo: ACHIEVEMENT_COMBO,
+            ..default()
+        };
+        assert!(!Achievement::Combo10.is_earned(&below));
+        assert!(Achievement::Combo10.is_earned(&at));
+    }
+
+    // --- parse_unlocked ---
+
+    #[test]
+    fn parse_unlocked_skips_unknown_keys() {
+        let parsed = parse_unlocked("combo_10\nbogus\n fast_clear \n");
+        assert_eq!(parsed, vec![Achievement::Combo10, Achievement::FastClear]);
+    }
+
+    // --- evaluate_achievements ---
+
+    #[test]
+    fn achievements_unlock_only_once() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<UnlockedAchievements>();
+        app.add_message::<AchievementUnlocked>();
+        app.insert_resource(GameStats {
+            max_combo: ACHIEVEMENT_COMBO,
+            ..default()
+        });
+        app.add_systems(Update, evaluate_achievements);
+
+        app.update();
+        app.update();
+
+        let unlocked = &app.world().resource::<UnlockedAchievements>().unlocked;
+        assert_eq!(unlocked, &vec![Achievement::Combo10]);
+        let messages = app.world().resource::<Messages<AchievementUnlocked>>();
+        assert_eq!(messages.len(), 1);
+    }
+}
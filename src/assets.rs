@@ -0,0 +1,97 @@
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use bevy::shader::Shader;
+
+use crate::components::{BACKGROUND_SHADER_PATH, OPTIONAL_ASSET_DIR};
+
+pub struct AssetWatchPlugin;
+
+impl Plugin for AssetWatchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrackedAssets>()
+            .add_systems(Startup, track_assets)
+            .add_systems(Update, watch_asset_failures);
+    }
+}
+
+/// Handles whose load state is checked for failures.
+#[derive(Resource, Default)]
+struct TrackedAssets {
+    handles: Vec<UntypedHandle>,
+}
+
+/// Returns true if the game cannot run without the asset at `path`.
+fn is_required_asset(path: &str) -> bool {
+    !path.starts_with(OPTIONAL_ASSET_DIR)
+}
+
+/// Returns the exit to request when the asset at `path` fails to load, if any.
+fn failure_exit(path: &str) -> Option<AppExit> {
+    is_required_asset(path).then(AppExit::error)
+}
+
+/// Starts loading the assets the game depends on so their failures can be detected.
+fn track_assets(asset_server: Res<AssetServer>, mut tracked: ResMut<TrackedAssets>) {
+    tracked.handles.push(
+        asset_server
+            .load::<Shader>(BACKGROUND_SHADER_PATH)
+            .untyped(),
+    );
+}
+
+/// Exits with an error code when a required asset fails; optional failures only warn.
+fn watch_asset_failures(
+    asset_server: Res<AssetServer>,
+    mut tracked: ResMut<TrackedAssets>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    tracked.handles.retain(|handle| {
+        let Some(LoadState::Failed(error)) = asset_server.get_load_state(handle.id()) else {
+            return true;
+        };
+
+        let path = handle
+            .path()
+            .map(|path| path.to_string())
+            .unwrap_or_default();
+
+        if let Some(exit) = failure_exit(&path) {
+            error!("Required asset '{path}' failed to load: {error}");
+            app_exit.write(exit);
+        } else {
+            warn!("Optional asset '{path}' failed to load, continuing without it: {error}");
+        }
+
+        // Report each failure once
+        false
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shaders_and_levels_are_required() {
+        assert!(is_required_asset(BACKGROUND_SHADER_PATH));
+        assert!(is_required_asset("levels/level1.txt"));
+        assert!(is_required_asset("fonts/ui.ttf"));
+    }
+
+    #[test]
+    fn sounds_are_optional() {
+        assert!(!is_required_asset("sounds/bounce.ogg"));
+    }
+
+    #[test]
+    fn required_failure_exits_with_error() {
+        let exit = failure_exit(BACKGROUND_SHADER_PATH);
+        assert_eq!(exit, Some(AppExit::error()));
+        assert!(exit.is_some_and(|exit| exit.is_error()));
+    }
+
+    #[test]
+    fn optional_failure_does_not_exit() {
+        assert_eq!(failure_exit("sounds/brick.ogg"), None);
+    }
+}
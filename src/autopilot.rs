@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::game::{landing_bounds, predict_landing_x};
+use crate::{collision, movement};
+
+pub struct AutopilotPlugin;
+
+impl Plugin for AutopilotPlugin {
+    fn build(&self, app: &mut App) {
+        if !autopilot_requested(std::env::args()) {
+            return;
+        }
+        app.add_systems(
+            Update,
+            (
+                steer_autopilot_paddle
+                    .after(movement::move_paddle)
+                    .before(collision::ball_collision_walls_and_paddle),
+                serve_autopilot_ball.before(movement::launch_ball),
+            )
+                .run_if(in_state(GameState::Ready).or(in_state(GameState::Playing))),
+        );
+    }
+}
+
+/// Reads the `--autopilot` flag that hands the paddle to the benchmarking AI.
+fn autopilot_requested(args: impl IntoIterator<Item = String>) -> bool {
+    args.into_iter().any(|arg| arg == "--autopilot")
+}
+
+/// Returns the paddle x that sends a ball landing at `landing_x` toward `target`, given the
+/// paddle's bounce settings.
+fn aimed_paddle_x(landing_x: f32, target: Vec2, paddle_width: f32, max_angle: f32) -> f32 {
+    let angle = (target.x - landing_x).atan2(target.y - PADDLE_Y);
+    let hit_offset = (angle / max_angle).clamp(-AUTOPILOT_MAX_AIM, AUTOPILOT_MAX_AIM);
+    landing_x - hit_offset * paddle_width / 2.0
+}
+
+/// Teleports the paddle under the ball's predicted landing, angled toward the lowest brick.
+#[allow(clippy::type_complexity)]
+fn steer_autopilot_paddle(
+    config: Res<GameConfig>,
+    ball_query: Query<(&Transform, &Velocity, &ColliderSize), (With<Ball>, Without<StuckToPaddle>)>,
+    brick_query: Query<&Transform, (With<Brick>, Without<Ball>, Without<Paddle>)>,
+    mut paddle_query: Query<(&mut Transform, &ColliderSize), (With<Paddle>, Without<Ball>)>,
+) {
+    let Ok((ball_transform, velocity, ball_collider)) = ball_query.single() else {
+        return;
+    };
+    let Ok((mut paddle_transform, paddle_collider)) = paddle_query.single_mut() else {
+        return;
+    };
+
+    let bounds = PlayfieldBounds::from_config(&config);
+    let landing_x = predict_landing_x(
+        ball_transform.translation.truncate(),
+        velocity.0,
+        landing_bounds(&bounds, ball_collider.0),
+    );
+    let target = brick_query
+        .iter()
+        .map(|transform| transform.translation.truncate())
+        .min_by(|a, b| a.y.total_cmp(&b.y));
+
+    // Only aim on the way down; heading up, the prediction changes at every brick hit anyway
+    let paddle_x = match target {
+        Some(target) if velocity.0.y < 0.0 => aimed_paddle_x(
+            landing_x,
+            target,
+            paddle_collider.0.x,
+            config.paddle_bounce.max_angle,
+        ),
+        _ => landing_x,
+    };
+
+    paddle_transform.translation.x = bounds.clamp_paddle_x(paddle_x, paddle_collider.0.x);
+}
+
+/// Queues a serve as soon as the countdown allows, so `launch_ball` fires it.
+fn serve_autopilot_ball(
+    time: Res<Time>,
+    mut serve: ResMut<Serve>,
+    stuck_query: Query<(), With<StuckToPaddle>>,
+) {
+    if stuck_query.is_empty() || !serve.countdown.is_finished() || serve.buffered_at.is_some() {
+        return;
+    }
+    serve.buffered_at = Some(time.elapsed_secs());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(16),
+        ));
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Settings>();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Lives>();
+        app.init_resource::<Serve>();
+        app.init_resource::<AimAngle>();
+        app.init_resource::<Combo>();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<DestroyedBricks>();
+        app.init_resource::<PowerUpWeights>();
+        app.init_resource::<GameRng>();
+        app.add_message::<BrickHitEvent>();
+        app.add_message::<BounceEvent>();
+        app
+    }
+
+    // --- autopilot_requested ---
+
+    #[test]
+    fn autopilot_flag_is_opt_in() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert!(autopilot_requested(args(&["breakout", "--autopilot"])));
+        assert!(!autopilot_requested(args(&["breakout", "--monitor", "1"])));
+    }
+
+    // --- aimed_paddle_x ---
+
+    #[test]
+    fn aiming_offsets_the_paddle_away_from_the_target() {
+        let max_angle = PADDLE_MAX_BOUNCE_ANGLE;
+        let right = aimed_paddle_x(0.0, Vec2::new(200.0, 100.0), PADDLE_WIDTH, max_angle);
+        let above = aimed_paddle_x(0.0, Vec2::new(0.0, 100.0), PADDLE_WIDTH, max_angle);
+
+        assert!(
+            right < 0.0,
+            "Paddle should sit left of the ball to send it right"
+        );
+        assert!(right >= -PADDLE_WIDTH / 2.0 * AUTOPILOT_MAX_AIM);
+        assert_eq!(above, 0.0);
+    }
+
+    // --- steer_autopilot_paddle ---
+
+    #[test]
+    fn autopilot_survives_long_runs_and_clears_bricks() {
+        let mut app = test_app();
+        app.add_systems(Startup, crate::setup::spawn_game);
+        app.add_systems(
+            Update,
+            (
+                movement::move_paddle,
+                movement::hold_stuck_ball,
+                serve_autopilot_ball,
+                movement::launch_ball,
+                movement::apply_velocity,
+                steer_autopilot_paddle,
+                collision::ball_collision_walls_and_paddle,
+                collision::ball_collision_bricks,
+                collision::clamp_ball_to_bounds,
+                collision::recover_stuck_ball,
+                collision::ball_death_zone,
+            )
+                .chain(),
+        );
+        app.update();
+
+        let mut brick_query = app.world_mut().query_filtered::<(), With<Brick>>();
+        let starting_bricks = brick_query.iter(app.world()).count();
+        let starting_lives = app.world().resource::<Lives>().count;
+
+        let mut frames = 0;
+        while frames < 20_000 && brick_query.iter(app.world()).count() > 0 {
+            app.update();
+            frames += 1;
+            assert_eq!(
+                app.world().resource::<Lives>().count,
+                starting_lives,
+                "Ball reached the death zone after {frames} frames"
+            );
+        }
+
+        let remaining = brick_query.iter(app.world()).count();
+        assert_eq!(
+            remaining, 0,
+            "{remaining} of {starting_bricks} bricks left after {frames} frames"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use bevy::render::render_resource::AsBindGroup;
 use bevy::shader::ShaderRef;
 use bevy::sprite_render::{Material2d, Material2dPlugin};
 
-use crate::components::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::components::{BACKGROUND_SHADER_PATH, Settings, WINDOW_HEIGHT, WINDOW_WIDTH};
 
 pub struct BackgroundPlugin;
 
@@ -27,7 +27,7 @@ pub struct BackgroundMaterial {
 
 impl Material2d for BackgroundMaterial {
     fn fragment_shader() -> ShaderRef {
-        "shaders/background.wgsl".into()
+        BACKGROUND_SHADER_PATH.into()
     }
 }
 
@@ -47,8 +47,63 @@ fn spawn_background(
     ));
 }
 
-fn update_time(time: Res<Time>, mut materials: ResMut<Assets<BackgroundMaterial>>) {
+/// Advances the shader clock; it freezes while reduce motion is on.
+fn update_time(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut materials: ResMut<Assets<BackgroundMaterial>>,
+) {
+    if settings.reduce_motion {
+        return;
+    }
+
     for (_, material) in materials.iter_mut() {
         material.time = time.elapsed_secs();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Settings>();
+        app.init_resource::<Assets<BackgroundMaterial>>();
+        app.add_systems(Update, update_time);
+        app
+    }
+
+    fn material_time(app: &App) -> f32 {
+        let materials = app.world().resource::<Assets<BackgroundMaterial>>();
+        materials.iter().next().unwrap().1.time
+    }
+
+    // --- update_time ---
+
+    #[test]
+    fn reduce_motion_freezes_shader_time() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.world_mut()
+            .resource_mut::<Assets<BackgroundMaterial>>()
+            .add(BackgroundMaterial {
+                time: 0.0,
+                resolution: Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+                _padding: 0.0,
+            });
+
+        app.update();
+        app.update();
+        let animated = material_time(&app);
+        assert!(animated > 0.0, "Shader time should advance normally");
+
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+        app.update();
+        app.update();
+        assert_eq!(material_time(&app), animated);
+    }
+}
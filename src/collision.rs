@@ -2,241 +2,1049 @@ use bevy::prelude::*;
 
 use crate::components::*;
 
-/// Ball vs walls and paddle — reflect velocity on collision.
+/// Every ball vs walls and paddle — reflect velocity on collision; touching the paddle ends a combo
+/// and squashes it, while wall hits send a `BounceEvent`. The paddle is ignored while the ball's
+/// `PaddleCooldown` runs.
 #[allow(clippy::type_complexity)]
 pub fn ball_collision_walls_and_paddle(
-    mut ball_query: Query<(&mut Transform, &mut Ball)>,
+    mut commands: Commands,
+    mut ball_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Velocity,
+            &ColliderSize,
+            Option<&mut PaddleCooldown>,
+        ),
+        With<Ball>,
+    >,
     collider_query: Query<
-        (&Transform, Option<&Paddle>, Option<&Wall>),
+        (
+            Entity,
+            &Transform,
+            &ColliderSize,
+            Option<&PaddleVelocity>,
+            Has<BottomShield>,
+        ),
         (With<Collider>, Without<Ball>, Without<Brick>),
     >,
+    mut combo: ResMut<Combo>,
+    config: Res<GameConfig>,
+    mut bounces: MessageWriter<BounceEvent>,
 ) {
-    let Ok((mut ball_transform, mut ball)) = ball_query.single_mut() else {
-        return;
-    };
+    let bounce = config.paddle_bounce;
+    // A shield breaks on its first hit, so a second ball reaching it this frame passes through
+    let mut broken_shields = Vec::new();
+
+    for (ball, mut ball_transform, mut ball_velocity, ball_collider, cooldown) in &mut ball_query {
+        let ball_pos = ball_transform.translation.truncate();
+        let ball_size = ball_collider.0;
+
+        let cooling_down = if let Some(mut cooldown) = cooldown {
+            cooldown.frames = cooldown.frames.saturating_sub(1);
+            if cooldown.frames == 0 {
+                commands.entity(ball).remove::<PaddleCooldown>();
+            }
+            true
+        } else {
+            false
+        };
+
+        for (collider, collider_transform, collider_size, paddle, shield) in &collider_query {
+            if (paddle.is_some() && cooling_down) || broken_shields.contains(&collider) {
+                continue;
+            }
 
-    let ball_pos = ball_transform.translation.truncate();
-    let ball_size = Vec2::splat(BALL_SIZE);
-
-    for (collider_transform, paddle, wall) in &collider_query {
-        let target_pos = collider_transform.translation.truncate();
-        let target_size = collider_transform.scale.truncate()
-            * if paddle.is_some() {
-                Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)
-            } else if wall.is_some() {
-                // Walls use custom_size in the sprite, but transform.scale is 1.0
-                // We need to figure out the wall size from its sprite custom_size.
-                // Since we can't easily query Sprite here, use the wall dimensions directly.
-                let diff = (target_pos - Vec2::ZERO).abs();
-                if diff.x > diff.y {
-                    // Left or right wall
-                    Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT + WALL_THICKNESS * 2.0)
+            let target_pos = collider_transform.translation.truncate();
+            let target_size = collider_size.0;
+
+            if let Some(collision) =
+                check_aabb_collision(ball_pos, ball_size, target_pos, target_size)
+            {
+                // Bounce and push the ball out of the collider so it can't stick
+                let mut position = ball_pos;
+                resolve_bounce(
+                    collision,
+                    &mut position,
+                    &mut ball_velocity.0,
+                    ball_size,
+                    Rect::from_center_size(target_pos, target_size),
+                );
+                ball_transform.translation.x = position.x;
+                ball_transform.translation.y = position.y;
+
+                if paddle.is_some() {
+                    // Only touch the combo when a streak is running, so change detection stays quiet
+                    if combo.streak != 0 {
+                        combo.streak = 0;
+                    }
+                    commands.entity(collider).insert(PaddleSquash {
+                        timer: Timer::from_seconds(PADDLE_SQUASH_DURATION, TimerMode::Once),
+                    });
+                    commands.entity(ball).insert(PaddleCooldown {
+                        frames: PADDLE_COOLDOWN_FRAMES,
+                    });
                 } else {
-                    // Top wall
-                    Vec2::new(WINDOW_WIDTH + WALL_THICKNESS * 2.0, WALL_THICKNESS)
+                    if shield {
+                        commands.entity(collider).despawn();
+                        broken_shields.push(collider);
+                    }
+                    bounces.write(BounceEvent {
+                        side: collision,
+                        speed: ball_velocity.0.length(),
+                    });
                 }
-            } else {
-                continue;
-            };
 
-        if let Some(collision) = check_aabb_collision(ball_pos, ball_size, target_pos, target_size)
-        {
-            match collision {
-                CollisionSide::Top | CollisionSide::Bottom => {
-                    ball.velocity.y = -ball.velocity.y;
+                // If hitting paddle, adjust angle based on where ball hit
+                if paddle.is_some() && bounce.steering == PaddleSteering::OffsetBased {
+                    let hit_offset = (ball_pos.x - target_pos.x) / (target_size.x / 2.0);
+                    let mut angle = hit_offset * bounce.max_angle;
+                    let mut speed = ball_velocity.0.length();
+                    // Bumpers kick edge hits back steeper and faster
+                    if bounce.edge_bumpers
+                        && hit_offset.abs() >= 1.0 - 2.0 * PADDLE_EDGE_BUMPER_ZONE
+                    {
+                        angle *= PADDLE_EDGE_BUMPER_ANGLE;
+                        speed *= PADDLE_EDGE_BUMPER_SPEED;
+                    }
+                    ball_velocity.0 = Vec2::new(
+                        speed * angle.sin() + ball_velocity.0.x * bounce.residual_x,
+                        ball_velocity.0.y.abs(), // Always bounce up
+                    )
+                    .normalize()
+                        * speed;
                 }
-                CollisionSide::Left | CollisionSide::Right => {
-                    ball.velocity.x = -ball.velocity.x;
+
+                // A moving paddle drags the ball along
+                if let Some(paddle) = paddle {
+                    ball_velocity.0 =
+                        apply_paddle_friction(ball_velocity.0, paddle.x, bounce.friction);
                 }
-            }
 
-            // If hitting paddle, adjust angle based on where ball hit
-            if paddle.is_some() {
-                let hit_offset = (ball_pos.x - target_pos.x) / (PADDLE_WIDTH / 2.0);
-                let angle = hit_offset * std::f32::consts::FRAC_PI_4; // max ±45° offset
-                let speed = ball.velocity.length();
-                ball.velocity = Vec2::new(
-                    speed * angle.sin() + ball.velocity.x * 0.3,
-                    ball.velocity.y.abs(), // Always bounce up
-                )
-                .normalize()
-                    * speed;
+                // Only handle one collision per frame
+                break;
             }
+        }
+    }
+}
 
-            // Push ball out of collision to avoid sticking
-            match collision {
-                CollisionSide::Top => {
-                    ball_transform.translation.y =
-                        target_pos.y + target_size.y / 2.0 + BALL_SIZE / 2.0 + 0.1;
-                }
-                CollisionSide::Bottom => {
-                    ball_transform.translation.y =
-                        target_pos.y - target_size.y / 2.0 - BALL_SIZE / 2.0 - 0.1;
-                }
-                CollisionSide::Left => {
-                    ball_transform.translation.x =
-                        target_pos.x - target_size.x / 2.0 - BALL_SIZE / 2.0 - 0.1;
-                }
-                CollisionSide::Right => {
-                    ball_transform.translation.x =
-                        target_pos.x + target_size.x / 2.0 + BALL_SIZE / 2.0 + 0.1;
-                }
-            }
+/// Returns `velocity` after a paddle moving at `paddle_vx` pulls its x toward the paddle's by
+/// `friction`, keeping its speed and vertical direction.
+pub fn apply_paddle_friction(velocity: Vec2, paddle_vx: f32, friction: f32) -> Vec2 {
+    let speed = velocity.length();
+    if friction <= 0.0 || speed == 0.0 {
+        return velocity;
+    }
+    let max_x = speed * PADDLE_FRICTION_MAX_ANGLE.sin();
+    let x = (velocity.x + (paddle_vx - velocity.x) * friction.min(1.0)).clamp(-max_x, max_x);
+    Vec2::new(x, (speed * speed - x * x).sqrt().copysign(velocity.y))
+}
 
-            // Only handle one collision per frame
-            break;
+/// Reflects `velocity` off the `side` of `target` the ball hit and pushes `position` just clear
+/// of that side.
+pub fn resolve_bounce(
+    side: CollisionSide,
+    position: &mut Vec2,
+    velocity: &mut Vec2,
+    ball_size: Vec2,
+    target: Rect,
+) {
+    let clearance = ball_size / 2.0 + 0.1;
+    match side {
+        CollisionSide::Top => {
+            velocity.y = -velocity.y;
+            position.y = target.max.y + clearance.y;
+        }
+        CollisionSide::Bottom => {
+            velocity.y = -velocity.y;
+            position.y = target.min.y - clearance.y;
+        }
+        CollisionSide::Left => {
+            velocity.x = -velocity.x;
+            position.x = target.min.x - clearance.x;
+        }
+        CollisionSide::Right => {
+            velocity.x = -velocity.x;
+            position.x = target.max.x + clearance.x;
         }
     }
 }
 
-/// Ball vs bricks — destroy brick, reflect, and add score.
-#[allow(clippy::type_complexity)]
+/// A ball's position and velocity at one step of a simulated trajectory.
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BallState {
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+/// Steps a ball of `ball_size` from `start` through `steps` fixed steps of `dt` seconds among
+/// static `colliders`, moving it and then bouncing it off the first collider it overlaps as the
+/// collision systems do, and returns the state after each step. Golden-trajectory tests use this
+/// to catch physics regressions without an app.
+#[cfg(test)]
+pub fn simulate_trajectory(
+    start: BallState,
+    ball_size: Vec2,
+    colliders: &[Rect],
+    steps: usize,
+    dt: f32,
+) -> Vec<BallState> {
+    let mut state = start;
+    (0..steps)
+        .map(|_| {
+            state.position += state.velocity * dt;
+            let hit = colliders.iter().find_map(|collider| {
+                check_aabb_collision(
+                    state.position,
+                    ball_size,
+                    collider.center(),
+                    collider.size(),
+                )
+                .map(|side| (side, *collider))
+            });
+            if let Some((side, collider)) = hit {
+                resolve_bounce(
+                    side,
+                    &mut state.position,
+                    &mut state.velocity,
+                    ball_size,
+                    collider,
+                );
+            }
+            state
+        })
+        .collect()
+}
+
+/// Rolls the power-up dropped by a destroyed brick when `random_drops` is on; every
+/// `combo_drop_streak`th brick of a combo drops a beneficial one without rolling the drop chance.
+fn roll_powerup_drop(
+    weights: &PowerUpWeights,
+    random_drops: bool,
+    combo_drop_streak: u32,
+    streak: u32,
+    rng: &mut SeededRng,
+) -> Option<PowerUpType> {
+    if combo_drop_streak > 0 && streak > 0 && streak.is_multiple_of(combo_drop_streak) {
+        return weights.pick_beneficial(rng.f32());
+    }
+    if !random_drops || !rng.chance(weights.drop_chance) {
+        return None;
+    }
+    weights.pick(rng.f32())
+}
+
+/// Ball vs bricks — damage or destroy brick, reflect, and add combo-scaled score on destruction.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn ball_collision_bricks(
     mut commands: Commands,
-    mut ball_query: Query<(&mut Transform, &mut Ball)>,
-    brick_query: Query<(Entity, &Transform), (With<Brick>, Without<Ball>)>,
+    mut ball_query: Query<
+        (
+            &mut Transform,
+            &mut Velocity,
+            &ColliderSize,
+            Option<&BallOwner>,
+        ),
+        With<Ball>,
+    >,
+    mut brick_query: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&BombBrick>,
+            Option<&mut BrickHealth>,
+            Option<&mut Sprite>,
+            Option<&HitFlash>,
+            Option<&mut Portal>,
+            Has<ExplosiveBrick>,
+            &ColliderSize,
+            Option<&BrickPoints>,
+            Has<BossBrick>,
+            Option<&OnDestroy>,
+        ),
+        (With<Brick>, Without<Ball>, Without<SpawnAnim>),
+    >,
+    portal_query: Query<&Transform, (With<Portal>, Without<Ball>)>,
     mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut combo: ResMut<Combo>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    powerup_weights: Res<PowerUpWeights>,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
+    mut brick_hits: MessageWriter<BrickHitEvent>,
 ) {
-    let Ok((mut ball_transform, mut ball)) = ball_query.single_mut() else {
-        return;
+    let ball_count = ball_query.iter().len();
+    let multiplier = if config.multi_ball_scoring {
+        multi_ball_multiplier(ball_count)
+    } else {
+        1.0
     };
+    // A brick takes at most one hit per frame, however many balls reach it
+    let mut hit_bricks = Vec::new();
+
+    for (mut ball_transform, mut ball_velocity, ball_collider, owner) in &mut ball_query {
+        let ball_pos = ball_transform.translation.truncate();
+        let ball_size = ball_collider.0;
+        let owner = owner.map(|owner| owner.0);
+
+        let mut exploded = None;
+
+        for (
+            brick_entity,
+            brick_transform,
+            bomb,
+            health,
+            sprite,
+            flash,
+            portal,
+            explosive,
+            collider_size,
+            points,
+            boss,
+            on_destroy,
+        ) in &mut brick_query
+        {
+            if hit_bricks.contains(&brick_entity) {
+                continue;
+            }
+            let brick_pos = brick_transform.translation.truncate();
+            let brick_size = collider_size.0;
+            let points = scale_points(
+                points.map_or(POINTS_PER_BRICK, |points| points.0),
+                multiplier,
+            );
+
+            if let Some(collision) =
+                check_aabb_collision(ball_pos, ball_size, brick_pos, brick_size)
+            {
+                hit_bricks.push(brick_entity);
+                let color = brick_color(sprite.as_deref(), flash);
+
+                // Portal bricks send the ball out of their linked portal, keeping its velocity
+                if let Some(mut portal) = portal
+                    && let Ok(link_transform) = portal_query.get(portal.link)
+                {
+                    portal.uses_left = portal.uses_left.saturating_sub(1);
+                    if portal.uses_left == 0 {
+                        commands.entity(brick_entity).despawn();
+                        brick_hits.write(BrickHitEvent {
+                            destroyed: true,
+                            color,
+                        });
+                        scoreboard.credit(combo.register(brick_pos, points), owner);
+                        destroyed_bricks
+                            .bricks
+                            .push(Rect::from_center_size(brick_pos, brick_size));
+                        if let Some(trigger) = on_destroy {
+                            run_on_destroy(&mut commands, trigger, brick_pos, &mut lives);
+                        }
+                    }
+
+                    let exit = portal_exit(
+                        link_transform.translation.truncate(),
+                        ball_velocity.0,
+                        brick_size,
+                        ball_size,
+                    );
+                    ball_transform.translation.x = exit.x;
+                    ball_transform.translation.y = exit.y;
+                    break;
+                }
 
-    let ball_pos = ball_transform.translation.truncate();
-    let ball_size = Vec2::splat(BALL_SIZE);
-    let brick_size = Vec2::new(BRICK_WIDTH, BRICK_HEIGHT);
-
-    for (brick_entity, brick_transform) in &brick_query {
-        let brick_pos = brick_transform.translation.truncate();
+                // A boss wears down to zero and stays for `check_victory` to clear; other multi-hit
+                // bricks lose a hit and dim instead of breaking
+                let mut defeated = false;
+                let destroyed = match health {
+                    Some(mut health) if boss => {
+                        defeated = health.hits == 1;
+                        health.hits = health.hits.saturating_sub(1);
+                        false
+                    }
+                    Some(mut health) if health.hits > 1 => {
+                        health.hits -= 1;
+                        if let Some(mut sprite) = sprite {
+                            // A brick hit mid-flash dims from its settled color, not the white
+                            let settled = flash.map_or(sprite.color, |flash| flash.color);
+                            sprite.color = settled.darker(BRICK_HEALTH_LIGHTEN);
+                            commands.entity(brick_entity).insert(HitFlash {
+                                timer: Timer::from_seconds(HIT_FLASH_DURATION, TimerMode::Once),
+                                color: sprite.color,
+                            });
+                        }
+                        false
+                    }
+                    _ => true,
+                };
+                brick_hits.write(BrickHitEvent {
+                    destroyed: destroyed || defeated,
+                    color,
+                });
+                if defeated {
+                    scoreboard.credit(combo.register(brick_pos, points), owner);
+                }
 
-        if let Some(collision) = check_aabb_collision(ball_pos, ball_size, brick_pos, brick_size) {
-            commands.entity(brick_entity).despawn();
-            scoreboard.score += POINTS_PER_BRICK;
+                if destroyed {
+                    commands.entity(brick_entity).despawn();
+                    scoreboard.credit(combo.register(brick_pos, points), owner);
+                    destroyed_bricks
+                        .bricks
+                        .push(Rect::from_center_size(brick_pos, brick_size));
+                    if explosive {
+                        exploded = Some(brick_entity);
+                    }
+                    if let Some(trigger) = on_destroy {
+                        run_on_destroy(&mut commands, trigger, brick_pos, &mut lives);
+                    }
+                }
 
-            match collision {
-                CollisionSide::Top | CollisionSide::Bottom => {
-                    ball.velocity.y = -ball.velocity.y;
+                // With drops on, other bricks occasionally drop a power-up, and long enough combos
+                // always do; a scripted drop takes the place of the roll
+                let scripted_drop = matches!(on_destroy, Some(OnDestroy::SpawnPowerUp(_)));
+                if destroyed
+                    && bomb.is_none()
+                    && !scripted_drop
+                    && let Some(kind) = roll_powerup_drop(
+                        &powerup_weights,
+                        config.powerup_drops,
+                        config.combo_drop_streak,
+                        combo.streak,
+                        &mut game_rng.rng,
+                    )
+                {
+                    spawn_powerup(&mut commands, kind, brick_pos);
                 }
-                CollisionSide::Left | CollisionSide::Right => {
-                    ball.velocity.x = -ball.velocity.x;
+
+                // Bomb bricks drop a hazard from where they stood
+                if destroyed && bomb.is_some() {
+                    commands.spawn((
+                        Sprite {
+                            color: HAZARD_COLOR,
+                            custom_size: Some(Vec2::splat(HAZARD_SIZE)),
+                            ..default()
+                        },
+                        Transform::from_xyz(brick_pos.x, brick_pos.y, 0.5),
+                        Hazard,
+                        Velocity(Vec2::new(0.0, -HAZARD_SPEED)),
+                    ));
                 }
+
+                let mut position = ball_pos;
+                resolve_bounce(
+                    collision,
+                    &mut position,
+                    &mut ball_velocity.0,
+                    ball_size,
+                    Rect::from_center_size(brick_pos, brick_size),
+                );
+                ball_transform.translation.x = position.x;
+                ball_transform.translation.y = position.y;
+
+                // Only handle one brick collision per frame
+                break;
             }
+        }
 
-            // Push ball out
-            match collision {
-                CollisionSide::Top => {
-                    ball_transform.translation.y =
-                        brick_pos.y + brick_size.y / 2.0 + BALL_SIZE / 2.0 + 0.1;
-                }
-                CollisionSide::Bottom => {
-                    ball_transform.translation.y =
-                        brick_pos.y - brick_size.y / 2.0 - BALL_SIZE / 2.0 - 0.1;
-                }
-                CollisionSide::Left => {
-                    ball_transform.translation.x =
-                        brick_pos.x - brick_size.x / 2.0 - BALL_SIZE / 2.0 - 0.1;
+        // Explosions take out neighbouring bricks in the same frame
+        if let Some(origin) = exploded {
+            let bricks: Vec<_> = brick_query
+                .iter()
+                .filter(|(.., boss, _)| !boss)
+                .map(|(entity, transform, _, _, _, _, _, explosive, ..)| {
+                    (entity, transform.translation.truncate(), explosive)
+                })
+                .collect();
+            for (entity, position) in explosion_chain(origin, &bricks) {
+                if hit_bricks.contains(&entity) {
+                    continue;
                 }
-                CollisionSide::Right => {
-                    ball_transform.translation.x =
-                        brick_pos.x + brick_size.x / 2.0 + BALL_SIZE / 2.0 + 0.1;
+                hit_bricks.push(entity);
+                commands.entity(entity).despawn();
+                let Ok((_, _, _, _, sprite, flash, .., collider_size, points, _, on_destroy)) =
+                    brick_query.get(entity)
+                else {
+                    continue;
+                };
+                brick_hits.write(BrickHitEvent {
+                    destroyed: true,
+                    color: brick_color(sprite, flash),
+                });
+                let points = scale_points(
+                    points.map_or(POINTS_PER_BRICK, |points| points.0),
+                    multiplier,
+                );
+                scoreboard.credit(combo.register(position, points), owner);
+                destroyed_bricks
+                    .bricks
+                    .push(Rect::from_center_size(position, collider_size.0));
+                if let Some(trigger) = on_destroy {
+                    run_on_destroy(&mut commands, trigger, position, &mut lives);
                 }
             }
+        }
+    }
+}
+
+/// Spawns a falling power-up capsule of `kind` at `position`.
+pub fn spawn_powerup(commands: &mut Commands, kind: PowerUpType, position: Vec2) {
+    commands.spawn((
+        Sprite {
+            color: kind.color(),
+            custom_size: Some(POWERUP_SIZE),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, 0.5),
+        PowerUp(kind),
+        Velocity(Vec2::new(0.0, -POWERUP_SPEED)),
+    ));
+}
+
+/// Runs the scripted effect of a brick destroyed at `brick_pos`. Spawned bricks never carry a
+/// trigger of their own, so one destruction can't set off a chain.
+fn run_on_destroy(
+    commands: &mut Commands,
+    trigger: &OnDestroy,
+    brick_pos: Vec2,
+    lives: &mut Lives,
+) {
+    match trigger {
+        OnDestroy::Nothing => {}
+        OnDestroy::SpawnPowerUp(kind) => spawn_powerup(commands, *kind, brick_pos),
+        OnDestroy::SpawnBricks(specs) => {
+            for spec in specs.iter().take(ON_DESTROY_BRICK_LIMIT) {
+                commands.spawn((
+                    Sprite {
+                        color: spec.color,
+                        custom_size: Some(spec.size),
+                        ..default()
+                    },
+                    Transform::from_translation((brick_pos + spec.offset).extend(0.0)),
+                    Brick,
+                    BrickHealth {
+                        hits: spec.hits.max(1),
+                    },
+                    Collider,
+                    ColliderSize(spec.size),
+                ));
+            }
+        }
+        OnDestroy::AddLife => lives.count += 1,
+    }
+}
+
+/// Returns the brick score multiplier for `ball_count` balls in play, from
+/// `MULTI_BALL_SCORE_MULTIPLIERS`; counts past the end of the table use its last entry.
+pub fn multi_ball_multiplier(ball_count: usize) -> f32 {
+    let index = ball_count.saturating_sub(1);
+    MULTI_BALL_SCORE_MULTIPLIERS
+        .get(index)
+        .or(MULTI_BALL_SCORE_MULTIPLIERS.last())
+        .copied()
+        .unwrap_or(1.0)
+}
+
+/// Returns `points` scaled by `multiplier`, rounded to whole points.
+fn scale_points(points: u32, multiplier: f32) -> u32 {
+    (points as f32 * multiplier).round() as u32
+}
+
+/// Returns a brick's settled color, looking past any hit flash in progress.
+fn brick_color(sprite: Option<&Sprite>, flash: Option<&HitFlash>) -> Color {
+    flash
+        .map(|flash| flash.color)
+        .or(sprite.map(|sprite| sprite.color))
+        .unwrap_or(Color::WHITE)
+}
+
+/// Returns the bricks destroyed by an explosion starting at `origin` (excluded), spreading
+/// breadth-first through explosive bricks. Each brick is visited once, so chains always end.
+fn explosion_chain(origin: Entity, bricks: &[(Entity, Vec2, bool)]) -> Vec<(Entity, Vec2)> {
+    let Some(&(_, origin_pos, _)) = bricks.iter().find(|(entity, _, _)| *entity == origin) else {
+        return Vec::new();
+    };
+
+    let mut visited = std::collections::HashSet::from([origin]);
+    let mut queue = std::collections::VecDeque::from([origin_pos]);
+    let mut destroyed = Vec::new();
+
+    while let Some(center) = queue.pop_front() {
+        for &(entity, position, explosive) in bricks {
+            if center.distance(position) > EXPLOSION_RADIUS || !visited.insert(entity) {
+                continue;
+            }
+            destroyed.push((entity, position));
+            if explosive {
+                queue.push_back(position);
+            }
+        }
+    }
+
+    destroyed
+}
 
-            // Only handle one brick collision per frame
-            break;
+/// Restores up to `REGEN_BRICK_COUNT` of the most recently destroyed bricks as single-hit
+/// bricks, skipping any that would overlap an area in `keep_clear`.
+fn regenerate_bricks(
+    commands: &mut Commands,
+    destroyed: &mut DestroyedBricks,
+    keep_clear: &[Rect],
+) {
+    let mut restored = 0;
+    let mut index = destroyed.bricks.len();
+
+    while index > 0 && restored < REGEN_BRICK_COUNT {
+        index -= 1;
+        let area = destroyed.bricks[index];
+        if keep_clear
+            .iter()
+            .any(|clear| !clear.intersect(area).is_empty())
+        {
+            continue;
         }
+
+        destroyed.bricks.remove(index);
+        commands.spawn((
+            Sprite {
+                color: REGEN_BRICK_COLOR,
+                custom_size: Some(area.size()),
+                ..default()
+            },
+            Transform::from_translation(area.center().extend(0.0)),
+            Brick,
+            BrickHealth { hits: 1 },
+            Collider,
+            ColliderSize(area.size()),
+        ));
+        restored += 1;
     }
 }
 
-/// Detects when the ball falls below the screen (death zone).
+/// Returns where the ball leaves a linked portal: just clear of the brick on the side it is
+/// heading, so it can't immediately re-enter the portal.
+fn portal_exit(link_pos: Vec2, velocity: Vec2, brick_size: Vec2, ball_size: Vec2) -> Vec2 {
+    let clearance = brick_size.y / 2.0 + ball_size.y / 2.0 + 0.1;
+    let direction = if velocity.y < 0.0 { -1.0 } else { 1.0 };
+    Vec2::new(link_pos.x, link_pos.y + direction * clearance)
+}
+
+/// Detects when a ball falls below the screen (death zone); a bottom `Wall` mode or practice
+/// never costs a life, a lost life restores the one-bounce shield, and the death penalty docks
+/// points. A lost ball leaves the others in play, and only losing the last one costs a life and
+/// serves a fresh ball from the centre of the paddle, or from wherever the paddle is with
+/// `keep_balls_in_play`; `lose_life_per_ball` charges a life for every ball lost.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn ball_death_zone(
-    mut ball_query: Query<(&mut Transform, &mut Ball)>,
+    mut commands: Commands,
+    mut ball_query: Query<(Entity, &mut Transform, &mut Velocity, &ColliderSize), With<Ball>>,
+    paddle_query: Query<&Transform, (With<Paddle>, Without<Ball>)>,
+    shield_query: Query<(), With<BottomShield>>,
+    config: Res<GameConfig>,
+    mut lives: ResMut<Lives>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut serve: ResMut<Serve>,
+    mut combo: ResMut<Combo>,
+) {
+    if config.bottom_mode == BottomMode::Wall {
+        return;
+    }
+
+    let death_y = PlayfieldBounds::from_config(&config).death_y;
+    let lost: Vec<Entity> = ball_query
+        .iter()
+        .filter(|(_, transform, ..)| transform.translation.y < death_y)
+        .map(|(ball, ..)| ball)
+        .collect();
+    let Some(&served) = lost.first() else {
+        return;
+    };
+
+    let last_ball_lost = lost.len() == ball_query.iter().len();
+    let lives_lost = if config.lose_life_per_ball {
+        lost.len() as u32
+    } else {
+        u32::from(last_ball_lost)
+    };
+
+    // Practice gives unlimited lives
+    if config.practice_row.is_none() && lives_lost > 0 {
+        lives.count = lives.count.saturating_sub(lives_lost);
+        if config.death_penalty {
+            scoreboard.score = scoreboard
+                .score
+                .saturating_sub(DEATH_PENALTY_POINTS * lives_lost);
+        }
+    }
+
+    if !last_ball_lost {
+        for ball in lost {
+            commands.entity(ball).despawn();
+        }
+        return;
+    }
+
+    // Play resumes with one ball held on the paddle
+    for (ball, ..) in &ball_query {
+        if ball != served {
+            commands.entity(ball).despawn();
+        }
+    }
+    let serve_x = if config.keep_balls_in_play {
+        paddle_query
+            .single()
+            .map_or(0.0, |transform| transform.translation.x)
+    } else {
+        0.0
+    };
+    if let Ok((ball, mut ball_transform, mut ball_velocity, ball_collider)) =
+        ball_query.get_mut(served)
+    {
+        ball_transform.translation.x = serve_x;
+        ball_transform.translation.y =
+            PADDLE_Y + PADDLE_HEIGHT / 2.0 + ball_collider.0.y / 2.0 + 1.0;
+        ball_velocity.0 = Vec2::new(BALL_SPEED * 0.7, BALL_SPEED);
+        commands.entity(ball).insert(StuckToPaddle);
+        *serve = Serve::default();
+        combo.streak = 0;
+
+        if config.bottom_mode == BottomMode::OneBounceShield && shield_query.is_empty() {
+            crate::setup::spawn_bottom_wall(
+                &mut commands,
+                config.bottom_mode,
+                PlayfieldBounds::from_config(&config),
+            );
+        }
+    }
+}
+
+/// Hazards vs paddle — a caught hazard costs a life outside practice; missed hazards despawn
+/// off-screen.
+pub fn hazard_collision_paddle(
+    mut commands: Commands,
     mut lives: ResMut<Lives>,
+    config: Res<GameConfig>,
+    hazard_query: Query<(Entity, &Transform), With<Hazard>>,
+    paddle_query: Query<(&Transform, &ColliderSize), With<Paddle>>,
 ) {
-    let Ok((mut ball_transform, mut ball)) = ball_query.single_mut() else {
+    let Ok((paddle_transform, paddle_collider)) = paddle_query.single() else {
         return;
     };
 
-    let death_y = -WINDOW_HEIGHT / 2.0 - BALL_SIZE;
+    let paddle_pos = paddle_transform.translation.truncate();
+    let paddle_size = paddle_collider.0;
+    let hazard_size = Vec2::splat(HAZARD_SIZE);
+    let despawn_y = PlayfieldBounds::from_config(&config).bottom - HAZARD_SIZE;
 
-    if ball_transform.translation.y < death_y {
-        lives.count = lives.count.saturating_sub(1);
+    for (hazard_entity, hazard_transform) in &hazard_query {
+        let hazard_pos = hazard_transform.translation.truncate();
 
-        // Reset ball position
-        ball_transform.translation.x = 0.0;
-        ball_transform.translation.y = PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 + 1.0;
-        ball.velocity = Vec2::new(BALL_SPEED * 0.7, BALL_SPEED);
+        if check_aabb_collision(hazard_pos, hazard_size, paddle_pos, paddle_size).is_some() {
+            if config.practice_row.is_none() {
+                lives.count = lives.count.saturating_sub(1);
+            }
+            commands.entity(hazard_entity).despawn();
+        } else if hazard_pos.y < despawn_y {
+            commands.entity(hazard_entity).despawn();
+        }
     }
 }
 
-/// Clamps ball position to stay within playable bounds (safety net).
-pub fn clamp_ball_to_bounds(mut ball_query: Query<(&mut Transform, &mut Ball)>) {
-    let Ok((mut transform, mut ball)) = ball_query.single_mut() else {
+/// Power-ups vs paddle — a caught power-up takes effect; missed ones despawn off-screen.
+#[allow(clippy::too_many_arguments)]
+pub fn powerup_collision_paddle(
+    mut commands: Commands,
+    mut lives: ResMut<Lives>,
+    mut active: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    config: Res<GameConfig>,
+    powerup_query: Query<(Entity, &Transform, &PowerUp)>,
+    paddle_query: Query<(&Transform, &ColliderSize), With<Paddle>>,
+    ball_query: Query<(&Transform, &ColliderSize, &Velocity), With<Ball>>,
+) {
+    let Ok((paddle_transform, paddle_collider)) = paddle_query.single() else {
         return;
     };
 
-    let min_x = -WINDOW_WIDTH / 2.0 + WALL_THICKNESS + BALL_SIZE / 2.0;
-    let max_x = WINDOW_WIDTH / 2.0 - WALL_THICKNESS - BALL_SIZE / 2.0;
+    let paddle_pos = paddle_transform.translation.truncate();
+    let paddle_size = paddle_collider.0;
+    let despawn_y = PlayfieldBounds::from_config(&config).bottom - POWERUP_SIZE.y;
+    let mut balls = ball_query.iter().len();
+
+    for (powerup_entity, powerup_transform, powerup) in &powerup_query {
+        let powerup_pos = powerup_transform.translation.truncate();
+
+        if check_aabb_collision(powerup_pos, POWERUP_SIZE, paddle_pos, paddle_size).is_some() {
+            match powerup.0 {
+                PowerUpType::WiderPaddle => active.activate(PowerUpType::WiderPaddle),
+                PowerUpType::ExtraLife => lives.count += 1,
+                PowerUpType::Regen => {
+                    // Leave the ball a brick's breathing room so it isn't trapped inside one
+                    let mut keep_clear = vec![Rect::from_center_size(paddle_pos, paddle_size)];
+                    keep_clear.extend(ball_query.iter().map(|(ball, ball_collider, _)| {
+                        Rect::from_center_size(ball.translation.truncate(), ball_collider.0 * 3.0)
+                    }));
+                    regenerate_bricks(&mut commands, &mut destroyed_bricks, &keep_clear);
+                }
+                PowerUpType::MultiBall => {
+                    if let Some((ball, ball_collider, velocity)) = ball_query.iter().next() {
+                        balls += spawn_multi_balls(
+                            &mut commands,
+                            ball.translation.truncate(),
+                            velocity.0,
+                            ball_collider.0.x,
+                            balls,
+                        );
+                    }
+                }
+            }
+            commands.entity(powerup_entity).despawn();
+        } else if powerup_pos.y < despawn_y {
+            commands.entity(powerup_entity).despawn();
+        }
+    }
+}
+
+/// Splits up to `MULTI_BALL_COUNT` balls off a ball at `position`, fanned around its `velocity`,
+/// without going over `MAX_BALLS` with `balls` already in play. Returns how many were spawned.
+fn spawn_multi_balls(
+    commands: &mut Commands,
+    position: Vec2,
+    velocity: Vec2,
+    diameter: f32,
+    balls: usize,
+) -> usize {
+    let count = MULTI_BALL_COUNT.min(ball_headroom(balls));
+    for i in 0..count {
+        let side = if i % 2 == 0 { 1.0 } else { -1.0 };
+        let angle = side * MULTI_BALL_SPREAD * (i / 2 + 1) as f32;
+        let ball = crate::setup::spawn_ball(commands, diameter, position.x);
+        commands.entity(ball).insert((
+            Transform::from_translation(position.extend(1.0)),
+            Velocity(Vec2::from_angle(angle).rotate(velocity)),
+        ));
+    }
+    count
+}
+
+/// Clamps every ball to stay within playable bounds (safety net).
+pub fn clamp_ball_to_bounds(
+    config: Res<GameConfig>,
+    mut ball_query: Query<(&mut Transform, &mut Velocity, &ColliderSize), With<Ball>>,
+) {
+    let bounds = PlayfieldBounds::from_config(&config);
+    for (mut transform, mut ball_velocity, ball_collider) in &mut ball_query {
+        let (min_x, max_x) = bounds.ball_x_range(ball_collider.0.x);
+
+        // Clamp X and reflect velocity if ball was outside bounds
+        if transform.translation.x < min_x {
+            transform.translation.x = min_x;
+            if ball_velocity.0.x < 0.0 {
+                ball_velocity.0.x = -ball_velocity.0.x;
+            }
+        } else if transform.translation.x > max_x {
+            transform.translation.x = max_x;
+            if ball_velocity.0.x > 0.0 {
+                ball_velocity.0.x = -ball_velocity.0.x;
+            }
+        }
+    }
+}
 
-    // Clamp X and reflect velocity if ball was outside bounds
-    if transform.translation.x < min_x {
-        transform.translation.x = min_x;
-        if ball.velocity.x < 0.0 {
-            ball.velocity.x = -ball.velocity.x;
+/// Puts a ball that has barely moved for `STUCK_BALL_FRAMES` frames (e.g. trapped in a wall
+/// seam) back in open space, heading down at its current speed.
+#[allow(clippy::type_complexity)]
+pub fn recover_stuck_ball(
+    mut game_rng: ResMut<GameRng>,
+    mut ball_query: Query<
+        (&mut Transform, &mut Velocity, &mut PositionHistory),
+        (With<Ball>, Without<StuckToPaddle>),
+    >,
+) {
+    for (mut transform, mut velocity, mut history) in &mut ball_query {
+        let position = transform.translation.truncate();
+        history.positions.push_back(position);
+        if history.positions.len() > STUCK_BALL_FRAMES {
+            history.positions.pop_front();
         }
-    } else if transform.translation.x > max_x {
-        transform.translation.x = max_x;
-        if ball.velocity.x > 0.0 {
-            ball.velocity.x = -ball.velocity.x;
+        if history.positions.len() < STUCK_BALL_FRAMES {
+            continue;
         }
+
+        let trapped = history
+            .positions
+            .iter()
+            .all(|past| past.distance(position) < STUCK_BALL_MIN_TRAVEL);
+        if !trapped {
+            continue;
+        }
+
+        let speed = velocity.0.length().max(BALL_SPEED);
+        let side = if game_rng.rng.bool() { 1.0 } else { -1.0 };
+        transform.translation.x = STUCK_BALL_RESET_POSITION.x;
+        transform.translation.y = STUCK_BALL_RESET_POSITION.y;
+        velocity.0 = Vec2::new(side * 0.5, -1.0).normalize() * speed;
+        history.positions.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::ecs::system::RunSystemOnce;
 
     fn test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Scoreboard>();
         app.init_resource::<Lives>();
+        app.init_resource::<Serve>();
+        app.init_resource::<Combo>();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<DestroyedBricks>();
+        app.init_resource::<PowerUpWeights>();
+        app.init_resource::<GameRng>();
+        app.add_message::<BrickHitEvent>();
+        app.add_message::<BounceEvent>();
         app
     }
 
-    // --- Wall size heuristic regression test ---
+    // --- ColliderSize ---
 
     #[test]
-    fn wall_size_heuristic_top_wall() {
-        // Top wall at (0, 305): diff.x=0 < diff.y=305
-        // Should get top-wall dimensions (wide and thin)
-        let top_wall_pos = Vec2::new(0.0, WINDOW_HEIGHT / 2.0 + WALL_THICKNESS / 2.0);
-        let diff = top_wall_pos.abs();
-        let size = if diff.x > diff.y {
-            Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT + WALL_THICKNESS * 2.0)
-        } else {
-            Vec2::new(WINDOW_WIDTH + WALL_THICKNESS * 2.0, WALL_THICKNESS)
-        };
-        assert!(
-            size.x > size.y,
-            "Top wall should be wider than tall, got {size:?}"
-        );
+    fn scaled_brick_keeps_its_collider_size() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        // Ball overlaps the collider edge but would miss a 0.1-scaled sprite
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                BRICK_WIDTH / 2.0 - 2.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0).with_scale(Vec3::splat(0.1)),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&Brick>();
+        assert_eq!(q.iter(app.world()).count(), 0);
     }
 
     #[test]
-    fn wall_size_heuristic_side_walls() {
-        // Left wall at (-455, 0): diff.x=455 > diff.y=0
-        // Should get side-wall dimensions (thin and tall)
-        let left_wall_pos = Vec2::new(-WINDOW_WIDTH / 2.0 - WALL_THICKNESS / 2.0, 0.0);
-        let diff = left_wall_pos.abs();
-        let size = if diff.x > diff.y {
-            Vec2::new(WALL_THICKNESS, WINDOW_HEIGHT + WALL_THICKNESS * 2.0)
-        } else {
-            Vec2::new(WINDOW_WIDTH + WALL_THICKNESS * 2.0, WALL_THICKNESS)
-        };
+    fn scaled_paddle_keeps_its_collider_size() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        // Ball lands near the paddle edge while the transform is squashed
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                PADDLE_WIDTH / 2.0 - 4.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0).with_scale(Vec3::new(0.1, 3.0, 1.0)),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let ball_vel = q.iter(app.world()).next().unwrap().0;
+        assert!(ball_vel.y > 0.0, "Ball should bounce off the paddle edge");
+    }
+
+    // --- recover_stuck_ball ---
+
+    #[test]
+    fn ball_pinned_in_wall_corner_is_relocated() {
+        let mut app = test_app();
+        app.add_systems(Update, recover_stuck_ball);
+
+        let corner = Vec2::new(
+            WINDOW_WIDTH / 2.0 - BALL_SIZE / 2.0,
+            WINDOW_HEIGHT / 2.0 - BALL_SIZE / 2.0,
+        );
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(corner.extend(1.0)),
+                Ball,
+                Velocity(Vec2::new(BALL_SPEED, BALL_SPEED)),
+                PositionHistory::default(),
+            ))
+            .id();
+
+        // Pinned for less than the detection window: left alone
+        for _ in 0..STUCK_BALL_FRAMES - 1 {
+            app.update();
+        }
+        let pos = app
+            .world()
+            .get::<Transform>(ball)
+            .unwrap()
+            .translation
+            .truncate();
+        assert_eq!(pos, corner);
+
+        app.update();
+
+        let pos = app
+            .world()
+            .get::<Transform>(ball)
+            .unwrap()
+            .translation
+            .truncate();
+        assert_eq!(pos, STUCK_BALL_RESET_POSITION);
+        let vel = app.world().get::<Velocity>(ball).unwrap().0;
         assert!(
-            size.y > size.x,
-            "Side wall should be taller than wide, got {size:?}"
+            vel.y < 0.0,
+            "Ball should head down after recovery, got {vel:?}"
         );
+        assert!(vel.length() >= BALL_SPEED);
+    }
+
+    #[test]
+    fn moving_ball_is_not_relocated() {
+        let mut app = test_app();
+        app.add_systems(Update, (recover_stuck_ball, move_ball_right).chain());
+
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(BALL_SPEED, 0.0)),
+                PositionHistory::default(),
+            ))
+            .id();
+
+        for _ in 0..STUCK_BALL_FRAMES * 2 {
+            app.update();
+        }
+        let pos = app.world().get::<Transform>(ball).unwrap().translation;
+        assert_eq!(pos.y, 0.0);
+    }
+
+    fn move_ball_right(mut query: Query<&mut Transform, With<Ball>>) {
+        for mut transform in &mut query {
+            transform.translation.x += 1.0;
+        }
     }
 
     // --- ball_collision_walls_and_paddle ---
@@ -255,19 +1063,25 @@ mod tests {
                 top_wall_y - WALL_THICKNESS / 2.0 - BALL_SIZE / 2.0 + 2.0,
                 1.0,
             ),
-            Ball {
-                velocity: Vec2::new(0.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
         ));
 
         // Spawn top wall with Collider + Wall
-        app.world_mut()
-            .spawn((Transform::from_xyz(0.0, top_wall_y, 0.0), Wall, Collider));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, top_wall_y, 0.0),
+            Wall,
+            Collider,
+            ColliderSize(Vec2::new(
+                WINDOW_WIDTH + WALL_THICKNESS * 2.0,
+                WALL_THICKNESS,
+            )),
+        ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<&Ball>();
-        let ball_vel = q.iter(app.world()).next().unwrap().velocity;
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let ball_vel = q.iter(app.world()).next().unwrap().0;
         assert!(
             ball_vel.y < 0.0,
             "Ball should bounce down after hitting top wall, got y={}",
@@ -289,19 +1103,25 @@ mod tests {
                 0.0,
                 1.0,
             ),
-            Ball {
-                velocity: Vec2::new(BALL_SPEED, 0.0),
-            },
+            Ball,
+            Velocity(Vec2::new(BALL_SPEED, 0.0)),
         ));
 
         // Spawn right wall
-        app.world_mut()
-            .spawn((Transform::from_xyz(right_wall_x, 0.0, 0.0), Wall, Collider));
+        app.world_mut().spawn((
+            Transform::from_xyz(right_wall_x, 0.0, 0.0),
+            Wall,
+            Collider,
+            ColliderSize(Vec2::new(
+                WALL_THICKNESS,
+                WINDOW_HEIGHT + WALL_THICKNESS * 2.0,
+            )),
+        ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<&Ball>();
-        let ball_vel = q.iter(app.world()).next().unwrap().velocity;
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let ball_vel = q.iter(app.world()).next().unwrap().0;
         assert!(
             ball_vel.x < 0.0,
             "Ball should bounce left after hitting right wall, got x={}",
@@ -309,69 +1129,1324 @@ mod tests {
         );
     }
 
+    #[test]
+    fn every_ball_bounces_off_walls_and_paddle() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        let top_wall_y = WINDOW_HEIGHT / 2.0 + WALL_THICKNESS / 2.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, top_wall_y, 0.0),
+            Wall,
+            Collider,
+            ColliderSize(Vec2::new(
+                WINDOW_WIDTH + WALL_THICKNESS * 2.0,
+                WALL_THICKNESS,
+            )),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        let rising = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(
+                    0.0,
+                    top_wall_y - WALL_THICKNESS / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                    1.0,
+                ),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        let falling = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(
+                    0.0,
+                    PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                    1.0,
+                ),
+                Ball,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+            ))
+            .id();
+
+        app.update();
+
+        let rising_y = app.world().get::<Velocity>(rising).unwrap().0.y;
+        let falling_y = app.world().get::<Velocity>(falling).unwrap().0.y;
+        assert!(rising_y < 0.0, "Top wall should send its ball down");
+        assert!(falling_y > 0.0, "Paddle should send its ball up");
+        assert!(app.world().get::<PaddleCooldown>(falling).is_some());
+        assert!(app.world().get::<PaddleCooldown>(rising).is_none());
+    }
+
     // --- ball_collision_bricks ---
 
     #[test]
-    fn ball_destroys_brick_and_scores() {
+    fn ball_destroys_brick_and_scores() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        // Spawn ball overlapping a brick
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+
+        // Spawn a brick
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let scoreboard = app.world().resource::<Scoreboard>();
+        assert_eq!(scoreboard.score, POINTS_PER_BRICK);
+
+        let mut q = app.world_mut().query::<&Brick>();
+        let brick_count = q.iter(app.world()).count();
+        assert_eq!(brick_count, 0, "Brick should be despawned after hit");
+    }
+
+    /// Breaks one brick with `ball_count` balls in play (only the first touching it) and returns
+    /// the points scored.
+    fn score_with_balls(ball_count: usize, multi_ball_scoring: bool) -> u32 {
+        let mut app = test_app();
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .multi_ball_scoring = multi_ball_scoring;
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+        for i in 0..ball_count {
+            let y = if i == 0 {
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0
+            } else {
+                -100.0 - i as f32 * 40.0
+            };
+            app.world_mut().spawn((
+                Transform::from_xyz(0.0, y, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ));
+        }
+
+        app.update();
+        app.world().resource::<Scoreboard>().score
+    }
+
+    #[test]
+    fn extra_balls_in_play_multiply_brick_points() {
+        let one = score_with_balls(1, true);
+        let two = score_with_balls(2, true);
+        assert_eq!(one, POINTS_PER_BRICK);
+        assert!(two > one, "Two balls should score more: {two} vs {one}");
+        assert_eq!(
+            two,
+            (POINTS_PER_BRICK as f32 * MULTI_BALL_SCORE_MULTIPLIERS[1]).round() as u32
+        );
+        assert_eq!(score_with_balls(2, false), POINTS_PER_BRICK);
+    }
+
+    #[test]
+    fn multi_ball_multiplier_follows_the_table() {
+        assert_eq!(multi_ball_multiplier(1), 1.0);
+        assert_eq!(multi_ball_multiplier(2), 1.5);
+        assert_eq!(multi_ball_multiplier(3), 2.0);
+        assert_eq!(
+            multi_ball_multiplier(9),
+            *MULTI_BALL_SCORE_MULTIPLIERS.last().unwrap()
+        );
+    }
+
+    #[test]
+    fn two_balls_on_one_brick_break_it_once() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+        for x in [-10.0, 10.0] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ));
+        }
+
+        app.update();
+
+        assert_eq!(app.world().resource::<Scoreboard>().score, POINTS_PER_BRICK);
+    }
+
+    /// Breaks one brick with the combo at `streak_before` and returns the power-ups dropped,
+    /// with the random drop chance at zero.
+    fn combo_drops(streak_before: u32) -> Vec<PowerUpType> {
+        let mut app = test_app();
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .combo_drop_streak = 5;
+        app.world_mut().resource_mut::<PowerUpWeights>().drop_chance = 0.0;
+        app.world_mut().resource_mut::<Combo>().streak = streak_before;
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+        app.update();
+
+        let mut q = app.world_mut().query::<&PowerUp>();
+        q.iter(app.world()).map(|powerup| powerup.0).collect()
+    }
+
+    #[test]
+    fn combo_threshold_forces_a_beneficial_drop() {
+        let drops = combo_drops(4);
+        assert_eq!(drops.len(), 1, "Fifth brick in the chain should drop");
+        assert!(drops[0].is_beneficial());
+
+        assert!(
+            combo_drops(2).is_empty(),
+            "Drop chance is zero off the threshold"
+        );
+    }
+
+    #[test]
+    fn random_drops_are_opt_in() {
+        let weights = PowerUpWeights {
+            drop_chance: 1.0,
+            ..default()
+        };
+        let roll = |random_drops: bool| {
+            roll_powerup_drop(&weights, random_drops, 0, 0, &mut SeededRng::new(7))
+        };
+        assert_eq!(roll(GameConfig::default().powerup_drops), None);
+        assert!(roll(true).is_some());
+    }
+
+    #[test]
+    fn boss_survives_many_hits_and_stays_at_zero() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+        let ball = app
+            .world_mut()
+            .spawn((Transform::default(), Ball, Velocity(Vec2::ZERO)))
+            .id();
+        let boss = app
+            .world_mut()
+            .run_system_once(|mut commands: Commands| {
+                crate::setup::spawn_boss(
+                    &mut commands,
+                    Vec2::new(0.0, 100.0),
+                    Vec2::new(BRICK_WIDTH * 4.0, BRICK_HEIGHT * 2.0),
+                    BOSS_HEALTH,
+                )
+            })
+            .unwrap();
+
+        // Park the ball inside the boss before every frame so each frame is one hit
+        let hit = |app: &mut App| {
+            app.world_mut()
+                .get_mut::<Transform>(ball)
+                .unwrap()
+                .translation = Vec3::new(0.0, 100.0 - BRICK_HEIGHT, 1.0);
+            app.world_mut().get_mut::<Velocity>(ball).unwrap().0 = Vec2::new(0.0, BALL_SPEED);
+            app.update();
+        };
+        for _ in 0..BOSS_HEALTH - 1 {
+            hit(&mut app);
+        }
+        assert_eq!(app.world().get::<BrickHealth>(boss).unwrap().hits, 1);
+        assert_eq!(app.world().resource::<Scoreboard>().score, 0);
+
+        hit(&mut app);
+        hit(&mut app);
+        assert_eq!(app.world().get::<BrickHealth>(boss).unwrap().hits, 0);
+        assert_eq!(app.world().resource::<Scoreboard>().score, BOSS_POINTS);
+    }
+
+    #[test]
+    fn brick_scores_its_row_points() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            BrickPoints(BRICK_ROW_POINTS[0]),
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Scoreboard>().score,
+            BRICK_ROW_POINTS[0]
+        );
+    }
+
+    #[test]
+    fn big_ball_hits_brick_a_normal_ball_misses() {
+        for (ball_size, expect_hit) in [(BallSize::Normal, false), (BallSize::Big, true)] {
+            let mut app = test_app();
+            app.world_mut().resource_mut::<GameConfig>().ball_size = ball_size;
+            app.add_systems(
+                Update,
+                (crate::game::sync_ball_size, ball_collision_bricks).chain(),
+            );
+
+            // Just below a normal ball's reach
+            let brick_y = 100.0;
+            app.world_mut().spawn((
+                Transform::from_xyz(
+                    0.0,
+                    brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 - 4.0,
+                    1.0,
+                ),
+                Sprite::default(),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ));
+            app.world_mut().spawn((
+                Transform::from_xyz(0.0, brick_y, 0.0),
+                Brick,
+                Collider,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ));
+
+            app.update();
+
+            let mut q = app.world_mut().query::<&Brick>();
+            let hit = q.iter(app.world()).count() == 0;
+            assert_eq!(hit, expect_hit, "{ball_size:?} ball");
+        }
+    }
+
+    #[test]
+    fn ball_reflects_on_brick_hit() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        // Ball moving upward, overlapping a brick from below
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(100.0, BALL_SPEED)),
+        ));
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let ball_vel = q.iter(app.world()).next().unwrap().0;
+        assert!(
+            ball_vel.y < 0.0,
+            "Ball y-velocity should flip after hitting brick from below"
+        );
+    }
+
+    #[test]
+    fn destroying_bomb_brick_spawns_hazard() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            BombBrick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query::<(&Transform, &Hazard)>();
+        let hazards: Vec<_> = q.iter(app.world()).collect();
+        assert_eq!(hazards.len(), 1, "Bomb brick should drop one hazard");
+        assert!(
+            (hazards[0].0.translation.y - brick_y).abs() < 0.01,
+            "Hazard should spawn at the brick position"
+        );
+    }
+
+    #[test]
+    fn destroying_normal_brick_spawns_no_hazard() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&Hazard>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn multi_hit_brick_survives_until_health_runs_out() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        let ball_y = brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0;
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, ball_y, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        let brick = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, brick_y, 0.0),
+                Sprite::from_color(BRICK_COLORS[0], Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                Brick,
+                BrickHealth { hits: 2 },
+                Collider,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ))
+            .id();
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&BrickHealth>();
+        let hits = q.iter(app.world()).next().unwrap().hits;
+        assert_eq!(hits, 1, "First hit should only damage the brick");
+        assert_eq!(app.world().resource::<Scoreboard>().score, 0);
+        let flash = app.world().get::<HitFlash>(brick).unwrap();
+        assert_eq!(flash.color, BRICK_COLORS[0].darker(BRICK_HEALTH_LIGHTEN));
+
+        // Put the ball back into the brick for a second hit
+        app.world_mut()
+            .get_mut::<Transform>(ball)
+            .unwrap()
+            .translation
+            .y = ball_y;
+        app.update();
+
+        let mut q = app.world_mut().query::<&Brick>();
+        assert_eq!(q.iter(app.world()).count(), 0, "Second hit should break it");
+        assert_eq!(app.world().resource::<Scoreboard>().score, POINTS_PER_BRICK);
+        let mut q = app.world_mut().query::<&HitFlash>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "A broken brick shouldn't flash"
+        );
+    }
+
+    #[test]
+    fn ball_hitting_portal_relocates_to_linked_portal() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let entry_pos = Vec2::new(-300.0, 100.0);
+        let exit_pos = Vec2::new(300.0, 100.0);
+        let velocity = Vec2::new(80.0, BALL_SPEED);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                entry_pos.x,
+                entry_pos.y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(velocity),
+        ));
+        let entry = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(entry_pos.extend(0.0)),
+                Brick,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ))
+            .id();
+        let exit = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(exit_pos.extend(0.0)),
+                Brick,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ))
+            .id();
+        app.world_mut().entity_mut(entry).insert(Portal {
+            link: exit,
+            uses_left: PORTAL_USES,
+        });
+        app.world_mut().entity_mut(exit).insert(Portal {
+            link: entry,
+            uses_left: PORTAL_USES,
+        });
+
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let (transform, ball_velocity) = q.iter(app.world()).next().unwrap();
+        assert!(
+            (transform.translation.x - exit_pos.x).abs() < 0.01,
+            "Ball should come out at the linked portal, got x={}",
+            transform.translation.x
+        );
+        assert!(
+            transform.translation.y > exit_pos.y + BRICK_HEIGHT / 2.0,
+            "Ball should exit clear of the linked portal"
+        );
+        assert_eq!(ball_velocity.0, velocity, "Velocity should be preserved");
+
+        let uses_left = app.world().get::<Portal>(entry).unwrap().uses_left;
+        assert_eq!(uses_left, PORTAL_USES - 1);
+    }
+
+    #[test]
+    fn portal_breaks_after_last_use() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0 - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        let exit = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(300.0, 100.0, 0.0),
+                Brick,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ))
+            .id();
+        let entry = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 100.0, 0.0),
+                Brick,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                Portal {
+                    link: exit,
+                    uses_left: 1,
+                },
+            ))
+            .id();
+        app.world_mut().entity_mut(exit).insert(Portal {
+            link: entry,
+            uses_left: PORTAL_USES,
+        });
+
+        app.update();
+
+        assert!(
+            app.world().get_entity(entry).is_err(),
+            "Used-up portal should break"
+        );
+        assert_eq!(app.world().resource::<Scoreboard>().score, POINTS_PER_BRICK);
+    }
+
+    // --- hazard_collision_paddle ---
+
+    #[test]
+    fn catching_hazard_loses_life() {
+        let mut app = test_app();
+        app.add_systems(Update, hazard_collision_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut()
+            .spawn((Transform::from_xyz(10.0, PADDLE_Y + 5.0, 0.5), Hazard));
+
+        app.update();
+
+        let lives = app.world().resource::<Lives>();
+        assert_eq!(lives.count, 2, "Caught hazard should cost a life (3 -> 2)");
+
+        let mut q = app.world_mut().query::<&Hazard>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "Caught hazard should despawn"
+        );
+    }
+
+    #[test]
+    fn missed_hazard_despawns_without_penalty() {
+        let mut app = test_app();
+        app.add_systems(Update, hazard_collision_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - HAZARD_SIZE * 2.0, 0.5),
+            Hazard,
+        ));
+
+        app.update();
+
+        let lives = app.world().resource::<Lives>();
+        assert_eq!(lives.count, 3, "Missed hazard should not cost a life");
+
+        let mut q = app.world_mut().query::<&Hazard>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "Off-screen hazard should despawn"
+        );
+    }
+
+    // --- explosive bricks ---
+
+    #[test]
+    fn explosive_brick_clears_cluster_in_one_frame() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0 - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        let spawn_brick = |app: &mut App, x: f32, y: f32, explosive: bool| {
+            let mut brick = app.world_mut().spawn((
+                Transform::from_xyz(x, y, 0.0),
+                Brick,
+                Collider,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ));
+            if explosive {
+                brick.insert(ExplosiveBrick);
+            }
+            brick.id()
+        };
+
+        spawn_brick(&mut app, 0.0, 100.0, true); // hit by the ball
+        spawn_brick(&mut app, 84.0, 100.0, false);
+        spawn_brick(&mut app, 0.0, 134.0, false);
+        spawn_brick(&mut app, -84.0, 100.0, true); // chains further left
+        spawn_brick(&mut app, -168.0, 100.0, false);
+        let survivor = spawn_brick(&mut app, 400.0, 100.0, false);
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<Entity, With<Brick>>();
+        let remaining: Vec<Entity> = q.iter(app.world()).collect();
+        assert_eq!(remaining, vec![survivor]);
+        assert_eq!(app.world().resource::<Combo>().streak, 5);
+    }
+
+    #[test]
+    fn explosion_chain_visits_each_brick_once() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+        // Three explosive bricks all within reach of each other
+        let bricks = [
+            (a, Vec2::new(0.0, 0.0), true),
+            (b, Vec2::new(50.0, 0.0), true),
+            (c, Vec2::new(25.0, 30.0), true),
+        ];
+
+        let destroyed = explosion_chain(a, &bricks);
+
+        let entities: Vec<Entity> = destroyed.iter().map(|(entity, _)| *entity).collect();
+        assert_eq!(entities, vec![b, c]);
+    }
+
+    // --- OnDestroy ---
+
+    /// Breaks a single-hit brick carrying `trigger` and returns the app after the hit.
+    fn destroy_scripted_brick(trigger: OnDestroy) -> App {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            trigger,
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+
+        app.update();
+        app
+    }
+
+    fn brick_count(app: &mut App) -> usize {
+        let mut q = app.world_mut().query_filtered::<(), With<Brick>>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn spawn_powerup_trigger_drops_that_powerup() {
+        let mut app = destroy_scripted_brick(OnDestroy::SpawnPowerUp(PowerUpType::ExtraLife));
+
+        let mut q = app.world_mut().query::<(&PowerUp, &Transform)>();
+        let drops: Vec<_> = q
+            .iter(app.world())
+            .map(|(powerup, transform)| (powerup.0, transform.translation.y))
+            .collect();
+        assert_eq!(drops, vec![(PowerUpType::ExtraLife, 100.0)]);
+    }
+
+    #[test]
+    fn add_life_trigger_grants_a_life() {
+        let app = destroy_scripted_brick(OnDestroy::AddLife);
+        assert_eq!(app.world().resource::<Lives>().count, 4);
+    }
+
+    #[test]
+    fn spawn_bricks_trigger_places_capped_bricks_around_the_brick() {
+        let spec = |x: f32| TriggeredBrick {
+            offset: Vec2::new(x, 60.0),
+            size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+            hits: 2,
+            color: Color::WHITE,
+        };
+        let mut app = destroy_scripted_brick(OnDestroy::SpawnBricks(vec![spec(-80.0), spec(80.0)]));
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &BrickHealth), With<Brick>>();
+        let mut spawned: Vec<_> = q
+            .iter(app.world())
+            .map(|(transform, health)| (transform.translation.truncate(), health.hits))
+            .collect();
+        spawned.sort_by(|a, b| a.0.x.total_cmp(&b.0.x));
+        assert_eq!(
+            spawned,
+            vec![(Vec2::new(-80.0, 160.0), 2), (Vec2::new(80.0, 160.0), 2)]
+        );
+
+        let flood = (0..ON_DESTROY_BRICK_LIMIT * 3)
+            .map(|i| spec(i as f32 * 10.0))
+            .collect();
+        let mut app = destroy_scripted_brick(OnDestroy::SpawnBricks(flood));
+        assert_eq!(brick_count(&mut app), ON_DESTROY_BRICK_LIMIT);
+    }
+
+    #[test]
+    fn nothing_trigger_just_breaks_the_brick() {
+        let mut app = destroy_scripted_brick(OnDestroy::Nothing);
+        assert_eq!(brick_count(&mut app), 0);
+        assert_eq!(app.world().resource::<Lives>().count, 3);
+        assert_eq!(app.world().resource::<Scoreboard>().score, POINTS_PER_BRICK);
+    }
+
+    // --- BottomMode ---
+
+    fn bottom_mode_app(mode: BottomMode) -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(16),
+        ));
+        app.insert_resource(GameConfig {
+            bottom_mode: mode,
+            ..default()
+        });
+        app.add_systems(
+            Update,
+            (
+                crate::movement::apply_velocity,
+                ball_collision_walls_and_paddle,
+                ball_death_zone,
+            )
+                .chain(),
+        );
+        crate::setup::spawn_bottom_wall(
+            &mut app.world_mut().commands(),
+            mode,
+            PlayfieldBounds::new(BALL_SIZE, WALL_THICKNESS),
+        );
+        app.world_mut().flush();
+        let ball = app.world_mut().spawn_empty().id();
+        drop_ball(&mut app, ball);
+        (app, ball)
+    }
+
+    /// Puts the ball just above the bottom edge, falling fast.
+    fn drop_ball(app: &mut App, ball: Entity) {
+        app.world_mut().entity_mut(ball).insert((
+            Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 + 20.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+        for _ in 0..30 {
+            app.update();
+        }
+    }
+
+    #[test]
+    fn wall_mode_bounces_the_ball_without_losing_a_life() {
+        let (app, ball) = bottom_mode_app(BottomMode::Wall);
+
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y > 0.0);
+        assert_eq!(
+            app.world().resource::<Lives>().count,
+            Lives::default().count
+        );
+    }
+
+    #[test]
+    fn death_zone_mode_still_costs_a_life() {
+        let (mut app, ball) = bottom_mode_app(BottomMode::DeathZone);
+
+        assert_eq!(
+            app.world().resource::<Lives>().count,
+            Lives::default().count - 1
+        );
+        assert!(app.world().get::<StuckToPaddle>(ball).is_some());
+        let mut q = app.world_mut().query_filtered::<(), With<BottomWall>>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn shield_bounces_once_then_returns_with_the_next_life() {
+        let (mut app, ball) = bottom_mode_app(BottomMode::OneBounceShield);
+        let mut shields = app.world_mut().query_filtered::<(), With<BottomShield>>();
+
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y > 0.0);
+        assert_eq!(shields.iter(app.world()).count(), 0, "Shield should break");
+
+        drop_ball(&mut app, ball);
+        assert_eq!(
+            app.world().resource::<Lives>().count,
+            Lives::default().count - 1
+        );
+        assert_eq!(shields.iter(app.world()).count(), 1, "Shield should return");
+    }
+
+    // --- BallOwner ---
+
+    #[test]
+    fn owned_ball_credits_its_player() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0 - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0, 1.0),
+            Ball,
+            BallOwner(PlayerId::One),
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        let scoreboard = app.world().resource::<Scoreboard>();
+        assert_eq!(scoreboard.score, POINTS_PER_BRICK);
+        assert_eq!(
+            scoreboard.player_scores[PlayerId::One.index()],
+            POINTS_PER_BRICK
+        );
+        assert_eq!(scoreboard.player_scores[PlayerId::Two.index()], 0);
+    }
+
+    // --- combo ---
+
+    #[test]
+    fn consecutive_bricks_score_growing_combo() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let ball_y = 100.0 - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0;
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, ball_y, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(300.0, 100.0, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+        app.world_mut()
+            .get_mut::<Transform>(ball)
+            .unwrap()
+            .translation = Vec3::new(300.0, ball_y, 1.0);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Scoreboard>().score,
+            POINTS_PER_BRICK + 2 * POINTS_PER_BRICK
+        );
+        assert_eq!(app.world().resource::<Combo>().streak, 2);
+    }
+
+    #[test]
+    fn paddle_hit_ends_combo() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+        app.world_mut().resource_mut::<Combo>().streak = 4;
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        assert_eq!(app.world().resource::<Combo>().streak, 0);
+    }
+
+    /// Bounces a falling ball off the paddle's right edge and returns its angle from vertical.
+    fn edge_bounce_angle(max_angle: f32) -> f32 {
+        let mut app = test_app();
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .paddle_bounce
+            .max_angle = max_angle;
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                PADDLE_WIDTH / 2.0 - 1.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let vel = q.iter(app.world()).next().unwrap().0;
+        vel.x.atan2(vel.y)
+    }
+
+    #[test]
+    fn steeper_max_bounce_angle_steers_edge_hits_further() {
+        let default_angle = edge_bounce_angle(PADDLE_MAX_BOUNCE_ANGLE);
+        let steep_angle = edge_bounce_angle(60f32.to_radians());
+
+        assert!(default_angle > 0.0 && default_angle <= PADDLE_MAX_BOUNCE_ANGLE);
+        assert!(
+            steep_angle > default_angle,
+            "60° config should bounce steeper: {steep_angle} vs {default_angle}"
+        );
+    }
+
+    /// Bounces a falling ball off the paddle `offset` from its centre and returns its speed.
+    fn paddle_bounce_speed(offset: f32, edge_bumpers: bool) -> f32 {
+        let mut app = test_app();
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .paddle_bounce
+            .edge_bumpers = edge_bumpers;
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                offset,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        q.iter(app.world()).next().unwrap().0.length()
+    }
+
+    /// Bounces a falling ball off the centre of a paddle moving at `paddle_vx` and returns its
+    /// velocity.
+    fn paddle_bounce_velocity(paddle_vx: f32, friction: f32) -> Vec2 {
+        let mut app = test_app();
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .paddle_bounce
+            .friction = friction;
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            PaddleVelocity {
+                x: paddle_vx,
+                last_x: Some(0.0),
+            },
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        q.iter(app.world()).next().unwrap().0
+    }
+
+    #[test]
+    fn moving_paddle_drags_the_ball_by_the_friction_factor() {
+        let still = paddle_bounce_velocity(0.0, 0.5);
+        let light = paddle_bounce_velocity(PADDLE_SPEED, 0.2);
+        let strong = paddle_bounce_velocity(PADDLE_SPEED, 0.4);
+        let leftward = paddle_bounce_velocity(-PADDLE_SPEED, 0.4);
+
+        assert!(still.x.abs() < 0.01, "A still paddle adds no spin: {still}");
+        assert!((light.x - PADDLE_SPEED * 0.2).abs() < 0.01, "{light}");
+        assert!(strong.x > light.x, "More friction should drag harder");
+        assert!((leftward.x + strong.x).abs() < 0.01);
+        for velocity in [light, strong, leftward] {
+            assert!(velocity.y > 0.0);
+            assert!((velocity.length() - BALL_SPEED).abs() < 0.01);
+        }
+        assert_eq!(paddle_bounce_velocity(PADDLE_SPEED, 0.0).x, still.x);
+    }
+
+    #[test]
+    fn paddle_friction_never_flattens_the_bounce() {
+        let velocity = apply_paddle_friction(Vec2::new(0.0, BALL_SPEED), 10_000.0, 1.0);
+        assert!((velocity.length() - BALL_SPEED).abs() < 0.01);
+        assert!(velocity.x.atan2(velocity.y) <= PADDLE_FRICTION_MAX_ANGLE + 0.001);
+    }
+
+    #[test]
+    fn edge_bumpers_return_edge_hits_faster() {
+        let edge = PADDLE_WIDTH / 2.0 - 1.0;
+        let bumped = paddle_bounce_speed(edge, true);
+
+        assert!(
+            bumped > paddle_bounce_speed(0.0, true),
+            "Edge hit should beat a centre hit: {bumped}"
+        );
+        assert!((paddle_bounce_speed(edge, false) - BALL_SPEED).abs() < 0.01);
+        assert!((bumped - BALL_SPEED * PADDLE_EDGE_BUMPER_SPEED).abs() < 0.01);
+    }
+
+    #[test]
+    fn paddle_hit_starts_squash() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        let paddle = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                Paddle,
+                Collider,
+                ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            ))
+            .id();
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        let squash = app.world().get::<PaddleSquash>(paddle).unwrap();
+        assert!(squash.timer.remaining_secs() > 0.0);
+        let collider = app.world().get::<ColliderSize>(paddle).unwrap();
+        assert_eq!(collider.0, Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT));
+    }
+
+    #[test]
+    fn paddle_cooldown_allows_one_bounce_across_frames() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_walls_and_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        let overlapping = Vec3::new(
+            PADDLE_WIDTH / 2.0 - 2.0,
+            PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
+            1.0,
+        );
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(overlapping),
+                Ball,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+            ))
+            .id();
+
+        app.update();
+        let first = app.world().get::<Velocity>(ball).unwrap().0;
+        assert!(first.y > 0.0, "First frame should bounce");
+
+        // Still overlapping the next frame: the bounce must not be applied again
+        app.world_mut()
+            .get_mut::<Transform>(ball)
+            .unwrap()
+            .translation = overlapping;
+        app.update();
+        assert_eq!(app.world().get::<Velocity>(ball).unwrap().0, first);
+    }
+
+    #[test]
+    fn paddle_cooldown_still_collides_with_walls() {
         let mut app = test_app();
-        app.add_systems(Update, ball_collision_bricks);
+        app.add_systems(Update, ball_collision_walls_and_paddle);
 
-        // Spawn ball overlapping a brick
-        let brick_y = 100.0;
+        let wall_x = 100.0;
         app.world_mut().spawn((
-            Transform::from_xyz(
-                0.0,
-                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
-                1.0,
-            ),
-            Ball {
-                velocity: Vec2::new(0.0, BALL_SPEED),
-            },
+            Transform::from_xyz(wall_x, 0.0, 0.0),
+            Wall,
+            Collider,
+            ColliderSize(Vec2::new(WALL_THICKNESS, 200.0)),
         ));
-
-        // Spawn a brick
-        app.world_mut()
-            .spawn((Transform::from_xyz(0.0, brick_y, 0.0), Brick, Collider));
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(
+                    wall_x - WALL_THICKNESS / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                    0.0,
+                    1.0,
+                ),
+                Ball,
+                Velocity(Vec2::new(BALL_SPEED, 0.0)),
+                PaddleCooldown {
+                    frames: PADDLE_COOLDOWN_FRAMES,
+                },
+            ))
+            .id();
 
         app.update();
 
-        let scoreboard = app.world().resource::<Scoreboard>();
-        assert_eq!(scoreboard.score, POINTS_PER_BRICK);
-
-        let mut q = app.world_mut().query::<&Brick>();
-        let brick_count = q.iter(app.world()).count();
-        assert_eq!(brick_count, 0, "Brick should be despawned after hit");
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.x < 0.0);
     }
 
     #[test]
-    fn ball_reflects_on_brick_hit() {
+    fn pure_reflect_keeps_incoming_x_velocity() {
         let mut app = test_app();
-        app.add_systems(Update, ball_collision_bricks);
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .paddle_bounce
+            .steering = PaddleSteering::PureReflect;
+        app.add_systems(Update, ball_collision_walls_and_paddle);
 
-        // Ball moving upward, overlapping a brick from below
-        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        // Off-centre hit that offset steering would push right
+        let incoming = Vec2::new(-120.0, -BALL_SPEED);
         app.world_mut().spawn((
             Transform::from_xyz(
-                0.0,
-                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                PADDLE_WIDTH / 3.0,
+                PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 - 2.0,
                 1.0,
             ),
-            Ball {
-                velocity: Vec2::new(100.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(incoming),
         ));
 
-        app.world_mut()
-            .spawn((Transform::from_xyz(0.0, brick_y, 0.0), Brick, Collider));
-
         app.update();
 
-        let mut q = app.world_mut().query::<&Ball>();
-        let ball_vel = q.iter(app.world()).next().unwrap().velocity;
-        assert!(
-            ball_vel.y < 0.0,
-            "Ball y-velocity should flip after hitting brick from below"
-        );
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let vel = q.iter(app.world()).next().unwrap().0;
+        assert_eq!(vel, Vec2::new(incoming.x, -incoming.y));
+    }
+
+    // --- simulate_trajectory ---
+
+    #[test]
+    fn ball_between_two_walls_follows_the_golden_trajectory() {
+        let walls = [
+            Rect::from_center_size(Vec2::new(-160.0, 0.0), Vec2::new(100.0, 1000.0)),
+            Rect::from_center_size(Vec2::new(160.0, 0.0), Vec2::new(100.0, 1000.0)),
+        ];
+        let start = BallState {
+            position: Vec2::ZERO,
+            velocity: Vec2::new(200.0, 40.0),
+        };
+        let trajectory = simulate_trajectory(start, Vec2::splat(BALL_SIZE), &walls, 14, 0.125);
+
+        let golden = [
+            (25.0, 5.0, 200.0),
+            (50.0, 10.0, 200.0),
+            (75.0, 15.0, 200.0),
+            (100.0, 20.0, 200.0),
+            (101.9, 25.0, -200.0),
+            (76.9, 30.0, -200.0),
+            (51.9, 35.0, -200.0),
+            (26.9, 40.0, -200.0),
+            (1.9, 45.0, -200.0),
+            (-23.1, 50.0, -200.0),
+            (-48.1, 55.0, -200.0),
+            (-73.1, 60.0, -200.0),
+            (-98.1, 65.0, -200.0),
+            (-101.9, 70.0, 200.0),
+        ];
+        assert_eq!(trajectory.len(), golden.len());
+        for (step, (state, (x, y, vx))) in trajectory.iter().zip(golden).enumerate() {
+            assert!(
+                (state.position - Vec2::new(x, y)).length() < 1e-3,
+                "Step {step}: {state:?}"
+            );
+            assert_eq!(state.velocity, Vec2::new(vx, 40.0), "Step {step}");
+        }
     }
 
     // --- ball_death_zone ---
@@ -384,9 +2459,8 @@ mod tests {
         let death_y = -WINDOW_HEIGHT / 2.0 - BALL_SIZE;
         app.world_mut().spawn((
             Transform::from_xyz(50.0, death_y - 10.0, 1.0),
-            Ball {
-                velocity: Vec2::new(100.0, -BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(100.0, -BALL_SPEED)),
         ));
 
         app.update();
@@ -403,6 +2477,164 @@ mod tests {
         );
     }
 
+    /// Spawns one ball past the death zone and one still in play, runs the death zone and
+    /// returns the surviving balls' positions and velocities along with the lives left.
+    fn lose_one_of_two_balls(keep_balls_in_play: bool) -> (Vec<(Vec3, Vec2)>, u32) {
+        let mut app = test_app();
+        app.add_systems(Update, ball_death_zone);
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .keep_balls_in_play = keep_balls_in_play;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - BALL_SIZE - 10.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(120.0, 40.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(-80.0, 230.0)),
+        ));
+
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let balls = q
+            .iter(app.world())
+            .map(|(transform, velocity)| (transform.translation, velocity.0))
+            .collect();
+        (balls, app.world().resource::<Lives>().count)
+    }
+
+    #[test]
+    fn kept_balls_play_on_when_one_is_lost() {
+        let (balls, lives) = lose_one_of_two_balls(true);
+        assert_eq!(
+            balls,
+            vec![(Vec3::new(120.0, 40.0, 1.0), Vec2::new(-80.0, 230.0))]
+        );
+        assert_eq!(lives, 3, "Another ball is still in play");
+    }
+
+    #[test]
+    fn losing_one_of_two_balls_plays_on_by_default() {
+        let (balls, lives) = lose_one_of_two_balls(false);
+        assert_eq!(
+            balls,
+            vec![(Vec3::new(120.0, 40.0, 1.0), Vec2::new(-80.0, 230.0))]
+        );
+        assert_eq!(lives, 3, "Another ball is still in play");
+    }
+
+    /// Loses the only ball with the paddle off-centre and returns where the fresh ball is served.
+    fn serve_x_after_last_ball(keep_balls_in_play: bool) -> f32 {
+        let mut app = test_app();
+        app.add_systems(Update, ball_death_zone);
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .keep_balls_in_play = keep_balls_in_play;
+        app.world_mut().spawn((
+            Transform::from_xyz(150.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - BALL_SIZE - 10.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Transform, With<Ball>>();
+        q.single(app.world()).unwrap().translation.x
+    }
+
+    #[test]
+    fn kept_balls_serve_the_last_one_from_the_paddle() {
+        assert_eq!(serve_x_after_last_ball(false), 0.0);
+        assert_eq!(serve_x_after_last_ball(true), 150.0);
+    }
+
+    /// Drops `falling` of three balls past the death zone, one frame at a time, and returns the
+    /// lives left after each frame along with the number of balls remaining.
+    fn drop_balls_one_by_one(config: GameConfig, falling: usize) -> (Vec<u32>, usize) {
+        let mut app = test_app();
+        app.add_systems(Update, ball_death_zone);
+        app.insert_resource(config);
+        let balls: Vec<Entity> = (0..3)
+            .map(|i| {
+                app.world_mut()
+                    .spawn((
+                        Transform::from_xyz(i as f32 * 60.0, 40.0, 1.0),
+                        Ball,
+                        Velocity(Vec2::new(0.0, BALL_SPEED)),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        let death_y = -WINDOW_HEIGHT / 2.0 - BALL_SIZE;
+        let mut lives = Vec::new();
+        for &ball in &balls[..falling] {
+            app.world_mut()
+                .get_mut::<Transform>(ball)
+                .unwrap()
+                .translation
+                .y = death_y - 10.0;
+            app.update();
+            lives.push(app.world().resource::<Lives>().count);
+        }
+
+        let mut q = app.world_mut().query_filtered::<(), With<Ball>>();
+        (lives, q.iter(app.world()).count())
+    }
+
+    #[test]
+    fn hard_mode_charges_a_life_for_every_lost_ball() {
+        let (lives, remaining) = drop_balls_one_by_one(
+            GameConfig {
+                lose_life_per_ball: true,
+                ..default()
+            },
+            2,
+        );
+        assert_eq!(lives, vec![2, 1]);
+        assert_eq!(remaining, 1, "The third ball plays on");
+    }
+
+    #[test]
+    fn hard_mode_charges_each_ball_lost_in_the_same_frame() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_death_zone);
+        app.world_mut()
+            .resource_mut::<GameConfig>()
+            .lose_life_per_ball = true;
+        for x in [-40.0, 40.0] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, -WINDOW_HEIGHT / 2.0 - BALL_SIZE - 10.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+            ));
+        }
+
+        app.update();
+
+        assert_eq!(app.world().resource::<Lives>().count, 1);
+        let mut q = app.world_mut().query_filtered::<(), With<StuckToPaddle>>();
+        assert_eq!(q.iter(app.world()).count(), 1, "One ball is served again");
+    }
+
+    #[test]
+    fn normal_mode_only_charges_for_the_last_ball() {
+        let (lives, remaining) = drop_balls_one_by_one(GameConfig::default(), 3);
+        assert_eq!(lives, vec![3, 3, 2]);
+        assert_eq!(remaining, 1, "The last ball is served again");
+    }
+
     #[test]
     fn ball_death_zone_saturates_at_zero() {
         let mut app = test_app();
@@ -412,9 +2644,8 @@ mod tests {
         let death_y = -WINDOW_HEIGHT / 2.0 - BALL_SIZE;
         app.world_mut().spawn((
             Transform::from_xyz(0.0, death_y - 10.0, 1.0),
-            Ball {
-                velocity: Vec2::new(0.0, -BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
         ));
 
         app.update();
@@ -423,6 +2654,55 @@ mod tests {
         assert_eq!(lives.count, 0, "Lives should stay at 0 via saturating_sub");
     }
 
+    #[test]
+    fn death_penalty_docks_points_only_when_enabled() {
+        let score_after_death = |death_penalty: bool, score: u32| {
+            let mut app = test_app();
+            app.add_systems(Update, ball_death_zone);
+            app.world_mut().resource_mut::<GameConfig>().death_penalty = death_penalty;
+            app.world_mut().resource_mut::<Scoreboard>().score = score;
+            app.world_mut().spawn((
+                Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - BALL_SIZE - 10.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+            ));
+            app.update();
+            app.world().resource::<Scoreboard>().score
+        };
+
+        assert_eq!(score_after_death(true, 200), 200 - DEATH_PENALTY_POINTS);
+        assert_eq!(
+            score_after_death(true, 20),
+            0,
+            "Penalty should clamp at zero"
+        );
+        assert_eq!(score_after_death(false, 200), 200);
+    }
+
+    #[test]
+    fn practice_mode_deaths_keep_lives() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_death_zone);
+        app.world_mut().resource_mut::<GameConfig>().practice_row = Some(0);
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -WINDOW_HEIGHT / 2.0 - BALL_SIZE - 10.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, -BALL_SPEED)),
+        ));
+
+        app.update();
+
+        assert_eq!(app.world().resource::<Lives>().count, 3);
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(), (With<Ball>, With<StuckToPaddle>)>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            1,
+            "Ball should still be re-served"
+        );
+    }
+
     #[test]
     fn ball_above_death_zone_keeps_lives() {
         let mut app = test_app();
@@ -431,9 +2711,8 @@ mod tests {
         // Ball well above death zone
         app.world_mut().spawn((
             Transform::from_xyz(0.0, 0.0, 1.0),
-            Ball {
-                velocity: Vec2::new(100.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(100.0, BALL_SPEED)),
         ));
 
         app.update();
@@ -465,9 +2744,8 @@ mod tests {
         // Spawn ball at paddle height
         app.world_mut().spawn((
             Transform::from_xyz(ball_x, PADDLE_Y, 1.0),
-            Ball {
-                velocity: Vec2::new(-100.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(-100.0, BALL_SPEED)),
         ));
 
         // Spawn paddle (overlapping ball)
@@ -475,16 +2753,26 @@ mod tests {
             Transform::from_xyz(paddle_x, PADDLE_Y, 0.0),
             Paddle,
             Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
         ));
 
         // Spawn left wall
-        app.world_mut()
-            .spawn((Transform::from_xyz(left_wall_x, 0.0, 0.0), Wall, Collider));
+        app.world_mut().spawn((
+            Transform::from_xyz(left_wall_x, 0.0, 0.0),
+            Wall,
+            Collider,
+            ColliderSize(Vec2::new(
+                WALL_THICKNESS,
+                WINDOW_HEIGHT + WALL_THICKNESS * 2.0,
+            )),
+        ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<(&Transform, &Ball)>();
-        let (transform, ball) = q.iter(app.world()).next().unwrap();
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let (transform, ball_velocity) = q.iter(app.world()).next().unwrap();
 
         // Ball should be clamped to stay within bounds
         assert!(
@@ -496,9 +2784,206 @@ mod tests {
 
         // Velocity should be pointing right (away from wall)
         assert!(
-            ball.velocity.x > 0.0,
+            ball_velocity.0.x > 0.0,
             "Ball velocity.x should be positive (moving right), got {}",
-            ball.velocity.x
+            ball_velocity.0.x
+        );
+    }
+
+    // --- powerup_collision_paddle ---
+
+    #[test]
+    fn catching_wider_paddle_activates_it() {
+        let mut app = test_app();
+        app.add_systems(Update, powerup_collision_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(10.0, PADDLE_Y + 5.0, 0.5),
+            PowerUp(PowerUpType::WiderPaddle),
+        ));
+
+        app.update();
+
+        let active = app.world().resource::<ActivePowerUps>();
+        assert!(active.is_active(PowerUpType::WiderPaddle));
+
+        let mut q = app.world_mut().query::<&PowerUp>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "Caught power-up should despawn"
+        );
+    }
+
+    #[test]
+    fn catching_extra_life_adds_a_life() {
+        let mut app = test_app();
+        app.add_systems(Update, powerup_collision_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.5),
+            PowerUp(PowerUpType::ExtraLife),
+        ));
+
+        app.update();
+
+        assert_eq!(app.world().resource::<Lives>().count, 4);
+        assert!(app.world().resource::<ActivePowerUps>().timers.is_empty());
+    }
+
+    #[test]
+    fn widened_paddle_catches_further_out() {
+        let mut app = test_app();
+        app.add_systems(Update, powerup_collision_paddle);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH * WIDER_PADDLE_SCALE, PADDLE_HEIGHT)),
+        ));
+        // Beyond the normal paddle edge, inside the widened one
+        app.world_mut().spawn((
+            Transform::from_xyz(PADDLE_WIDTH * 0.6 + POWERUP_SIZE.x / 2.0, PADDLE_Y, 0.5),
+            PowerUp(PowerUpType::WiderPaddle),
+        ));
+
+        app.update();
+
+        assert!(
+            app.world()
+                .resource::<ActivePowerUps>()
+                .is_active(PowerUpType::WiderPaddle)
+        );
+    }
+
+    #[test]
+    fn catching_regen_restores_recent_bricks_up_to_cap() {
+        let mut app = test_app();
+        app.add_systems(Update, powerup_collision_paddle);
+
+        // Five destroyed bricks; the most recent one sits where the ball is now
+        let ball_pos = Vec2::new(200.0, 100.0);
+        let size = Vec2::new(BRICK_WIDTH, BRICK_HEIGHT);
+        let mut areas: Vec<Rect> = (0..4)
+            .map(|i| Rect::from_center_size(Vec2::new(-300.0 + i as f32 * 90.0, 200.0), size))
+            .collect();
+        areas.push(Rect::from_center_size(ball_pos, size));
+        app.world_mut().resource_mut::<DestroyedBricks>().bricks = areas.clone();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_translation(ball_pos.extend(1.0)),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.5),
+            PowerUp(PowerUpType::Regen),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Transform, With<Brick>>();
+        let mut restored: Vec<Vec2> = q
+            .iter(app.world())
+            .map(|transform| transform.translation.truncate())
+            .collect();
+        restored.sort_by(|a, b| a.x.total_cmp(&b.x));
+        let expected: Vec<Vec2> = areas[1..4].iter().map(|area| area.center()).collect();
+        assert_eq!(restored, expected, "Newest clear bricks should come back");
+
+        // The skipped ones stay available for a later pickup
+        let left = &app.world().resource::<DestroyedBricks>().bricks;
+        assert_eq!(left, &vec![areas[0], areas[4]]);
+    }
+
+    /// Catches a MultiBall with `ball_count` balls in play and returns the balls afterwards.
+    fn balls_after_multi_ball(ball_count: usize) -> usize {
+        let mut app = test_app();
+        app.add_systems(Update, powerup_collision_paddle);
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            Collider,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        for i in 0..ball_count {
+            app.world_mut().spawn((
+                Transform::from_xyz(i as f32 * 20.0 - 80.0, 100.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ));
+        }
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.5),
+            PowerUp(PowerUpType::MultiBall),
+        ));
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<(), With<Ball>>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn multi_ball_splits_off_extra_balls() {
+        assert_eq!(balls_after_multi_ball(1), 1 + MULTI_BALL_COUNT);
+    }
+
+    #[test]
+    fn multi_ball_stays_within_the_ball_cap() {
+        assert_eq!(balls_after_multi_ball(MAX_BALLS), MAX_BALLS);
+        assert_eq!(balls_after_multi_ball(MAX_BALLS - 1), MAX_BALLS);
+    }
+
+    #[test]
+    fn destroyed_brick_is_recorded() {
+        let mut app = test_app();
+        app.add_systems(Update, ball_collision_bricks);
+
+        let brick_y = 100.0;
+        app.world_mut().spawn((
+            Transform::from_xyz(
+                0.0,
+                brick_y - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0,
+                1.0,
+            ),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, brick_y, 0.0),
+            Brick,
+            Collider,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<DestroyedBricks>().bricks,
+            vec![Rect::from_center_size(
+                Vec2::new(0.0, brick_y),
+                Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)
+            )]
         );
     }
 
@@ -514,15 +2999,16 @@ mod tests {
         // Ball outside right bound, moving right
         app.world_mut().spawn((
             Transform::from_xyz(max_x + 50.0, 0.0, 1.0),
-            Ball {
-                velocity: Vec2::new(100.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(100.0, BALL_SPEED)),
         ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<(&Transform, &Ball)>();
-        let (transform, ball) = q.iter(app.world()).next().unwrap();
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let (transform, ball_velocity) = q.iter(app.world()).next().unwrap();
 
         assert!(
             (transform.translation.x - max_x).abs() < 0.01,
@@ -530,9 +3016,9 @@ mod tests {
             transform.translation.x
         );
         assert!(
-            ball.velocity.x < 0.0,
+            ball_velocity.0.x < 0.0,
             "Ball velocity.x should be negative (moving left), got {}",
-            ball.velocity.x
+            ball_velocity.0.x
         );
     }
 
@@ -546,23 +3032,54 @@ mod tests {
         // Ball outside left bound, but already moving right (inward)
         app.world_mut().spawn((
             Transform::from_xyz(min_x - 50.0, 0.0, 1.0),
-            Ball {
-                velocity: Vec2::new(100.0, BALL_SPEED), // positive = moving right
-            },
+            Ball,
+            Velocity(Vec2::new(100.0, BALL_SPEED)), // positive = moving right
         ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<&Ball>();
-        let ball = q.iter(app.world()).next().unwrap();
+        let mut q = app.world_mut().query_filtered::<&Velocity, With<Ball>>();
+        let ball_velocity = q.iter(app.world()).next().unwrap();
 
         assert!(
-            ball.velocity.x > 0.0,
+            ball_velocity.0.x > 0.0,
             "Ball velocity.x should stay positive (not double-flip), got {}",
-            ball.velocity.x
+            ball_velocity.0.x
         );
     }
 
+    #[test]
+    fn every_ball_is_clamped() {
+        let mut app = test_app();
+        app.add_systems(Update, clamp_ball_to_bounds);
+
+        let max_x = WINDOW_WIDTH / 2.0 - WALL_THICKNESS - BALL_SIZE / 2.0;
+        for x in [-max_x - 50.0, max_x + 50.0] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, 0.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(x.signum() * 100.0, BALL_SPEED)),
+            ));
+        }
+
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        for (transform, ball_velocity) in q.iter(app.world()) {
+            assert!(
+                (transform.translation.x.abs() - max_x).abs() < 0.01,
+                "Ball should be clamped to its wall, got x={}",
+                transform.translation.x
+            );
+            assert!(
+                ball_velocity.0.x * transform.translation.x < 0.0,
+                "Ball should head back inward"
+            );
+        }
+    }
+
     #[test]
     fn ball_within_bounds_unchanged() {
         let mut app = test_app();
@@ -571,15 +3088,16 @@ mod tests {
         // Ball in center, moving normally
         app.world_mut().spawn((
             Transform::from_xyz(0.0, 0.0, 1.0),
-            Ball {
-                velocity: Vec2::new(-150.0, BALL_SPEED),
-            },
+            Ball,
+            Velocity(Vec2::new(-150.0, BALL_SPEED)),
         ));
 
         app.update();
 
-        let mut q = app.world_mut().query::<(&Transform, &Ball)>();
-        let (transform, ball) = q.iter(app.world()).next().unwrap();
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let (transform, ball_velocity) = q.iter(app.world()).next().unwrap();
 
         assert!(
             transform.translation.x.abs() < 0.01,
@@ -587,9 +3105,9 @@ mod tests {
             transform.translation.x
         );
         assert!(
-            (ball.velocity.x - (-150.0)).abs() < 0.01,
+            (ball_velocity.0.x - (-150.0)).abs() < 0.01,
             "Ball velocity should be unchanged, got {}",
-            ball.velocity.x
+            ball_velocity.0.x
         );
     }
 }
@@ -6,31 +6,290 @@ use bevy::prelude::*;
 pub enum GameState {
     #[default]
     Menu,
+    /// Ball held on the paddle before a serve; the paddle moves but physics is frozen.
+    Ready,
     Playing,
     Paused,
+    /// Level editor, opened from the menu; gameplay systems are idle.
+    Editor,
     GameOver,
     Victory,
+    /// Typing initials for a new leaderboard entry after a qualifying run.
+    EnterInitials,
 }
 
 // --- Components ---
 
 #[derive(Component)]
+#[require(PaddleVelocity)]
 pub struct Paddle;
 
+/// The paddle's horizontal speed over the last frame, whatever moved it.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct PaddleVelocity {
+    pub x: f32,
+    /// Paddle x at the end of the last sample; `None` until the first one.
+    pub last_x: Option<f32>,
+}
+
+/// The ball; its `ColliderSize` defaults to the normal size until `GameConfig::ball_size` is applied.
+#[derive(Component)]
+#[require(ColliderSize = ColliderSize(Vec2::splat(BALL_SIZE)), BallSpeedModifier)]
+pub struct Ball;
+
+/// Multiplier on how far a ball travels along its velocity each frame, set by speed assists.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct BallSpeedModifier(pub f32);
+
+impl Default for BallSpeedModifier {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Player credited with the bricks a ball breaks; balls without one score for nobody in
+/// particular.
+#[derive(Component, Clone, Copy)]
+pub struct BallOwner(pub PlayerId);
+
+/// One of the two co-op players.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlayerId {
+    One,
+    Two,
+}
+
+impl PlayerId {
+    /// Returns the player's slot in `Scoreboard::player_scores`.
+    pub fn index(self) -> usize {
+        match self {
+            PlayerId::One => 0,
+            PlayerId::Two => 1,
+        }
+    }
+}
+
+/// Marks a ball resting on the paddle, waiting to be launched.
 #[derive(Component)]
-pub struct Ball {
-    pub velocity: Vec2,
+pub struct StuckToPaddle;
+
+/// Squash-and-stretch played on the paddle sprite after a ball hit; collisions ignore it.
+#[derive(Component)]
+pub struct PaddleSquash {
+    pub timer: Timer,
 }
 
+/// Entrance of a brick at level start: it waits out `delay`, then scales and fades in over
+/// `timer`, and only gets its `Collider` back once in place.
+#[derive(Component)]
+pub struct SpawnAnim {
+    pub delay: f32,
+    pub timer: Timer,
+}
+
+/// White flash on a multi-hit brick that survived a hit, fading back to `color`.
+#[derive(Component)]
+pub struct HitFlash {
+    pub timer: Timer,
+    pub color: Color,
+}
+
+/// Frames left during which a ball ignores the paddle after bouncing off it, so the push-out
+/// can't register a second bounce.
+#[derive(Component)]
+pub struct PaddleCooldown {
+    pub frames: u32,
+}
+
+/// Steers the paddle from code instead of the keyboard; `direction` is -1, 0, or 1.
+#[derive(Component)]
+pub struct AiPaddle {
+    pub direction: f32,
+}
+
+/// Recent ball positions, used to spot a ball trapped bouncing in place.
+#[derive(Component, Default)]
+pub struct PositionHistory {
+    pub positions: std::collections::VecDeque<Vec2>,
+}
+
+/// Linear velocity in world units per second, applied by `apply_velocity`.
+#[derive(Component)]
+pub struct Velocity(pub Vec2);
+
 #[derive(Component)]
 pub struct Brick;
 
+/// Base points a brick is worth before the combo multiplier; bricks without one are worth
+/// `POINTS_PER_BRICK`.
+#[derive(Component, Clone, Copy)]
+pub struct BrickPoints(pub u32);
+
+/// Remaining hits before a brick breaks.
+#[derive(Component)]
+pub struct BrickHealth {
+    pub hits: u32,
+}
+
+/// Brick that drops a `Hazard` when destroyed.
+#[derive(Component)]
+pub struct BombBrick;
+
+/// Brick that fires a slow `Hazard` shot downward each time its cooldown runs out.
+#[derive(Component)]
+pub struct EnemyBrick {
+    pub cooldown: Timer,
+}
+
+impl Default for EnemyBrick {
+    fn default() -> Self {
+        Self {
+            cooldown: Timer::from_seconds(ENEMY_FIRE_COOLDOWN, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Hazard fired by an `EnemyBrick`; at most `ENEMY_SHOT_LIMIT` are in flight at once.
+#[derive(Component)]
+pub struct EnemyShot;
+
+/// Brick that destroys its neighbours when it breaks, chaining through other explosive bricks.
+#[derive(Component)]
+pub struct ExplosiveBrick;
+
+/// Large brick spanning several grid cells whose defeat wins the level. Hits wear its
+/// `BrickHealth` down to zero, where it stays until `check_victory` clears the field.
+#[derive(Component)]
+pub struct BossBrick;
+
+/// Fill of the health bar drawn above a `BossBrick`, as a child of it.
+#[derive(Component)]
+pub struct BossHealthBar;
+
+/// Brick that sends the ball out of its linked portal; breaks after its uses run out.
+#[derive(Component)]
+pub struct Portal {
+    pub link: Entity,
+    pub uses_left: u32,
+}
+
+/// Scripted effect a brick runs when it is destroyed, for scripted and boss levels.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub enum OnDestroy {
+    #[default]
+    Nothing,
+    /// Drops this power-up instead of rolling for a random one.
+    SpawnPowerUp(PowerUpType),
+    /// Places these bricks around the destroyed one, up to `ON_DESTROY_BRICK_LIMIT`.
+    SpawnBricks(Vec<TriggeredBrick>),
+    AddLife,
+}
+
+/// A brick placed by `OnDestroy::SpawnBricks`, relative to the brick that was destroyed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggeredBrick {
+    pub offset: Vec2,
+    pub size: Vec2,
+    pub hits: u32,
+    pub color: Color,
+}
+
+/// Kinds of power-up a destroyed brick can drop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerUpType {
+    WiderPaddle,
+    ExtraLife,
+    /// Trade-off drop: restores a few destroyed bricks.
+    Regen,
+    /// Splits off `MULTI_BALL_COUNT` extra balls, within `MAX_BALLS`.
+    MultiBall,
+}
+
+impl PowerUpType {
+    /// Returns the capsule color used for this power-up.
+    pub fn color(self) -> Color {
+        match self {
+            PowerUpType::WiderPaddle => WIDER_PADDLE_COLOR,
+            PowerUpType::ExtraLife => EXTRA_LIFE_COLOR,
+            PowerUpType::Regen => REGEN_COLOR,
+            PowerUpType::MultiBall => MULTI_BALL_COLOR,
+        }
+    }
+
+    /// Whether the power-up only helps the player, unlike the Regen trade-off.
+    pub fn is_beneficial(self) -> bool {
+        !matches!(self, PowerUpType::Regen)
+    }
+}
+
+/// Falling power-up capsule, collected by catching it with the paddle.
+#[derive(Component)]
+pub struct PowerUp(pub PowerUpType);
+
+/// Falling hazard that costs a life if caught by the paddle.
+#[derive(Component)]
+pub struct Hazard;
+
 #[derive(Component)]
 pub struct Collider;
 
+/// Authoritative collision size, set at spawn and independent of `Transform.scale`.
+#[derive(Component)]
+pub struct ColliderSize(pub Vec2);
+
 #[derive(Component)]
 pub struct Wall;
 
+/// Wall closing the bottom of the playfield in the forgiving `BottomMode`s.
+#[derive(Component)]
+pub struct BottomWall;
+
+/// Bottom wall that breaks after one bounce; returned with each new life.
+#[derive(Component)]
+pub struct BottomShield;
+
+/// Faint line drawn just above the death zone so players can judge how low is too low.
+#[derive(Component)]
+pub struct DangerLine;
+
+/// World-space text announcing a combo; grows and fades via `FadeOut`.
+#[derive(Component)]
+pub struct ComboPopup;
+
+/// Fades an entity's text out over the timer, then despawns it.
+#[derive(Component)]
+pub struct FadeOut {
+    pub timer: Timer,
+}
+
+/// Counts the victory score up from `from` to `to` over the timer.
+#[derive(Component)]
+pub struct ScoreTally {
+    pub from: u32,
+    pub to: u32,
+    pub timer: Timer,
+}
+
+impl ScoreTally {
+    /// Returns the score to display at the current point of the count-up.
+    pub fn value(&self) -> u32 {
+        let span = self.to.saturating_sub(self.from) as f32;
+        self.from + (span * self.timer.fraction()).round() as u32
+    }
+}
+
+/// Entities that belong to a run and are despawned when it restarts.
+pub type RunEntity = Or<(
+    With<Brick>,
+    With<Ball>,
+    With<Paddle>,
+    With<Wall>,
+    With<Hazard>,
+    With<ComboPopup>,
+    With<PowerUp>,
+    With<DangerLine>,
+)>;
+
 // --- UI Markers ---
 
 #[derive(Component)]
@@ -39,36 +298,905 @@ pub struct ScoreboardUi;
 #[derive(Component)]
 pub struct LivesUi;
 
+#[derive(Component)]
+pub struct TimerUi;
+
 #[derive(Component)]
 pub struct OverlayUi;
 
+#[derive(Component)]
+pub struct TutorialHintUi;
+
+/// Menu line showing the current frame limit setting.
+#[derive(Component)]
+pub struct FrameLimitUi;
+
+/// Menu line showing which brick row practice mode drills, if any.
+#[derive(Component)]
+pub struct PracticeRowUi;
+
+/// Run statistics listed on the GameOver/Victory results screen.
+#[derive(Component)]
+pub struct ResultsUi;
+
+/// Continue prompt on the results screen, shown once any score tally is done.
+#[derive(Component)]
+pub struct ResultsPromptUi;
+
+/// Top-10 table on the menu.
+#[derive(Component)]
+pub struct LeaderboardUi;
+
+/// Menu line totalling the games logged in past sessions.
+#[derive(Component)]
+pub struct SessionSummaryUi;
+
+/// Initials being typed for a new leaderboard entry.
+#[derive(Component)]
+pub struct InitialsUi;
+
+/// Arrow above a stuck ball showing the direction it will be served.
+#[derive(Component)]
+pub struct AimArrow;
+
+/// Tray under the paddle shown while a ball is caught on it, waiting to be launched.
+#[derive(Component)]
+pub struct CatchIndicator;
+
+/// Pulsing red border shown while the player is on their last life.
+#[derive(Component)]
+pub struct LastLifeWarningUi;
+
+/// Faint marker on the paddle line showing where the ball will come down.
+#[derive(Component)]
+pub struct LandingIndicator;
+
+/// HUD legend mapping brick colors to their points, shown only when enabled in settings.
+#[derive(Component)]
+pub struct ScoreLegendUi;
+
+/// Points label of the score legend entry for a brick row.
+#[derive(Component)]
+pub struct ScoreLegendPoints(pub usize);
+
 #[derive(Component)]
 pub struct ResumeButton;
 
 #[derive(Component)]
 pub struct QuitButton;
 
+/// Pause menu line explaining that a controller disconnect paused the game.
+#[derive(Component)]
+pub struct ControllerNoteUi;
+
 // --- Pause Menu ---
 
 /// Tracks which button is currently selected in the pause menu (for keyboard navigation).
 #[derive(Resource, Default)]
 pub struct PauseMenuState {
     pub selected: usize, // 0 = Resume, 1 = Quit
+    /// Set when a controller disconnects; cleared when one reconnects.
+    pub controller_disconnected: bool,
 }
 
 /// Number of items in the pause menu.
 pub const PAUSE_MENU_ITEMS: usize = 2;
 
-// Pause menu button colors
-pub const BUTTON_NORMAL: Color = Color::srgb(0.15, 0.15, 0.15);
-pub const BUTTON_HOVERED: Color = Color::srgb(0.35, 0.35, 0.35);
-pub const BUTTON_PRESSED: Color = Color::srgb(0.7, 0.6, 0.1);
+/// How long a menu direction key has been held, so holding it keeps stepping the selection.
+#[derive(Debug, Default)]
+pub struct KeyRepeat {
+    held_secs: f32,
+}
+
+impl KeyRepeat {
+    /// Advances the key by `delta` seconds and returns how many steps it moves: one on the
+    /// press, then one per `MENU_REPEAT_INTERVAL` once held past `MENU_REPEAT_DELAY`.
+    pub fn steps(&mut self, just_pressed: bool, pressed: bool, delta: f32) -> usize {
+        if just_pressed {
+            self.held_secs = 0.0;
+            return 1;
+        }
+        if !pressed {
+            self.held_secs = 0.0;
+            return 0;
+        }
+        let repeats = |held: f32| {
+            if held < MENU_REPEAT_DELAY {
+                0
+            } else {
+                ((held - MENU_REPEAT_DELAY) / MENU_REPEAT_INTERVAL) as usize + 1
+            }
+        };
+        let before = repeats(self.held_secs);
+        self.held_secs += delta;
+        repeats(self.held_secs) - before
+    }
+}
+
+// Pause menu button colors
+pub const BUTTON_NORMAL: Color = Color::srgb(0.15, 0.15, 0.15);
+pub const BUTTON_HOVERED: Color = Color::srgb(0.35, 0.35, 0.35);
+pub const BUTTON_PRESSED: Color = Color::srgb(0.7, 0.6, 0.1);
+
+// --- Messages ---
+
+/// Sent whenever the ball damages or destroys a brick (explosions included).
+#[derive(Message)]
+pub struct BrickHitEvent {
+    pub destroyed: bool,
+    /// The brick's color before the hit, for effects that echo it.
+    pub color: Color,
+}
+
+/// Sent when the ball bounces off a wall; `side` is the side of the wall it hit.
+#[derive(Message)]
+pub struct BounceEvent {
+    pub side: CollisionSide,
+    pub speed: f32,
+}
+
+/// Sent when the score changes during play; the new value is in `GameStats`.
+#[derive(Message)]
+pub struct ScoreChanged;
+
+// --- Resources ---
+
+#[derive(Resource, Default)]
+pub struct Scoreboard {
+    pub score: u32,
+    /// Share of `score` earned by each player's balls, indexed by `PlayerId::index`.
+    pub player_scores: [u32; 2],
+    /// Score when the current board began; the victory tally counts up from it.
+    pub board_start: u32,
+}
+
+impl Scoreboard {
+    /// Adds `points` to the total and to the owning player's score, if the ball has an owner.
+    pub fn credit(&mut self, points: u32, owner: Option<PlayerId>) {
+        self.score += points;
+        if let Some(owner) = owner {
+            self.player_scores[owner.index()] += points;
+        }
+    }
+}
+
+/// The level being played; deeper levels spawn tougher bricks.
+#[derive(Resource)]
+pub struct CurrentLevel {
+    pub number: u32,
+}
+
+impl Default for CurrentLevel {
+    fn default() -> Self {
+        Self { number: 1 }
+    }
+}
+
+/// Serve countdown before a stuck ball can launch, plus any launch press buffered during it.
+#[derive(Resource)]
+pub struct Serve {
+    pub countdown: Timer,
+    pub buffered_at: Option<f32>,
+}
+
+impl Default for Serve {
+    fn default() -> Self {
+        Self {
+            countdown: Timer::from_seconds(SERVE_COUNTDOWN, TimerMode::Once),
+            buffered_at: None,
+        }
+    }
+}
+
+/// Statistics for the current run, gathered from game events.
+#[derive(Resource, Default)]
+pub struct GameStats {
+    pub score: u32,
+    pub bricks_destroyed: u32,
+    pub max_combo: u32,
+    pub balls_lost: u32,
+    /// Seconds spent in the Playing state.
+    pub playtime: f32,
+    /// Whether the board was cleared.
+    pub cleared: bool,
+}
+
+/// Bricks broken since the ball last touched the paddle; each one scores a larger multiple.
+#[derive(Resource, Default)]
+pub struct Combo {
+    pub streak: u32,
+    pub last_points: u32,
+    pub last_position: Vec2,
+}
+
+impl Combo {
+    /// Counts a brick worth `points` broken at `position` and returns the points it scores.
+    pub fn register(&mut self, position: Vec2, points: u32) -> u32 {
+        self.streak += 1;
+        self.last_points = points * self.streak;
+        self.last_position = position;
+        self.last_points
+    }
+}
+
+/// Time left for the combo; it drops back to zero if no brick breaks before this runs out.
+#[derive(Resource)]
+pub struct ComboTimer(pub Timer);
+
+impl Default for ComboTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(COMBO_TIMEOUT, TimerMode::Once))
+    }
+}
+
+/// Areas of bricks destroyed this run, most recent last, for the Regen power-up to restore.
+#[derive(Resource, Default)]
+pub struct DestroyedBricks {
+    pub bricks: Vec<Rect>,
+}
+
+/// Timed power-ups currently in effect, each with its remaining duration.
+#[derive(Resource, Default)]
+pub struct ActivePowerUps {
+    pub timers: Vec<(PowerUpType, Timer)>,
+}
+
+impl ActivePowerUps {
+    /// Starts a timed power-up, or restarts its timer if it is already running.
+    pub fn activate(&mut self, kind: PowerUpType) {
+        self.timers.retain(|(active, _)| *active != kind);
+        self.timers
+            .push((kind, Timer::from_seconds(POWERUP_DURATION, TimerMode::Once)));
+    }
+
+    /// Returns whether a timed power-up of this kind is running.
+    pub fn is_active(&self, kind: PowerUpType) -> bool {
+        self.timers.iter().any(|(active, _)| *active == kind)
+    }
+
+    /// Returns whether a timed power-up of this kind is running with little time left.
+    pub fn is_expiring(&self, kind: PowerUpType) -> bool {
+        self.timers.iter().any(|(active, timer)| {
+            *active == kind && timer.fraction_remaining() < POWERUP_EXPIRY_WARNING
+        })
+    }
+}
+
+/// Serve direction in radians from straight up (positive aims right).
+#[derive(Resource)]
+pub struct AimAngle(pub f32);
+
+impl Default for AimAngle {
+    fn default() -> Self {
+        Self(AIM_DEFAULT_ANGLE)
+    }
+}
+
+impl AimAngle {
+    /// Returns the aim for the next serve: the default, nudged by up to `SERVE_ANGLE_JITTER`
+    /// either way from `rng` when serves are randomized.
+    pub fn for_serve(randomize: bool, rng: &mut GameRng) -> Self {
+        if !randomize {
+            return Self::default();
+        }
+        let jitter = rng.rng.range_f32(-SERVE_ANGLE_JITTER..=SERVE_ANGLE_JITTER);
+        Self(AIM_DEFAULT_ANGLE + jitter)
+    }
+}
+
+/// Countdown for time attack mode; the board must be cleared before it runs out.
+#[derive(Resource, Default)]
+pub struct TimeAttack {
+    pub active: bool,
+    pub remaining: f32,
+}
+
+/// Tunable gameplay configuration; the defaults reproduce the classic game.
+#[derive(Resource, Default)]
+pub struct GameConfig {
+    pub bricks: BrickLayoutConfig,
+    pub paddle_bounce: PaddleBounceConfig,
+    pub ball_size: BallSize,
+    pub bottom_mode: BottomMode,
+    pub victory: VictoryCondition,
+    /// Destroyed bricks roll `PowerUpWeights::drop_chance` for a random power-up.
+    pub powerup_drops: bool,
+    /// Every this-many bricks in one combo chain drop a helpful power-up regardless of the drop
+    /// roll; 0 turns the guarantee off.
+    pub combo_drop_streak: u32,
+    /// Replaces the middle of the brick grid with a `BossBrick` that must be beaten to win.
+    pub boss: bool,
+    pub wall_thickness: WallThickness,
+    /// Caps how fast mouse control can move the paddle; `None` lets it snap to the cursor.
+    pub max_paddle_speed: Option<MaxPaddleSpeed>,
+    /// Hardcore modifier: each lost ball costs `DEATH_PENALTY_POINTS`.
+    pub death_penalty: bool,
+    /// Drills a single brick row with unlimited lives; `None` plays the full grid.
+    pub practice_row: Option<usize>,
+    /// Replaces the level's bricks with a layout generated from the run's seed.
+    pub random_layout: bool,
+    /// Varies each serve's starting aim using the run's seed.
+    pub random_serve: bool,
+    /// Escalating hazard: periodic earthquakes jostle the bricks and knock the ball off line.
+    pub earthquakes: bool,
+    /// Beginner assist: each serve starts aimed toward where most of the bricks are.
+    pub serve_assist: bool,
+    /// Survival rule: once the last ball is lost, the fresh one is served from wherever the
+    /// paddle is instead of its centre.
+    pub keep_balls_in_play: bool,
+    /// Hard rule: every lost ball costs a life on its own while the others play on.
+    pub lose_life_per_ball: bool,
+    /// Bonus scoring: bricks are worth more while several balls are in play, per
+    /// `MULTI_BALL_SCORE_MULTIPLIERS`.
+    pub multi_ball_scoring: bool,
+    /// Per-row scoring: bricks are worth their row's `BRICK_ROW_POINTS` instead of a flat
+    /// `POINTS_PER_BRICK`.
+    pub row_points: bool,
+    /// Escape hatch for stuck balls: `BALL_SKIP_KEY` drops the ball into the death zone. Always
+    /// available in practice, where the re-serve is free.
+    pub ball_skip: bool,
+    /// Backdrop the camera clears to, seen before the background shader renders and around the
+    /// letterboxed playfield.
+    pub clear_color: BackdropColor,
+}
+
+impl GameConfig {
+    /// Returns the brick layout fitted between this config's walls.
+    pub fn fitted_bricks(&self) -> BrickLayoutConfig {
+        self.bricks.fit_within(&PlayfieldBounds::from_config(self))
+    }
+
+    /// Returns the practice row after the current one in the cycle off, first row, ..., last
+    /// row, off.
+    pub fn next_practice_row(&self) -> Option<usize> {
+        match self.practice_row {
+            None => (self.bricks.rows > 0).then_some(0),
+            Some(row) => (row + 1 < self.bricks.rows).then_some(row + 1),
+        }
+    }
+}
+
+/// Fastest the paddle may travel under mouse control, in world units per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MaxPaddleSpeed(pub f32);
+
+/// Wall thickness in world units; walls thicker than `WALL_THICKNESS` grow into the playfield.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WallThickness(pub f32);
+
+impl Default for WallThickness {
+    fn default() -> Self {
+        Self(WALL_THICKNESS)
+    }
+}
+
+/// Camera clear color behind the background shader.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BackdropColor(pub Color);
+
+impl Default for BackdropColor {
+    fn default() -> Self {
+        Self(BACKDROP_COLOR)
+    }
+}
+
+/// What happens at the bottom of the playfield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BottomMode {
+    /// A ball falling past the bottom costs a life.
+    #[default]
+    DeathZone,
+    /// The bottom is a wall for practice; lives are never lost to the bottom.
+    Wall,
+    /// The bottom bounces the ball once per life, then opens up as a death zone.
+    OneBounceShield,
+}
+
+/// How many bricks must be cleared to win; bricks left standing after that are decorative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VictoryCondition {
+    /// Every brick must be destroyed.
+    #[default]
+    AllBricks,
+    /// Destroying this many bricks wins.
+    Count(u32),
+    /// Destroying this percentage of the level's bricks wins.
+    Percent(u32),
+}
+
+impl VictoryCondition {
+    /// Returns whether the level is won with `destroyed` bricks cleared and `remaining` left. A
+    /// level that never had any bricks is never won.
+    pub fn is_met(self, destroyed: usize, remaining: usize) -> bool {
+        match self {
+            _ if destroyed + remaining == 0 => false,
+            _ if remaining == 0 => true,
+            VictoryCondition::AllBricks => false,
+            VictoryCondition::Count(count) => destroyed >= count as usize,
+            VictoryCondition::Percent(percent) => {
+                destroyed * 100 >= (destroyed + remaining) * percent as usize
+            }
+        }
+    }
+}
+
+/// Ball size modifier; the ball's sprite and collider both use its diameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BallSize {
+    Small,
+    #[default]
+    Normal,
+    Big,
+}
+
+impl BallSize {
+    /// Returns the ball's width and height in world units.
+    pub fn diameter(self) -> f32 {
+        match self {
+            BallSize::Small => BALL_SIZE_SMALL,
+            BallSize::Normal => BALL_SIZE,
+            BallSize::Big => BALL_SIZE_BIG,
+        }
+    }
+}
+
+/// Player options; assists default to off.
+#[derive(Resource, Default)]
+pub struct Settings {
+    pub landing_indicator: bool,
+    /// Turns off screen shake, background animation, pulsing, and combo popups.
+    pub reduce_motion: bool,
+    /// Swaps the left and right paddle controls.
+    pub invert_paddle: bool,
+    /// Slows the ball while it is low on the screen, near the paddle.
+    pub paddle_zone_assist: bool,
+    /// Shows the brick color-to-points legend on the HUD.
+    pub score_legend: bool,
+    /// Steers the paddle with the mouse cursor instead of the keyboard.
+    pub mouse_paddle: bool,
+    pub mouse_smoothing: MouseSmoothing,
+    /// Draws an arrow from each ball showing its direction and speed.
+    pub velocity_arrows: bool,
+    /// Draws a faint line just above the death zone.
+    pub danger_line: bool,
+    pub difficulty: Difficulty,
+    pub game_speed: GameSpeed,
+    pub frame_limit: FrameLimit,
+    pub score_format: ScoreFormat,
+    /// Keeps the leaderboard for this session only instead of saving it to disk.
+    pub session_scores: bool,
+}
+
+/// How scores are written out: the HUD label and the digit-group separator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoreFormat {
+    pub label: String,
+    /// Character between groups of three digits; `None` writes the digits unbroken.
+    pub separator: Option<char>,
+}
+
+impl Default for ScoreFormat {
+    fn default() -> Self {
+        Self {
+            label: "Score".to_string(),
+            separator: Some(','),
+        }
+    }
+}
+
+impl ScoreFormat {
+    /// Returns `value` with its digits grouped in threes by the separator.
+    pub fn number(&self, value: u32) -> String {
+        let digits = value.to_string();
+        let Some(separator) = self.separator else {
+            return digits;
+        };
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Returns the labelled score, e.g. "Score: 12,340".
+    pub fn score(&self, value: u32) -> String {
+        format!("{}: {}", self.label, self.number(value))
+    }
+}
+
+/// Multiplier on virtual time, letting players slow the whole game down or speed it up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GameSpeed(pub f32);
+
+impl Default for GameSpeed {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl GameSpeed {
+    /// Returns the multiplier limited to `GAME_SPEED_MIN..=GAME_SPEED_MAX`.
+    pub fn clamped(self) -> f32 {
+        self.0.clamp(GAME_SPEED_MIN, GAME_SPEED_MAX)
+    }
+}
+
+/// Share of the gap to the cursor the mouse paddle closes each 60 Hz frame; 1.0 snaps to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MouseSmoothing(pub f32);
+
+impl Default for MouseSmoothing {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// How frames are paced: synced to the display, or uncapped with an optional frame-rate cap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum FrameLimit {
+    #[default]
+    VSync,
+    Uncapped,
+    Cap60,
+    Cap120,
+    Cap144,
+}
+
+impl FrameLimit {
+    /// Returns the setting after this one, wrapping around, for cycling from the menu.
+    pub fn next(self) -> Self {
+        match self {
+            FrameLimit::VSync => FrameLimit::Uncapped,
+            FrameLimit::Uncapped => FrameLimit::Cap60,
+            FrameLimit::Cap60 => FrameLimit::Cap120,
+            FrameLimit::Cap120 => FrameLimit::Cap144,
+            FrameLimit::Cap144 => FrameLimit::VSync,
+        }
+    }
+
+    /// Returns the window present mode; every setting but VSync presents immediately.
+    pub fn present_mode(self) -> bevy::window::PresentMode {
+        match self {
+            FrameLimit::VSync => bevy::window::PresentMode::AutoVsync,
+            _ => bevy::window::PresentMode::AutoNoVsync,
+        }
+    }
+
+    /// Returns the frame-rate cap enforced by the limiter, if any.
+    pub fn frame_cap(self) -> Option<f32> {
+        match self {
+            FrameLimit::VSync | FrameLimit::Uncapped => None,
+            FrameLimit::Cap60 => Some(60.0),
+            FrameLimit::Cap120 => Some(120.0),
+            FrameLimit::Cap144 => Some(144.0),
+        }
+    }
+
+    /// Returns the name shown in the menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            FrameLimit::VSync => "VSync",
+            FrameLimit::Uncapped => "Uncapped",
+            FrameLimit::Cap60 => "60 FPS",
+            FrameLimit::Cap120 => "120 FPS",
+            FrameLimit::Cap144 => "144 FPS",
+        }
+    }
+}
+
+/// Overall challenge level; tunes power-up drops.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// How often destroyed bricks drop power-ups and how likely each kind is.
+#[derive(Resource, Debug, PartialEq)]
+pub struct PowerUpWeights {
+    /// Chance that a destroyed (non-bomb) brick drops anything.
+    pub drop_chance: f32,
+    /// Relative weight of each kind; zero-weight kinds never drop.
+    pub weights: Vec<(PowerUpType, f32)>,
+}
+
+impl Default for PowerUpWeights {
+    fn default() -> Self {
+        Self::for_difficulty(Difficulty::default())
+    }
+}
+
+impl PowerUpWeights {
+    /// Returns the drop table for a difficulty: Easy favours helpful drops, Hard the Regen
+    /// trade-off.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        let (drop_chance, wider, extra_life, regen, multi_ball) = match difficulty {
+            Difficulty::Easy => (0.2, 0.4, 0.35, 0.05, 0.2),
+            Difficulty::Normal => (POWERUP_DROP_CHANCE, 0.35, 0.35, 0.15, 0.15),
+            Difficulty::Hard => (0.1, 0.35, 0.25, 0.3, 0.1),
+        };
+        Self {
+            drop_chance,
+            weights: vec![
+                (PowerUpType::WiderPaddle, wider),
+                (PowerUpType::ExtraLife, extra_life),
+                (PowerUpType::Regen, regen),
+                (PowerUpType::MultiBall, multi_ball),
+            ],
+        }
+    }
+
+    /// Picks a kind for a uniform `roll` in `[0, 1)`, or `None` if every weight is zero.
+    pub fn pick(&self, roll: f32) -> Option<PowerUpType> {
+        self.pick_where(roll, |_| true)
+    }
+
+    /// Picks a beneficial kind for a uniform `roll` in `[0, 1)`, keeping their relative weights.
+    pub fn pick_beneficial(&self, roll: f32) -> Option<PowerUpType> {
+        self.pick_where(roll, PowerUpType::is_beneficial)
+    }
+
+    /// Picks among the kinds `allowed` accepts, or `None` if all of them have zero weight.
+    fn pick_where(&self, roll: f32, allowed: impl Fn(PowerUpType) -> bool) -> Option<PowerUpType> {
+        let enabled = || {
+            self.weights
+                .iter()
+                .filter(|(kind, weight)| *weight > 0.0 && allowed(*kind))
+        };
+        let total: f32 = enabled().map(|(_, weight)| weight).sum();
+        let mut remaining = roll.clamp(0.0, 1.0) * total;
+
+        for &(kind, weight) in enabled() {
+            if remaining < weight {
+                return Some(kind);
+            }
+            remaining -= weight;
+        }
+        // Rounding can leave a sliver past the last bucket
+        enabled().next_back().map(|(kind, _)| *kind)
+    }
+}
+
+/// Real-time clock for UI animation, so HUD effects keep their pace while gameplay time is
+/// paused, slowed or sped up.
+#[derive(Resource, Default)]
+pub struct UiTime {
+    pub delta: std::time::Duration,
+    pub elapsed: std::time::Duration,
+}
+
+impl UiTime {
+    /// Returns the real time since the last frame, in seconds.
+    pub fn delta_secs(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Returns the real time since startup, in seconds.
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+}
+
+/// Seconds of camera shake left to play.
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    pub remaining: f32,
+}
+
+/// Countdown to the next earthquake, which comes sooner after each one, and the seconds left
+/// of the current quake.
+#[derive(Resource)]
+pub struct Earthquake {
+    pub timer: Timer,
+    pub burst_remaining: f32,
+}
+
+impl Default for Earthquake {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(EARTHQUAKE_INTERVAL, TimerMode::Once),
+            burst_remaining: 0.0,
+        }
+    }
+}
+
+/// Camera framing for the finale zoom: the view centre and orthographic scale (1.0 = whole
+/// playfield).
+#[derive(Resource)]
+pub struct CameraZoom {
+    pub center: Vec2,
+    pub scale: f32,
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Small SplitMix64 generator: the same seed always replays the same rolls.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Creates a generator seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns a fresh seed taken from the system clock.
+    pub fn clock_seed() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniform float in `[0, 1)`.
+    pub fn f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a fair coin flip.
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Returns `true` with probability `p`.
+    pub fn chance(&mut self, p: f32) -> bool {
+        self.f32() < p
+    }
+
+    /// Returns a uniform float in `range`.
+    pub fn range_f32(&mut self, range: std::ops::RangeInclusive<f32>) -> f32 {
+        range.start() + (range.end() - range.start()) * self.f32()
+    }
+
+    /// Returns a uniform integer in `range`.
+    pub fn range_u32(&mut self, range: std::ops::RangeInclusive<u32>) -> u32 {
+        let span = u64::from(range.end() - range.start()) + 1;
+        range.start() + (self.next_u64() % span) as u32
+    }
+}
+
+impl Default for SeededRng {
+    fn default() -> Self {
+        Self::new(Self::clock_seed())
+    }
+}
+
+/// Seeded random source for run outcomes such as power-up drops, so a seed replays a run.
+#[derive(Resource)]
+pub struct GameRng {
+    pub seed: u64,
+    pub rng: SeededRng,
+    /// Whether the next run reuses `seed` instead of rolling a fresh one.
+    pub retry_seed: bool,
+}
+
+impl GameRng {
+    /// Creates a generator seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: SeededRng::new(seed),
+            retry_seed: false,
+        }
+    }
+
+    /// Reseeds for a new run, keeping the current seed only when a retry was requested.
+    pub fn start_run(&mut self) {
+        if !self.retry_seed {
+            self.seed = SeededRng::clock_seed();
+        }
+        *self = Self::new(self.seed);
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new(SeededRng::clock_seed())
+    }
+}
+
+/// Best final score reached this session.
+#[derive(Resource, Default)]
+pub struct HighScore {
+    pub score: u32,
+}
+
+/// One row of the leaderboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub initials: String,
+    pub score: u32,
+    /// Seconds since the Unix epoch when the entry was made.
+    pub timestamp: u64,
+}
+
+/// Best runs, highest score first, capped at `LEADERBOARD_SIZE`.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Returns whether a run scoring `score` earns a place in the table.
+    pub fn qualifies(&self, score: u32) -> bool {
+        score > 0
+            && (self.entries.len() < LEADERBOARD_SIZE
+                || self
+                    .entries
+                    .last()
+                    .is_some_and(|lowest| score > lowest.score))
+    }
+
+    /// Inserts `entry` below any equal scores, evicting the lowest entry past
+    /// `LEADERBOARD_SIZE`, and returns its rank from 0, or `None` if it didn't make the table.
+    pub fn insert(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .partition_point(|other| other.score >= entry.score);
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        self.entries.insert(rank, entry);
+        self.entries.truncate(LEADERBOARD_SIZE);
+        Some(rank)
+    }
+}
+
+/// Score waiting for the player's initials in `GameState::EnterInitials`.
+#[derive(Resource, Default)]
+pub struct InitialsEntry {
+    pub score: u32,
+    pub initials: String,
+}
+
+/// Whether a valid in-progress run is saved, so the menu can offer Continue.
+#[derive(Resource, Default)]
+pub struct SavedRun {
+    pub available: bool,
+}
+
+/// Where the run save and session log are read from and written to when the game closes.
+#[derive(Resource, Debug, Clone)]
+pub struct ExitSavePaths {
+    pub run: std::path::PathBuf,
+    pub session_log: std::path::PathBuf,
+}
 
-// --- Resources ---
+impl Default for ExitSavePaths {
+    fn default() -> Self {
+        Self {
+            run: RUN_SAVE_PATH.into(),
+            session_log: SESSION_LOG_PATH.into(),
+        }
+    }
+}
 
+/// Whether the control hints have already been shown (persisted across runs).
 #[derive(Resource, Default)]
-pub struct Scoreboard {
-    pub score: u32,
+pub struct SeenTutorial {
+    pub seen: bool,
 }
 
 #[derive(Resource)]
@@ -84,21 +1212,278 @@ impl Default for Lives {
 
 // --- Shared Constants ---
 
+// Assets
+pub const BACKGROUND_SHADER_PATH: &str = "shaders/background.wgsl";
+/// Asset directory whose files the game can run without (missing sounds just stay silent).
+pub const OPTIONAL_ASSET_DIR: &str = "sounds/";
+
+// Backdrop
+/// Dark navy the camera clears to, matching the top of the background shader's gradient.
+pub const BACKDROP_COLOR: Color = Color::srgb(0.02, 0.02, 0.08);
+
+// Time
+/// Longest frame step applied to moving entities, so a stalled frame can't warp physics.
+pub const MAX_FRAME_DELTA: f32 = 1.0 / 20.0;
+
+// Save files
+pub const TUTORIAL_FLAG_PATH: &str = "save/seen_tutorial";
+pub const RUN_SAVE_PATH: &str = "save/run.txt";
+pub const ACHIEVEMENTS_PATH: &str = "save/achievements";
+pub const LEVEL_PATH: &str = "save/level";
+/// Format version written at the top of level files; files from other versions are rejected.
+pub const LEVEL_VERSION: u32 = 1;
+pub const SESSION_LOG_PATH: &str = "save/sessions.log";
+pub const LEADERBOARD_PATH: &str = "save/leaderboard.txt";
+
+// Persistence
+/// Format version written at the top of run saves; files from other versions are rejected.
+pub const RUN_SAVE_VERSION: u32 = 2;
+
+// Leaderboard
+pub const LEADERBOARD_SIZE: usize = 10;
+pub const LEADERBOARD_INITIALS_MAX: usize = 3;
+
+// Achievements
+pub const ACHIEVEMENT_COMBO: u32 = 10;
+pub const ACHIEVEMENT_FAST_CLEAR_SECS: f32 = 60.0;
+pub const ACHIEVEMENT_TOAST_DURATION: f32 = 3.0;
+
+// Game speed
+pub const GAME_SPEED_MIN: f32 = 0.5;
+pub const GAME_SPEED_MAX: f32 = 2.0;
+
 // Window
 pub const WINDOW_WIDTH: f32 = 900.0;
 pub const WINDOW_HEIGHT: f32 = 600.0;
 
+// UI layers, bottom to top; the HUD has no `GlobalZIndex` and sits under all of them
+pub const UI_Z_LAST_LIFE_WARNING: GlobalZIndex = GlobalZIndex(5);
+/// Menu, game over, victory, results, and tutorial overlays.
+pub const UI_Z_OVERLAY: GlobalZIndex = GlobalZIndex(8);
+pub const UI_Z_PAUSE_DIM: GlobalZIndex = GlobalZIndex(10);
+pub const UI_Z_PAUSE_MENU: GlobalZIndex = GlobalZIndex(11);
+/// Time attack countdown, kept readable over the pause dim.
+pub const UI_Z_COUNTDOWN: GlobalZIndex = GlobalZIndex(12);
+pub const UI_Z_TOAST: GlobalZIndex = GlobalZIndex(15);
+pub const UI_Z_CONSOLE: GlobalZIndex = GlobalZIndex(20);
+
+// Overlays
+/// Widest an overlay text block grows before it wraps, so lines stay readable on wide windows.
+pub const OVERLAY_MAX_WIDTH: f32 = 720.0;
+/// Margin kept between overlay text and the window sides on narrow windows.
+pub const OVERLAY_SIDE_PADDING: f32 = 24.0;
+
+// Menu navigation
+/// Seconds a menu direction key must be held before the selection starts repeating.
+pub const MENU_REPEAT_DELAY: f32 = 0.4;
+/// Seconds between repeated steps while a menu direction key stays held.
+pub const MENU_REPEAT_INTERVAL: f32 = 0.1;
+
+// Tutorial
+pub const TUTORIAL_HINT_DURATION: f32 = 4.0;
+
+// Multi-ball scoring
+/// Brick score multiplier by the number of balls in play, starting from one ball.
+pub const MULTI_BALL_SCORE_MULTIPLIERS: [f32; 4] = [1.0, 1.5, 2.0, 2.5];
+
+// Death penalty
+/// Points a lost ball costs when the death penalty modifier is on.
+pub const DEATH_PENALTY_POINTS: u32 = 50;
+
+// Ball skip
+/// Key that sends the ball to the death zone when ball skipping is allowed.
+pub const BALL_SKIP_KEY: KeyCode = KeyCode::KeyK;
+
+// Last life warning
+pub const LAST_LIFE_BORDER_WIDTH: f32 = 6.0;
+pub const LAST_LIFE_COLOR: Color = Color::srgb(0.9, 0.1, 0.1);
+/// Pulse rate of the warning border, in radians per second.
+pub const LAST_LIFE_PULSE_SPEED: f32 = 4.0;
+
+// Screen shake
+pub const SCREEN_SHAKE_DURATION: f32 = 0.3;
+/// Largest camera offset at the start of a shake, in world units.
+pub const SCREEN_SHAKE_INTENSITY: f32 = 8.0;
+
+// Earthquakes
+/// Seconds before the first earthquake of a run.
+pub const EARTHQUAKE_INTERVAL: f32 = 25.0;
+/// Seconds each earthquake takes off the wait for the next, down to the minimum.
+pub const EARTHQUAKE_INTERVAL_STEP: f32 = 3.0;
+pub const EARTHQUAKE_INTERVAL_MIN: f32 = 10.0;
+pub const EARTHQUAKE_DURATION: f32 = 0.6;
+/// Fastest a brick drifts along each axis during a quake, in world units per second.
+pub const EARTHQUAKE_DRIFT_SPEED: f32 = 40.0;
+/// Widest turn a quake gives the ball's heading, in radians.
+pub const EARTHQUAKE_BALL_NUDGE: f32 = 0.15;
+
+// Finale zoom
+/// The camera starts zooming toward the bricks once this many or fewer remain.
+pub const ZOOM_BRICK_THRESHOLD: usize = 5;
+/// Closest the finale zoom gets, as a fraction of the playfield.
+pub const ZOOM_MIN_SCALE: f32 = 0.75;
+/// Space kept around the remaining bricks, in world units.
+pub const ZOOM_MARGIN: f32 = 40.0;
+/// How quickly the camera eases toward its target, per second.
+pub const ZOOM_EASE_RATE: f32 = 1.5;
+
+// Landing indicator
+pub const LANDING_INDICATOR_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.25);
+
+// Collider debug view
+pub const COLLIDER_DEBUG_COLOR: Color = Color::srgb(0.2, 1.0, 0.4);
+
+// Velocity arrows
+/// Seconds of travel a ball's velocity arrow spans, so faster balls draw longer arrows.
+pub const VELOCITY_ARROW_SECONDS: f32 = 0.2;
+pub const VELOCITY_ARROW_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+/// Arrow brightness pulse rate, in radians per second.
+pub const VELOCITY_ARROW_PULSE_SPEED: f32 = 6.0;
+
+// Danger line
+/// Height of the danger line above the death zone.
+pub const DANGER_LINE_MARGIN: f32 = 36.0;
+pub const DANGER_LINE_HEIGHT: f32 = 2.0;
+pub const DANGER_LINE_COLOR: Color = Color::srgba(1.0, 0.3, 0.3, 0.3);
+/// Danger line alpha pulse rate, in radians per second.
+pub const DANGER_LINE_PULSE_SPEED: f32 = 2.0;
+
+// Score tally
+pub const SCORE_TALLY_DURATION: f32 = 1.5;
+pub const SCORE_TALLY_TICK_INTERVAL: f32 = 0.06;
+pub const SCORE_TALLY_TICK_SOUND_PATH: &str = "sounds/tick.ogg";
+
+// Bounce sounds
+pub const BOUNCE_SOUND_PATH: &str = "sounds/bounce.ogg";
+/// Pitch of a ceiling bounce relative to a side-wall bounce at the same speed.
+pub const BOUNCE_PITCH_CEILING: f32 = 1.15;
+/// Pitch change per multiple of `BALL_SPEED` the ball is above or below it.
+pub const BOUNCE_PITCH_PER_SPEED: f32 = 0.3;
+pub const BOUNCE_PITCH_MIN: f32 = 0.7;
+pub const BOUNCE_PITCH_MAX: f32 = 1.6;
+
+// Brick shimmer
+/// Idle brightness pulse rate, in radians per second.
+pub const BRICK_SHIMMER_SPEED: f32 = 1.5;
+/// Peak lightness added or removed by the pulse.
+pub const BRICK_SHIMMER_AMOUNT: f32 = 0.04;
+/// Phase offset per world unit, staggering the pulse across the grid.
+pub const BRICK_SHIMMER_PHASE_SCALE: Vec2 = Vec2::new(0.01, 0.02);
+
+// Brick spawn animation
+/// Extra delay before each lower brick row starts its entrance.
+pub const BRICK_SPAWN_ROW_DELAY: f32 = 0.08;
+pub const BRICK_SPAWN_DURATION: f32 = 0.3;
+
+// Brick hit flash
+pub const HIT_FLASH_DURATION: f32 = 0.12;
+
+// Paddle squash
+pub const PADDLE_SQUASH_DURATION: f32 = 0.15;
+/// Peak fraction the paddle widens by (and flattens by) on impact.
+pub const PADDLE_SQUASH_AMOUNT: f32 = 0.25;
+
+// Combo
+/// Seconds without a broken brick before the combo resets.
+pub const COMBO_TIMEOUT: f32 = 3.0;
+
+// Quick restart
+/// Seconds R must be held during play to restart the run.
+pub const QUICK_RESTART_HOLD: f32 = 0.6;
+/// Most seconds between two taps of R that restart the run.
+pub const QUICK_RESTART_DOUBLE_TAP: f32 = 0.35;
+
+// Combo popups
+pub const COMBO_POPUP_DURATION: f32 = 0.8;
+pub const COMBO_POPUP_FONT_SIZE: f32 = 28.0;
+/// Extra scale a popup gains over its lifetime.
+pub const COMBO_POPUP_GROWTH: f32 = 0.6;
+pub const COMBO_POPUP_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
+// Demo
+pub const DEMO_IDLE_DELAY: f32 = 8.0;
+pub const AI_PADDLE_DEAD_ZONE: f32 = 6.0;
+
+// Autopilot
+/// Share of the paddle half-width the autopilot may offset its hit by to aim at a brick.
+pub const AUTOPILOT_MAX_AIM: f32 = 0.8;
+
+// Serve
+pub const SERVE_COUNTDOWN: f32 = 1.0;
+/// How early a launch press may come before the ball is ready and still count.
+pub const LAUNCH_BUFFER_WINDOW: f32 = 0.25;
+
+// Aim
+/// Default serve direction, matching the classic `(0.7, 1.0)` launch vector.
+pub const AIM_DEFAULT_ANGLE: f32 = 0.611;
+/// Widest serve angle either side of vertical.
+pub const AIM_MAX_ANGLE: f32 = std::f32::consts::FRAC_PI_3;
+/// Turn rate while aiming, in radians per second.
+pub const AIM_TURN_SPEED: f32 = 1.5;
+/// Widest serve angle the serve assist picks, for bricks bunched against one wall.
+pub const SERVE_ASSIST_MAX_ANGLE: f32 = 0.5;
+/// Widest nudge either way from the default aim when serves are randomized.
+pub const SERVE_ANGLE_JITTER: f32 = 0.25;
+pub const AIM_ARROW_LENGTH: f32 = 40.0;
+pub const AIM_ARROW_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.6);
+
+// Catch indicator
+pub const CATCH_INDICATOR_HEIGHT: f32 = 4.0;
+/// Space between the paddle's underside and the catch tray.
+pub const CATCH_INDICATOR_GAP: f32 = 3.0;
+pub const CATCH_INDICATOR_COLOR: Color = Color::srgba(0.5, 0.9, 1.0, 0.5);
+
+// Time attack
+pub const TIME_ATTACK_DURATION: f32 = 90.0;
+pub const TIME_ATTACK_BONUS_PER_SECOND: u32 = 5;
+
 // Paddle
 pub const PADDLE_WIDTH: f32 = 120.0;
 pub const PADDLE_HEIGHT: f32 = 20.0;
 pub const PADDLE_Y: f32 = -WINDOW_HEIGHT / 2.0 + 40.0;
 pub const PADDLE_SPEED: f32 = 500.0;
+/// Frame rate `MouseSmoothing` is defined at, so the lag feels the same at any frame rate.
+pub const MOUSE_SMOOTHING_FPS: f32 = 60.0;
 pub const PADDLE_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+/// Bounce angle from vertical for a hit at the very edge of the paddle.
+pub const PADDLE_MAX_BOUNCE_ANGLE: f32 = std::f32::consts::FRAC_PI_4;
+/// Share of the incoming horizontal velocity kept after a paddle bounce.
+pub const PADDLE_BOUNCE_RESIDUAL_X: f32 = 0.3;
+/// Share of the paddle width at each end that acts as a bumper when edge bumpers are on.
+pub const PADDLE_EDGE_BUMPER_ZONE: f32 = 0.15;
+/// Speed multiplier for a ball returned by an edge bumper.
+pub const PADDLE_EDGE_BUMPER_SPEED: f32 = 1.25;
+/// Bounce angle multiplier for a ball returned by an edge bumper.
+pub const PADDLE_EDGE_BUMPER_ANGLE: f32 = 1.2;
+/// Share of the paddle-to-ball horizontal speed difference handed to the ball on contact.
+pub const PADDLE_FRICTION: f32 = 0.0;
+/// Steepest angle from vertical paddle friction can bend a bounce to, so it never skims flat.
+pub const PADDLE_FRICTION_MAX_ANGLE: f32 = 1.2;
 
 // Ball
+/// Frames a ball ignores the paddle after a paddle bounce.
+pub const PADDLE_COOLDOWN_FRAMES: u32 = 3;
+/// Normal ball diameter; see `BallSize` for the modifiers.
 pub const BALL_SIZE: f32 = 16.0;
+pub const BALL_SIZE_SMALL: f32 = 10.0;
+pub const BALL_SIZE_BIG: f32 = 28.0;
 pub const BALL_SPEED: f32 = 350.0;
 pub const BALL_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+pub const BALL_FAST_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+/// Speed at which the ball is fully tinted with `BALL_FAST_COLOR`.
+pub const BALL_FAST_COLOR_SPEED: f32 = BALL_SPEED * 2.0;
+/// Most balls allowed in play at once; every ball spawner stays within it.
+pub const MAX_BALLS: usize = 8;
+/// Frames of position history checked for a ball trapped in place.
+pub const STUCK_BALL_FRAMES: usize = 30;
+/// Net movement below which a ball over the full history counts as trapped.
+pub const STUCK_BALL_MIN_TRAVEL: f32 = 2.0;
+/// Where a trapped ball is put back: midway between the paddle and the lowest bricks.
+pub const STUCK_BALL_RESET_POSITION: Vec2 = Vec2::new(0.0, PADDLE_Y + BRICK_PADDLE_CLEARANCE / 2.0);
+/// Height below which the paddle zone assist slows the ball.
+pub const PADDLE_ZONE_ASSIST_Y: f32 = PADDLE_Y + 120.0;
+/// Ball speed multiplier inside the paddle zone while the assist is on.
+pub const PADDLE_ZONE_ASSIST_SPEED: f32 = 0.8;
 
 // Bricks
 pub const BRICK_WIDTH: f32 = 80.0;
@@ -114,14 +1499,343 @@ pub const BRICK_COLORS: [Color; 5] = [
     Color::srgb(0.3, 0.5, 0.9), // Blue
 ];
 pub const POINTS_PER_BRICK: u32 = 10;
+/// Base points for each row color in `BRICK_COLORS` with per-row scoring on, higher rows worth
+/// more.
+pub const BRICK_ROW_POINTS: [u32; 5] = [30, 25, 20, 15, 10];
+pub const MAX_BRICK_HEALTH: u32 = 3;
+/// Lightness added per extra hit so tougher bricks read brighter.
+pub const BRICK_HEALTH_LIGHTEN: f32 = 0.12;
+pub const PORTAL_BRICK_COLOR: Color = Color::srgb(0.7, 0.3, 0.9);
+pub const PORTAL_USES: u32 = 3;
+pub const BOMB_BRICK_COLOR: Color = Color::srgb(0.2, 0.2, 0.2);
+pub const EXPLOSIVE_BRICK_COLOR: Color = Color::srgb(1.0, 0.45, 0.0);
+pub const ENEMY_BRICK_COLOR: Color = Color::srgb(0.55, 0.1, 0.15);
+/// Hits a `BossBrick` takes before it falls.
+pub const BOSS_HEALTH: u32 = 30;
+/// Grid cells the boss covers, centred horizontally from `BOSS_FIRST_ROW` down.
+pub const BOSS_COLS: usize = 4;
+pub const BOSS_ROWS: usize = 2;
+pub const BOSS_FIRST_ROW: usize = 1;
+pub const BOSS_COLOR: Color = Color::srgb(0.6, 0.1, 0.4);
+pub const BOSS_POINTS: u32 = 500;
+pub const BOSS_BAR_HEIGHT: f32 = 6.0;
+/// Space between the top of the boss and its health bar.
+pub const BOSS_BAR_GAP: f32 = 4.0;
+pub const BOSS_BAR_COLOR: Color = Color::srgb(0.9, 0.15, 0.15);
+/// Centre-to-centre reach of an explosion; covers the eight surrounding bricks.
+pub const EXPLOSION_RADIUS: f32 = 100.0;
+pub const BRICK_GRID_TOP_MARGIN: f32 = 80.0;
+/// Minimum space between the bottom brick row and the paddle.
+pub const BRICK_PADDLE_CLEARANCE: f32 = 150.0;
+
+// Random layout
+/// Chance that a whole column of a random layout is left empty.
+pub const RANDOM_LAYOUT_GAP_CHANCE: f32 = 0.15;
+
+// Hazards
+pub const HAZARD_SIZE: f32 = 14.0;
+pub const HAZARD_SPEED: f32 = 180.0;
+pub const HAZARD_COLOR: Color = Color::srgb(1.0, 0.35, 0.1);
+/// Seconds between shots from each enemy brick.
+pub const ENEMY_FIRE_COOLDOWN: f32 = 4.0;
+pub const ENEMY_SHOT_SPEED: f32 = 110.0;
+pub const ENEMY_SHOT_LIMIT: usize = 2;
+
+// Power-ups
+pub const POWERUP_SIZE: Vec2 = Vec2::new(30.0, 14.0);
+pub const POWERUP_SPEED: f32 = 150.0;
+pub const POWERUP_DURATION: f32 = 10.0;
+/// Share of a power-up's duration left when its effect starts blinking as a warning.
+pub const POWERUP_EXPIRY_WARNING: f32 = 0.2;
+/// Blinks per second of an expiring power-up's effect.
+pub const POWERUP_BLINK_RATE: f32 = 6.0;
+/// Alpha of the paddle during the off half of an expiry blink.
+pub const POWERUP_BLINK_ALPHA: f32 = 0.35;
+/// Chance that a destroyed (non-bomb) brick drops a power-up on Normal difficulty.
+pub const POWERUP_DROP_CHANCE: f32 = 0.15;
+pub const WIDER_PADDLE_SCALE: f32 = 1.5;
+pub const WIDER_PADDLE_COLOR: Color = Color::srgb(0.2, 0.6, 1.0);
+pub const EXTRA_LIFE_COLOR: Color = Color::srgb(0.3, 0.9, 0.3);
+pub const REGEN_COLOR: Color = Color::srgb(0.8, 0.2, 0.9);
+/// Most bricks a single Regen pickup restores.
+pub const REGEN_BRICK_COUNT: usize = 3;
+pub const REGEN_BRICK_COLOR: Color = Color::srgb(0.6, 0.3, 0.7);
+pub const MULTI_BALL_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+/// Extra balls a single MultiBall pickup splits off.
+pub const MULTI_BALL_COUNT: usize = 2;
+/// Angle in radians between the split balls' headings.
+pub const MULTI_BALL_SPREAD: f32 = 0.35;
+/// Height above the paddle where a console-spawned power-up starts falling.
+pub const CONSOLE_POWERUP_HEIGHT: f32 = 200.0;
+
+// Brick triggers
+/// Most bricks one `OnDestroy::SpawnBricks` trigger places, so a script can't flood the field.
+pub const ON_DESTROY_BRICK_LIMIT: usize = 6;
 
 // Walls
 pub const WALL_THICKNESS: f32 = 10.0;
+/// Thinnest and thickest walls the console accepts.
+pub const WALL_THICKNESS_MIN: f32 = 2.0;
+pub const WALL_THICKNESS_MAX: f32 = 60.0;
 pub const WALL_COLOR: Color = Color::srgb(0.3, 0.3, 0.3);
 
-// --- Collision Helper ---
+// --- Brick Layout ---
+
+/// Configured size and spacing of the brick grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrickLayoutConfig {
+    pub cols: usize,
+    pub rows: usize,
+    pub brick_width: f32,
+    pub brick_height: f32,
+    pub gap: f32,
+    /// Distance from the top of the window to the centre of the first row.
+    pub top_margin: f32,
+    /// Horizontal shift of the grid from the centre of the playfield; positive moves it right.
+    pub offset_x: f32,
+}
+
+impl Default for BrickLayoutConfig {
+    fn default() -> Self {
+        Self {
+            cols: BRICK_COLS,
+            rows: BRICK_ROWS,
+            brick_width: BRICK_WIDTH,
+            brick_height: BRICK_HEIGHT,
+            gap: BRICK_GAP,
+            top_margin: BRICK_GRID_TOP_MARGIN,
+            offset_x: 0.0,
+        }
+    }
+}
 
+impl BrickLayoutConfig {
+    /// Returns the layout with bricks and gaps scaled down uniformly so the grid fits between
+    /// default-thickness side walls and stays clear of the paddle, and the horizontal offset
+    /// limited so the grid doesn't cross a wall; layouts that already fit are unchanged.
+    pub fn fit_to_playfield(self) -> Self {
+        self.fit_within(&PlayfieldBounds::new(BALL_SIZE, WALL_THICKNESS))
+    }
+
+    /// Returns the layout scaled down like `fit_to_playfield`, but between the side edges of
+    /// `bounds`.
+    pub fn fit_within(self, bounds: &PlayfieldBounds) -> Self {
+        if self.cols == 0 || self.rows == 0 {
+            return self;
+        }
+
+        let interior_width = bounds.size().x - WALL_THICKNESS * 2.0;
+        let natural_width = self.cols as f32 * (self.brick_width + self.gap) - self.gap;
+
+        // From the first row's centre down to the last row's bottom edge
+        let available_height =
+            WINDOW_HEIGHT / 2.0 - self.top_margin - (PADDLE_Y + BRICK_PADDLE_CLEARANCE);
+        let natural_height =
+            (self.rows - 1) as f32 * (self.brick_height + self.gap) + self.brick_height / 2.0;
+
+        let scale = (interior_width / natural_width)
+            .min(available_height / natural_height)
+            .clamp(0.0, 1.0);
+        let max_offset = ((interior_width - natural_width * scale) / 2.0).max(0.0);
+
+        Self {
+            brick_width: self.brick_width * scale,
+            brick_height: self.brick_height * scale,
+            gap: self.gap * scale,
+            offset_x: self.offset_x.clamp(-max_offset, max_offset),
+            ..self
+        }
+    }
+}
+
+/// Horizontal layout of the brick grid, scaled down when needed to fit between the side walls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrickGridLayout {
+    pub cols: usize,
+    pub brick_width: f32,
+    pub gap: f32,
+    pub offset_x: f32,
+}
+
+impl BrickGridLayout {
+    /// Builds the horizontal layout of a configured grid, fitted to the playfield.
+    pub fn from_config(config: &BrickLayoutConfig) -> Self {
+        let fitted = config.fit_to_playfield();
+        Self {
+            cols: fitted.cols,
+            brick_width: fitted.brick_width,
+            gap: fitted.gap,
+            offset_x: fitted.offset_x,
+        }
+    }
+
+    /// Returns the total width of the grid, edge to edge.
+    pub fn width(&self) -> f32 {
+        self.cols as f32 * (self.brick_width + self.gap) - self.gap
+    }
+
+    /// Returns the x-coordinate of the center of the brick in column `col`.
+    pub fn column_x(&self, col: usize) -> f32 {
+        self.offset_x - self.width() / 2.0
+            + self.brick_width / 2.0
+            + col as f32 * (self.brick_width + self.gap)
+    }
+}
+
+/// Returns how many more balls may be spawned with `balls` already in play.
+pub fn ball_headroom(balls: usize) -> usize {
+    MAX_BALLS.saturating_sub(balls)
+}
+
+/// Returns the base points of a brick in `row`: its row's `BRICK_ROW_POINTS` with per-row scoring
+/// on, otherwise `POINTS_PER_BRICK`.
+pub fn brick_row_points(row: usize, row_points: bool) -> u32 {
+    if row_points {
+        BRICK_ROW_POINTS[row % BRICK_ROW_POINTS.len()]
+    } else {
+        POINTS_PER_BRICK
+    }
+}
+
+/// Returns the hits needed to break a brick in `row` of a `rows`-row grid on `level`.
+/// Bricks gain a hit every two levels, with the upper half of the grid one level ahead.
+pub fn brick_health(level: u32, row: usize, rows: usize) -> u32 {
+    let top_rows_bonus = if row < rows / 2 { 1 } else { 0 };
+    let extra_hits = (level.saturating_sub(1) + top_rows_bonus) / 2;
+    (1 + extra_hits).min(MAX_BRICK_HEALTH)
+}
+
+/// Returns the sprite color for a brick with `hits` remaining.
+pub fn brick_health_color(base: Color, hits: u32) -> Color {
+    base.lighter(BRICK_HEALTH_LIGHTEN * hits.saturating_sub(1) as f32)
+}
+
+// --- Paddle Bounce ---
+
+/// Whether paddle bounces are steered by where the ball lands.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PaddleSteering {
+    /// Hits away from the centre angle the ball outward.
+    #[default]
+    OffsetBased,
+    /// The paddle reflects like a wall, keeping the incoming angle.
+    PureReflect,
+}
+
+/// How the paddle steers the ball depending on where it is hit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaddleBounceConfig {
+    pub steering: PaddleSteering,
+    /// Outgoing angle from vertical, in radians, for a hit at the paddle edge.
+    pub max_angle: f32,
+    /// Share of the incoming horizontal velocity mixed into the outgoing one.
+    pub residual_x: f32,
+    /// Turns the paddle ends into bumpers that return the ball steeper and faster.
+    pub edge_bumpers: bool,
+    /// Share of the paddle-to-ball horizontal speed difference added to the ball on contact,
+    /// so a moving paddle drags it along; 0 turns it off.
+    pub friction: f32,
+}
+
+impl Default for PaddleBounceConfig {
+    fn default() -> Self {
+        Self {
+            steering: PaddleSteering::default(),
+            max_angle: PADDLE_MAX_BOUNCE_ANGLE,
+            residual_x: PADDLE_BOUNCE_RESIDUAL_X,
+            edge_bumpers: false,
+            friction: PADDLE_FRICTION,
+        }
+    }
+}
+
+// --- Playfield Bounds ---
+
+/// Edges of the logical playfield (the walls' inner faces) and the line a ball is lost below.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayfieldBounds {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+    pub death_y: f32,
+    pub wall_thickness: f32,
+}
+
+impl PlayfieldBounds {
+    /// Builds the bounds for a ball of `ball_diameter`, which sets how far below it is lost,
+    /// between walls of `wall_thickness`. Extra thickness moves the side and top edges inward.
+    pub fn new(ball_diameter: f32, wall_thickness: f32) -> Self {
+        let inset = (wall_thickness - WALL_THICKNESS).max(0.0);
+        Self {
+            left: -WINDOW_WIDTH / 2.0 + inset,
+            right: WINDOW_WIDTH / 2.0 - inset,
+            top: WINDOW_HEIGHT / 2.0 - inset,
+            bottom: -WINDOW_HEIGHT / 2.0,
+            death_y: -WINDOW_HEIGHT / 2.0 - ball_diameter,
+            wall_thickness,
+        }
+    }
+
+    /// Builds the bounds for the configured ball size and wall thickness.
+    pub fn from_config(config: &GameConfig) -> Self {
+        Self::new(config.ball_size.diameter(), config.wall_thickness.0)
+    }
+
+    /// Returns the playfield's width and height.
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.right - self.left, self.top - self.bottom)
+    }
+
+    /// Returns the height of the danger line drawn just above the death zone.
+    pub fn danger_line_y(&self) -> f32 {
+        self.death_y + DANGER_LINE_MARGIN
+    }
+
+    /// Clamps a paddle of `width` centred at `x` to stay inside the side edges.
+    pub fn clamp_paddle_x(&self, x: f32, width: f32) -> f32 {
+        x.clamp(self.left + width / 2.0, self.right - width / 2.0)
+    }
+
+    /// Returns the lowest and highest x a ball of `width` may sit at, `WALL_THICKNESS` in from
+    /// each side like the brick layout's interior.
+    pub fn ball_x_range(&self, width: f32) -> (f32, f32) {
+        (
+            self.left + WALL_THICKNESS + width / 2.0,
+            self.right - WALL_THICKNESS - width / 2.0,
+        )
+    }
+}
+
+// --- Letterbox ---
+
+/// Scale and offset that fit the logical playfield into a window while preserving aspect ratio.
 #[derive(Debug, PartialEq)]
+pub struct Letterbox {
+    pub scale: f32,
+    pub offset: Vec2,
+}
+
+impl Letterbox {
+    /// Fits the playfield into `window_size`, centered between equal bars on the excess axis.
+    pub fn fit(window_size: Vec2) -> Self {
+        let scale = (window_size.x / WINDOW_WIDTH).min(window_size.y / WINDOW_HEIGHT);
+        let size = Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT) * scale;
+        Self {
+            scale,
+            offset: (window_size - size) / 2.0,
+        }
+    }
+
+    /// Returns the on-screen size of the scaled playfield.
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT) * self.scale
+    }
+}
+
+// --- Collision Helper ---
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CollisionSide {
     Top,
     Bottom,
@@ -272,20 +1986,24 @@ mod tests {
 
     #[test]
     fn window_dimensions_positive() {
-        assert!(WINDOW_WIDTH > 0.0);
-        assert!(WINDOW_HEIGHT > 0.0);
+        const {
+            assert!(WINDOW_WIDTH > 0.0);
+            assert!(WINDOW_HEIGHT > 0.0);
+        }
     }
 
     #[test]
     fn entity_dimensions_positive() {
-        assert!(PADDLE_WIDTH > 0.0);
-        assert!(PADDLE_HEIGHT > 0.0);
-        assert!(BALL_SIZE > 0.0);
-        assert!(BRICK_WIDTH > 0.0);
-        assert!(BRICK_HEIGHT > 0.0);
-        assert!(WALL_THICKNESS > 0.0);
-        assert!(PADDLE_SPEED > 0.0);
-        assert!(BALL_SPEED > 0.0);
+        const {
+            assert!(PADDLE_WIDTH > 0.0);
+            assert!(PADDLE_HEIGHT > 0.0);
+            assert!(BALL_SIZE > 0.0);
+            assert!(BRICK_WIDTH > 0.0);
+            assert!(BRICK_HEIGHT > 0.0);
+            assert!(WALL_THICKNESS > 0.0);
+            assert!(PADDLE_SPEED > 0.0);
+            assert!(BALL_SPEED > 0.0);
+        }
     }
 
     #[test]
@@ -294,6 +2012,349 @@ mod tests {
         assert!(grid_width < WINDOW_WIDTH, "Brick grid wider than window");
     }
 
+    // --- BrickGridLayout tests ---
+
+    #[test]
+    fn default_grid_layout_keeps_constant_sizes() {
+        let layout = BrickGridLayout::from_config(&BrickLayoutConfig::default());
+        assert_eq!(layout.brick_width, BRICK_WIDTH);
+        assert_eq!(layout.gap, BRICK_GAP);
+    }
+
+    #[test]
+    fn grid_layout_is_centered() {
+        let layout = BrickGridLayout::from_config(&BrickLayoutConfig::default());
+        let left = layout.column_x(0);
+        let right = layout.column_x(BRICK_COLS - 1);
+        assert!(
+            (left + right).abs() < 0.01,
+            "Grid should be centered, got left={left} right={right}"
+        );
+    }
+
+    #[test]
+    fn oversized_grid_fits_between_walls() {
+        let cols = 40;
+        let layout = BrickGridLayout::from_config(&BrickLayoutConfig { cols, ..default() });
+        let limit = WINDOW_WIDTH / 2.0 - WALL_THICKNESS;
+
+        let left = layout.column_x(0);
+        let right = layout.column_x(cols - 1);
+        assert!(left > -limit, "Left-most brick outside walls, got x={left}");
+        assert!(
+            right < limit,
+            "Right-most brick outside walls, got x={right}"
+        );
+        assert!(
+            layout.width() <= WINDOW_WIDTH - WALL_THICKNESS * 2.0 + 0.01,
+            "Grid wider than playfield interior, got {}",
+            layout.width()
+        );
+        assert!(
+            layout.brick_width < BRICK_WIDTH,
+            "Bricks should shrink to fit"
+        );
+    }
+
+    #[test]
+    fn default_layout_config_already_fits() {
+        let config = BrickLayoutConfig::default();
+        assert_eq!(config.fit_to_playfield(), config);
+    }
+
+    #[test]
+    fn tall_layout_config_shrinks_to_clear_paddle() {
+        let config = BrickLayoutConfig {
+            rows: 20,
+            ..default()
+        };
+        let fitted = config.fit_to_playfield();
+
+        let last_row_bottom = WINDOW_HEIGHT / 2.0
+            - fitted.top_margin
+            - (fitted.rows - 1) as f32 * (fitted.brick_height + fitted.gap)
+            - fitted.brick_height / 2.0;
+        assert!(last_row_bottom >= PADDLE_Y + BRICK_PADDLE_CLEARANCE - 0.01);
+        assert!(fitted.brick_height < BRICK_HEIGHT);
+        // Bricks keep their proportions
+        assert!(
+            (fitted.brick_width / fitted.brick_height - BRICK_WIDTH / BRICK_HEIGHT).abs() < 0.001
+        );
+    }
+
+    // --- SeededRng tests ---
+
+    #[test]
+    fn same_seed_replays_the_same_rolls() {
+        let rolls = |seed: u64| {
+            let mut rng = SeededRng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(3), rolls(3));
+        assert_ne!(rolls(3), rolls(4));
+    }
+
+    #[test]
+    fn rolls_stay_in_range() {
+        let mut rng = SeededRng::new(11);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&rng.f32()));
+            assert!((-0.5..=0.5).contains(&rng.range_f32(-0.5..=0.5)));
+            assert!((2..=3).contains(&rng.range_u32(2..=3)));
+        }
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+
+    // --- ball_headroom tests ---
+
+    #[test]
+    fn ball_headroom_is_zero_at_the_cap() {
+        assert_eq!(ball_headroom(0), MAX_BALLS);
+        assert_eq!(ball_headroom(MAX_BALLS - 3), 3);
+        assert_eq!(ball_headroom(MAX_BALLS), 0);
+        assert_eq!(ball_headroom(MAX_BALLS + 2), 0);
+    }
+
+    // --- brick_health tests ---
+
+    #[test]
+    fn first_level_bricks_break_in_one_hit() {
+        for row in 0..BRICK_ROWS {
+            assert_eq!(brick_health(1, row, BRICK_ROWS), 1, "Row {row} on level 1");
+        }
+    }
+
+    #[test]
+    fn deeper_levels_spawn_tougher_bricks() {
+        for row in 0..BRICK_ROWS {
+            assert!(
+                brick_health(3, row, BRICK_ROWS) > brick_health(1, row, BRICK_ROWS),
+                "Row {row} should be tougher on level 3 than level 1"
+            );
+        }
+    }
+
+    #[test]
+    fn brick_health_is_capped() {
+        assert_eq!(brick_health(5, 0, BRICK_ROWS), MAX_BRICK_HEALTH);
+        assert_eq!(
+            brick_health(100, BRICK_ROWS - 1, BRICK_ROWS),
+            MAX_BRICK_HEALTH
+        );
+    }
+
+    #[test]
+    fn top_half_bonus_follows_the_configured_row_count() {
+        // Row 3 is in the top half of an eight-row grid but not of a five-row one
+        assert_eq!(brick_health(2, 3, 8), 2);
+        assert_eq!(brick_health(2, 3, 5), 1);
+    }
+
+    // --- PowerUpWeights tests ---
+
+    fn rolls() -> impl Iterator<Item = f32> {
+        (0..100).map(|i| i as f32 / 100.0).chain([0.999_999])
+    }
+
+    #[test]
+    fn all_weight_on_one_type_always_picks_it() {
+        let weights = PowerUpWeights {
+            drop_chance: 1.0,
+            weights: vec![
+                (PowerUpType::WiderPaddle, 0.0),
+                (PowerUpType::ExtraLife, 3.0),
+                (PowerUpType::Regen, 0.0),
+            ],
+        };
+        for roll in rolls() {
+            assert_eq!(weights.pick(roll), Some(PowerUpType::ExtraLife));
+        }
+    }
+
+    #[test]
+    fn zero_weight_types_are_never_picked() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            let mut weights = PowerUpWeights::for_difficulty(difficulty);
+            weights.weights[2].1 = 0.0;
+            for roll in rolls() {
+                assert_ne!(weights.pick(roll), Some(PowerUpType::Regen));
+            }
+        }
+
+        let none = PowerUpWeights {
+            drop_chance: 1.0,
+            weights: vec![(PowerUpType::Regen, 0.0)],
+        };
+        assert_eq!(none.pick(0.5), None);
+    }
+
+    #[test]
+    fn beneficial_pick_skips_regen() {
+        let weights = PowerUpWeights {
+            drop_chance: 1.0,
+            weights: vec![
+                (PowerUpType::WiderPaddle, 0.1),
+                (PowerUpType::ExtraLife, 0.0),
+                (PowerUpType::Regen, 10.0),
+            ],
+        };
+        for roll in rolls() {
+            assert_eq!(
+                weights.pick_beneficial(roll),
+                Some(PowerUpType::WiderPaddle)
+            );
+        }
+    }
+
+    #[test]
+    fn easy_favours_helpful_drops_over_hard() {
+        let easy = PowerUpWeights::for_difficulty(Difficulty::Easy);
+        let hard = PowerUpWeights::for_difficulty(Difficulty::Hard);
+        assert!(easy.drop_chance > hard.drop_chance);
+        assert!(easy.weights[2].1 < hard.weights[2].1);
+    }
+
+    // --- PlayfieldBounds tests ---
+
+    #[test]
+    fn playfield_bounds_match_window_constants() {
+        let bounds = PlayfieldBounds::from_config(&GameConfig::default());
+        assert_eq!(bounds.left, -WINDOW_WIDTH / 2.0);
+        assert_eq!(bounds.right, WINDOW_WIDTH / 2.0);
+        assert_eq!(bounds.top, WINDOW_HEIGHT / 2.0);
+        assert_eq!(bounds.bottom, -WINDOW_HEIGHT / 2.0);
+        assert_eq!(bounds.death_y, -WINDOW_HEIGHT / 2.0 - BALL_SIZE);
+        assert_eq!(bounds.size(), Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT));
+    }
+
+    #[test]
+    fn thicker_walls_shrink_the_playfield() {
+        let config = GameConfig {
+            wall_thickness: WallThickness(WALL_THICKNESS + 30.0),
+            ..default()
+        };
+        let bounds = PlayfieldBounds::from_config(&config);
+        assert_eq!(bounds.left, -WINDOW_WIDTH / 2.0 + 30.0);
+        assert_eq!(bounds.right, WINDOW_WIDTH / 2.0 - 30.0);
+        assert_eq!(bounds.top, WINDOW_HEIGHT / 2.0 - 30.0);
+        assert_eq!(bounds.bottom, -WINDOW_HEIGHT / 2.0);
+
+        let fitted = GameConfig {
+            bricks: BrickLayoutConfig {
+                cols: 40,
+                ..default()
+            },
+            ..config
+        }
+        .fitted_bricks();
+        let layout = BrickGridLayout::from_config(&fitted);
+        assert!(layout.width() <= bounds.size().x - WALL_THICKNESS * 2.0 + 0.01);
+    }
+
+    #[test]
+    fn playfield_death_line_follows_ball_size() {
+        let config = GameConfig {
+            ball_size: BallSize::Big,
+            ..default()
+        };
+        let bounds = PlayfieldBounds::from_config(&config);
+        assert_eq!(bounds.death_y, -WINDOW_HEIGHT / 2.0 - BALL_SIZE_BIG);
+    }
+
+    #[test]
+    fn playfield_paddle_and_ball_limits_match_constants() {
+        let bounds = PlayfieldBounds::new(BALL_SIZE, WALL_THICKNESS);
+        let max_paddle_x = WINDOW_WIDTH / 2.0 - PADDLE_WIDTH / 2.0;
+        assert_eq!(bounds.clamp_paddle_x(1000.0, PADDLE_WIDTH), max_paddle_x);
+        assert_eq!(bounds.clamp_paddle_x(-1000.0, PADDLE_WIDTH), -max_paddle_x);
+        assert_eq!(bounds.clamp_paddle_x(12.0, PADDLE_WIDTH), 12.0);
+
+        let max_ball_x = WINDOW_WIDTH / 2.0 - WALL_THICKNESS - BALL_SIZE / 2.0;
+        assert_eq!(bounds.ball_x_range(BALL_SIZE), (-max_ball_x, max_ball_x));
+    }
+
+    // --- GameConfig tests ---
+
+    #[test]
+    fn practice_row_cycles_through_every_row_then_off() {
+        let mut config = GameConfig::default();
+        let mut seen = Vec::new();
+        loop {
+            config.practice_row = config.next_practice_row();
+            match config.practice_row {
+                Some(row) => seen.push(row),
+                None => break,
+            }
+        }
+        assert_eq!(seen, (0..BRICK_ROWS).collect::<Vec<_>>());
+    }
+
+    // --- UI layer tests ---
+
+    #[test]
+    fn ui_layers_are_strictly_ordered() {
+        let layers = [
+            UI_Z_LAST_LIFE_WARNING,
+            UI_Z_OVERLAY,
+            UI_Z_PAUSE_DIM,
+            UI_Z_PAUSE_MENU,
+            UI_Z_COUNTDOWN,
+            UI_Z_TOAST,
+            UI_Z_CONSOLE,
+        ];
+        assert!(layers[0].0 > 0, "Layers should all sit above the HUD");
+        for pair in layers.windows(2) {
+            assert!(
+                pair[0].0 < pair[1].0,
+                "{:?} should be below {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    // --- Letterbox tests ---
+
+    #[test]
+    fn letterbox_matches_window_of_same_size() {
+        let letterbox = Letterbox::fit(Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT));
+        assert_eq!(letterbox.scale, 1.0);
+        assert_eq!(letterbox.offset, Vec2::ZERO);
+    }
+
+    #[test]
+    fn letterbox_adds_side_bars_for_wide_window() {
+        let window = Vec2::new(WINDOW_WIDTH * 3.0, WINDOW_HEIGHT * 1.5);
+        let letterbox = Letterbox::fit(window);
+
+        assert!(
+            letterbox.offset.x > 0.0,
+            "Wide window should have side bars"
+        );
+        assert_eq!(
+            letterbox.offset.y, 0.0,
+            "Wide window should have no top bars"
+        );
+
+        let size = letterbox.size();
+        assert!(
+            (size.x / size.y - WINDOW_WIDTH / WINDOW_HEIGHT).abs() < 0.001,
+            "Aspect ratio should be preserved, got {size:?}"
+        );
+        assert!((letterbox.offset.x * 2.0 + size.x - window.x).abs() < 0.01);
+    }
+
+    #[test]
+    fn letterbox_adds_top_bars_for_tall_window() {
+        let letterbox = Letterbox::fit(Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT * 2.0));
+        assert_eq!(letterbox.offset.x, 0.0);
+        assert!(
+            letterbox.offset.y > 0.0,
+            "Tall window should have top/bottom bars"
+        );
+    }
+
     #[test]
     fn default_resources_valid() {
         let scoreboard = Scoreboard::default();
@@ -302,4 +2363,33 @@ mod tests {
         let lives = Lives::default();
         assert!(lives.count > 0);
     }
+
+    // --- ScoreFormat tests ---
+
+    #[test]
+    fn score_format_groups_digits_in_threes() {
+        let format = ScoreFormat::default();
+        assert_eq!(format.number(0), "0");
+        assert_eq!(format.number(7), "7");
+        assert_eq!(format.number(999), "999");
+        assert_eq!(format.number(1_000), "1,000");
+        assert_eq!(format.number(12_340), "12,340");
+        assert_eq!(format.number(1_234_567), "1,234,567");
+        assert_eq!(format.number(u32::MAX), "4,294,967,295");
+    }
+
+    #[test]
+    fn score_format_uses_the_configured_label_and_separator() {
+        let dotted = ScoreFormat {
+            label: "Punkte".to_string(),
+            separator: Some('.'),
+        };
+        assert_eq!(dotted.score(1_234_567), "Punkte: 1.234.567");
+
+        let plain = ScoreFormat {
+            separator: None,
+            ..default()
+        };
+        assert_eq!(plain.score(12_340), "Score: 12340");
+    }
 }
@@ -0,0 +1,899 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::components::*;
+
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleState>().add_systems(
+            Update,
+            (toggle_console, console_text_input, update_console_ui).chain(),
+        );
+    }
+}
+
+/// Open/closed state, pending input line, and last output of the developer console.
+#[derive(Resource, Default)]
+struct ConsoleState {
+    open: bool,
+    input: String,
+    output: String,
+}
+
+#[derive(Component)]
+struct ConsoleUi;
+
+/// A developer console command.
+#[derive(Debug, PartialEq)]
+enum ConsoleCommand {
+    SetLives(u32),
+    SetScore(u32),
+    SetDifficulty(Difficulty),
+    SetSpeed(f32),
+    SetMouseSmoothing(f32),
+    SetBallSize(BallSize),
+    SetBallOwner(Option<PlayerId>),
+    SetBottomMode(BottomMode),
+    SetVictory(VictoryCondition),
+    SetComboDropStreak(u32),
+    SetWallThickness(f32),
+    SetPaddleFriction(f32),
+    SetMaxPaddleSpeed(u32),
+    SpawnPowerUp(PowerUpType),
+    GotoLevel(u32),
+    ClearBricks,
+    ToggleLandingIndicator,
+    ToggleReduceMotion,
+    ToggleSteering,
+    ToggleEdgeBumpers,
+    ToggleInvertPaddle,
+    TogglePaddleZoneAssist,
+    ToggleScoreLegend,
+    ToggleBoss,
+    ToggleDeathPenalty,
+    ToggleRandomLayout,
+    ToggleRandomServe,
+    ToggleEarthquakes,
+    ToggleServeAssist,
+    ToggleKeepBalls,
+    ToggleLifePerBall,
+    ToggleMultiBallScoring,
+    TogglePowerUpDrops,
+    ToggleRowPoints,
+    ToggleBallSkip,
+    ToggleMousePaddle,
+    ToggleVelocityArrows,
+    ToggleDangerLine,
+    ToggleSessionScores,
+}
+
+/// Reasons a console line could not be parsed.
+#[derive(Debug, PartialEq)]
+enum ConsoleError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument,
+    InvalidNumber(String),
+    InvalidDifficulty(String),
+    InvalidBallSize(String),
+    InvalidPlayer(String),
+    InvalidBottomMode(String),
+    InvalidPowerUp(String),
+    InvalidLevel(String),
+    TrailingInput(String),
+}
+
+impl std::fmt::Display for ConsoleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConsoleError::Empty => write!(f, "empty command"),
+            ConsoleError::UnknownCommand(cmd) => write!(f, "unknown command: {cmd}"),
+            ConsoleError::MissingArgument => write!(f, "missing argument"),
+            ConsoleError::InvalidNumber(arg) => write!(f, "invalid number: {arg}"),
+            ConsoleError::InvalidDifficulty(arg) => write!(f, "invalid difficulty: {arg}"),
+            ConsoleError::InvalidBallSize(arg) => write!(f, "invalid ball size: {arg}"),
+            ConsoleError::InvalidPlayer(arg) => write!(f, "invalid player: {arg}"),
+            ConsoleError::InvalidBottomMode(arg) => write!(f, "invalid bottom mode: {arg}"),
+            ConsoleError::InvalidPowerUp(arg) => write!(f, "invalid power-up: {arg}"),
+            ConsoleError::InvalidLevel(arg) => write!(f, "invalid level: {arg}"),
+            ConsoleError::TrailingInput(arg) => write!(f, "unexpected input: {arg}"),
+        }
+    }
+}
+
+/// Parses a console line such as `set lives 9`, `spawn_powerup multiball`, `goto level 3`, or
+/// `toggle steering`.
+fn parse_command(input: &str) -> Result<ConsoleCommand, ConsoleError> {
+    let mut words = input.split_whitespace();
+    let Some(verb) = words.next() else {
+        return Err(ConsoleError::Empty);
+    };
+
+    let command = match verb {
+        "set" => {
+            let target = words.next().ok_or(ConsoleError::MissingArgument)?;
+            let value = words.next().ok_or(ConsoleError::MissingArgument)?;
+            if target == "difficulty" {
+                let difficulty = match value {
+                    "easy" => Difficulty::Easy,
+                    "normal" => Difficulty::Normal,
+                    "hard" => Difficulty::Hard,
+                    _ => return Err(ConsoleError::InvalidDifficulty(value.to_string())),
+                };
+                ConsoleCommand::SetDifficulty(difficulty)
+            } else if target == "ball" {
+                let size = match value {
+                    "small" => BallSize::Small,
+                    "normal" => BallSize::Normal,
+                    "big" => BallSize::Big,
+                    _ => return Err(ConsoleError::InvalidBallSize(value.to_string())),
+                };
+                ConsoleCommand::SetBallSize(size)
+            } else if target == "bottom" {
+                let mode = match value {
+                    "death" => BottomMode::DeathZone,
+                    "wall" => BottomMode::Wall,
+                    "shield" => BottomMode::OneBounceShield,
+                    _ => return Err(ConsoleError::InvalidBottomMode(value.to_string())),
+                };
+                ConsoleCommand::SetBottomMode(mode)
+            } else if target == "victory" {
+                let victory = if value == "all" {
+                    VictoryCondition::AllBricks
+                } else if let Some(percent) = value.strip_suffix('%') {
+                    VictoryCondition::Percent(
+                        percent
+                            .parse()
+                            .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?,
+                    )
+                } else {
+                    VictoryCondition::Count(
+                        value
+                            .parse()
+                            .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?,
+                    )
+                };
+                ConsoleCommand::SetVictory(victory)
+            } else if target == "owner" {
+                let owner = match value {
+                    "1" => Some(PlayerId::One),
+                    "2" => Some(PlayerId::Two),
+                    "none" => None,
+                    _ => return Err(ConsoleError::InvalidPlayer(value.to_string())),
+                };
+                ConsoleCommand::SetBallOwner(owner)
+            } else if target == "speed" {
+                let speed: f32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                ConsoleCommand::SetSpeed(speed)
+            } else if target == "smoothing" {
+                let smoothing: f32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                ConsoleCommand::SetMouseSmoothing(smoothing)
+            } else if target == "walls" {
+                let thickness: f32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                ConsoleCommand::SetWallThickness(thickness)
+            } else if target == "friction" {
+                let friction: f32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                ConsoleCommand::SetPaddleFriction(friction)
+            } else {
+                let value: u32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                match target {
+                    "lives" => ConsoleCommand::SetLives(value),
+                    "score" => ConsoleCommand::SetScore(value),
+                    "guarantee" => ConsoleCommand::SetComboDropStreak(value),
+                    "maxspeed" => ConsoleCommand::SetMaxPaddleSpeed(value),
+                    _ => return Err(ConsoleError::UnknownCommand(format!("set {target}"))),
+                }
+            }
+        }
+        "spawn_powerup" => {
+            let name = words.next().ok_or(ConsoleError::MissingArgument)?;
+            let kind = match name {
+                "widerpaddle" => PowerUpType::WiderPaddle,
+                "extralife" => PowerUpType::ExtraLife,
+                "regen" => PowerUpType::Regen,
+                "multiball" => PowerUpType::MultiBall,
+                _ => return Err(ConsoleError::InvalidPowerUp(name.to_string())),
+            };
+            ConsoleCommand::SpawnPowerUp(kind)
+        }
+        "goto" => match words.next() {
+            Some("level") => {
+                let value = words.next().ok_or(ConsoleError::MissingArgument)?;
+                let number: u32 = value
+                    .parse()
+                    .map_err(|_| ConsoleError::InvalidNumber(value.to_string()))?;
+                // Levels are numbered from 1
+                if number == 0 {
+                    return Err(ConsoleError::InvalidLevel(value.to_string()));
+                }
+                ConsoleCommand::GotoLevel(number)
+            }
+            Some(target) => return Err(ConsoleError::UnknownCommand(format!("goto {target}"))),
+            None => return Err(ConsoleError::MissingArgument),
+        },
+        "clear" => match words.next() {
+            Some("bricks") => ConsoleCommand::ClearBricks,
+            Some(target) => return Err(ConsoleError::UnknownCommand(format!("clear {target}"))),
+            None => return Err(ConsoleError::MissingArgument),
+        },
+        "toggle" => match words.next() {
+            Some("landing") => ConsoleCommand::ToggleLandingIndicator,
+            Some("motion") => ConsoleCommand::ToggleReduceMotion,
+            Some("steering") => ConsoleCommand::ToggleSteering,
+            Some("bumpers") => ConsoleCommand::ToggleEdgeBumpers,
+            Some("invert") => ConsoleCommand::ToggleInvertPaddle,
+            Some("zone") => ConsoleCommand::TogglePaddleZoneAssist,
+            Some("legend") => ConsoleCommand::ToggleScoreLegend,
+            Some("boss") => ConsoleCommand::ToggleBoss,
+            Some("penalty") => ConsoleCommand::ToggleDeathPenalty,
+            Some("random") => ConsoleCommand::ToggleRandomLayout,
+            Some("serve") => ConsoleCommand::ToggleRandomServe,
+            Some("quakes") => ConsoleCommand::ToggleEarthquakes,
+            Some("assist") => ConsoleCommand::ToggleServeAssist,
+            Some("keepballs") => ConsoleCommand::ToggleKeepBalls,
+            Some("lifeperball") => ConsoleCommand::ToggleLifePerBall,
+            Some("multiscore") => ConsoleCommand::ToggleMultiBallScoring,
+            Some("drops") => ConsoleCommand::TogglePowerUpDrops,
+            Some("rowpoints") => ConsoleCommand::ToggleRowPoints,
+            Some("skip") => ConsoleCommand::ToggleBallSkip,
+            Some("mouse") => ConsoleCommand::ToggleMousePaddle,
+            Some("arrows") => ConsoleCommand::ToggleVelocityArrows,
+            Some("danger") => ConsoleCommand::ToggleDangerLine,
+            Some("sessionscores") => ConsoleCommand::ToggleSessionScores,
+            Some(target) => return Err(ConsoleError::UnknownCommand(format!("toggle {target}"))),
+            None => return Err(ConsoleError::MissingArgument),
+        },
+        _ => return Err(ConsoleError::UnknownCommand(verb.to_string())),
+    };
+
+    if let Some(extra) = words.next() {
+        return Err(ConsoleError::TrailingInput(extra.to_string()));
+    }
+
+    Ok(command)
+}
+
+/// Returns "on" or "off" for a toggled setting.
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "on" } else { "off" }
+}
+
+/// Opens/closes the console when the backtick key is pressed.
+fn toggle_console(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut console: ResMut<ConsoleState>,
+    mut commands: Commands,
+    query: Query<Entity, With<ConsoleUi>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Backquote) {
+        return;
+    }
+
+    console.open = !console.open;
+    console.input.clear();
+
+    if console.open {
+        commands.spawn((
+            Text::new("> "),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 1.0, 0.6)),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+            UI_Z_CONSOLE,
+            ConsoleUi,
+        ));
+    } else {
+        for entity in &query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Collects typed characters and executes the line on Enter.
+#[allow(clippy::too_many_arguments)]
+fn console_text_input(
+    mut keyboard_input: MessageReader<KeyboardInput>,
+    mut console: ResMut<ConsoleState>,
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut settings: ResMut<Settings>,
+    mut config: ResMut<GameConfig>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    mut level: ResMut<CurrentLevel>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut next_state: ResMut<NextState<GameState>>,
+    paddle_query: Query<&Transform, With<Paddle>>,
+    brick_query: Query<(Entity, &Transform, &ColliderSize), With<Brick>>,
+    ball_query: Query<Entity, With<Ball>>,
+    run_query: Query<Entity, RunEntity>,
+) {
+    if !console.open {
+        keyboard_input.clear();
+        return;
+    }
+
+    for input in keyboard_input.read() {
+        if input.state != ButtonState::Pressed || input.key_code == KeyCode::Backquote {
+            continue;
+        }
+
+        match &input.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                console.output = match parse_command(&line) {
+                    Ok(ConsoleCommand::SetLives(count)) => {
+                        lives.count = count;
+                        format!("lives set to {count}")
+                    }
+                    Ok(ConsoleCommand::SetScore(score)) => {
+                        scoreboard.score = score;
+                        format!("score set to {score}")
+                    }
+                    Ok(ConsoleCommand::SetDifficulty(difficulty)) => {
+                        settings.difficulty = difficulty;
+                        format!("difficulty set to {difficulty:?}")
+                    }
+                    Ok(ConsoleCommand::SetSpeed(speed)) => {
+                        settings.game_speed = GameSpeed(speed);
+                        format!("game speed set to {}x", settings.game_speed.clamped())
+                    }
+                    Ok(ConsoleCommand::SetMouseSmoothing(smoothing)) => {
+                        settings.mouse_smoothing = MouseSmoothing(smoothing.clamp(0.0, 1.0));
+                        format!("mouse smoothing set to {}", settings.mouse_smoothing.0)
+                    }
+                    Ok(ConsoleCommand::SetBallSize(size)) => {
+                        config.ball_size = size;
+                        format!("ball size set to {size:?}")
+                    }
+                    Ok(ConsoleCommand::SetBottomMode(mode)) => {
+                        config.bottom_mode = mode;
+                        format!("bottom mode set to {mode:?}")
+                    }
+                    Ok(ConsoleCommand::SetVictory(victory)) => {
+                        config.victory = victory;
+                        format!("victory condition set to {victory:?}")
+                    }
+                    Ok(ConsoleCommand::SetComboDropStreak(streak)) => {
+                        config.combo_drop_streak = streak;
+                        if streak == 0 {
+                            "combo drop guarantee off".to_string()
+                        } else {
+                            format!("combo drop guarantee set to every {streak} bricks")
+                        }
+                    }
+                    Ok(ConsoleCommand::SetWallThickness(thickness)) => {
+                        let thickness = thickness.clamp(WALL_THICKNESS_MIN, WALL_THICKNESS_MAX);
+                        config.wall_thickness = WallThickness(thickness);
+                        format!("wall thickness set to {thickness} (from the next run)")
+                    }
+                    Ok(ConsoleCommand::SetPaddleFriction(friction)) => {
+                        let friction = friction.clamp(0.0, 1.0);
+                        config.paddle_bounce.friction = friction;
+                        format!("paddle friction set to {friction}")
+                    }
+                    Ok(ConsoleCommand::SetMaxPaddleSpeed(speed)) => {
+                        config.max_paddle_speed =
+                            (speed > 0).then_some(MaxPaddleSpeed(speed as f32));
+                        if speed == 0 {
+                            "mouse paddle speed limit off".to_string()
+                        } else {
+                            format!("mouse paddle speed limited to {speed} per second")
+                        }
+                    }
+                    Ok(ConsoleCommand::SetBallOwner(owner)) => {
+                        for ball in &ball_query {
+                            match owner {
+                                Some(owner) => commands.entity(ball).insert(BallOwner(owner)),
+                                None => commands.entity(ball).remove::<BallOwner>(),
+                            };
+                        }
+                        format!("ball owner set to {owner:?}")
+                    }
+                    Ok(ConsoleCommand::SpawnPowerUp(kind)) => {
+                        if let Ok(paddle) = paddle_query.single() {
+                            let position = paddle.translation.truncate()
+                                + Vec2::new(0.0, CONSOLE_POWERUP_HEIGHT);
+                            crate::collision::spawn_powerup(&mut commands, kind, position);
+                            format!("spawned {kind:?} power-up")
+                        } else {
+                            "no paddle to drop a power-up on".to_string()
+                        }
+                    }
+                    Ok(ConsoleCommand::GotoLevel(number)) => {
+                        level.number = number;
+                        scoreboard.board_start = scoreboard.score;
+                        active_powerups.timers.clear();
+                        destroyed_bricks.bricks.clear();
+                        for entity in &run_query {
+                            commands.entity(entity).despawn();
+                        }
+                        // Runs after the despawns above, reading the new level
+                        commands.run_system_cached(crate::setup::spawn_game);
+                        next_state.set(GameState::Ready);
+                        format!("went to level {number}")
+                    }
+                    Ok(ConsoleCommand::ClearBricks) => {
+                        // Cleared bricks count as destroyed, so the level is won
+                        for (entity, transform, size) in &brick_query {
+                            commands.entity(entity).despawn();
+                            destroyed_bricks.bricks.push(Rect::from_center_size(
+                                transform.translation.truncate(),
+                                size.0,
+                            ));
+                        }
+                        "bricks cleared".to_string()
+                    }
+                    Ok(ConsoleCommand::ToggleLandingIndicator) => {
+                        settings.landing_indicator = !settings.landing_indicator;
+                        format!("landing indicator {}", on_off(settings.landing_indicator))
+                    }
+                    Ok(ConsoleCommand::ToggleReduceMotion) => {
+                        settings.reduce_motion = !settings.reduce_motion;
+                        format!("reduce motion {}", on_off(settings.reduce_motion))
+                    }
+                    Ok(ConsoleCommand::ToggleSteering) => {
+                        let bounce = &mut config.paddle_bounce;
+                        bounce.steering = match bounce.steering {
+                            PaddleSteering::OffsetBased => PaddleSteering::PureReflect,
+                            PaddleSteering::PureReflect => PaddleSteering::OffsetBased,
+                        };
+                        format!("paddle steering {:?}", bounce.steering)
+                    }
+                    Ok(ConsoleCommand::ToggleEdgeBumpers) => {
+                        let bounce = &mut config.paddle_bounce;
+                        bounce.edge_bumpers = !bounce.edge_bumpers;
+                        format!("paddle edge bumpers {}", on_off(bounce.edge_bumpers))
+                    }
+                    Ok(ConsoleCommand::ToggleInvertPaddle) => {
+                        settings.invert_paddle = !settings.invert_paddle;
+                        format!("inverted paddle {}", on_off(settings.invert_paddle))
+                    }
+                    Ok(ConsoleCommand::TogglePaddleZoneAssist) => {
+                        settings.paddle_zone_assist = !settings.paddle_zone_assist;
+                        format!("paddle zone assist {}", on_off(settings.paddle_zone_assist))
+                    }
+                    Ok(ConsoleCommand::ToggleScoreLegend) => {
+                        settings.score_legend = !settings.score_legend;
+                        format!("score legend {}", on_off(settings.score_legend))
+                    }
+                    Ok(ConsoleCommand::ToggleMousePaddle) => {
+                        settings.mouse_paddle = !settings.mouse_paddle;
+                        format!("mouse paddle {}", on_off(settings.mouse_paddle))
+                    }
+                    Ok(ConsoleCommand::ToggleVelocityArrows) => {
+                        settings.velocity_arrows = !settings.velocity_arrows;
+                        format!("velocity arrows {}", on_off(settings.velocity_arrows))
+                    }
+                    Ok(ConsoleCommand::ToggleDangerLine) => {
+                        settings.danger_line = !settings.danger_line;
+                        format!("danger line {}", on_off(settings.danger_line))
+                    }
+                    Ok(ConsoleCommand::ToggleSessionScores) => {
+                        settings.session_scores = !settings.session_scores;
+                        format!(
+                            "session-only high scores {}",
+                            on_off(settings.session_scores)
+                        )
+                    }
+                    Ok(ConsoleCommand::ToggleDeathPenalty) => {
+                        config.death_penalty = !config.death_penalty;
+                        format!("death penalty {}", on_off(config.death_penalty))
+                    }
+                    Ok(ConsoleCommand::ToggleRandomLayout) => {
+                        config.random_layout = !config.random_layout;
+                        format!(
+                            "random layout {} (from the next run)",
+                            on_off(config.random_layout)
+                        )
+                    }
+                    Ok(ConsoleCommand::ToggleRandomServe) => {
+                        config.random_serve = !config.random_serve;
+                        format!("random serve {}", on_off(config.random_serve))
+                    }
+                    Ok(ConsoleCommand::ToggleEarthquakes) => {
+                        config.earthquakes = !config.earthquakes;
+                        format!("earthquakes {}", on_off(config.earthquakes))
+                    }
+                    Ok(ConsoleCommand::ToggleServeAssist) => {
+                        config.serve_assist = !config.serve_assist;
+                        format!("serve assist {}", on_off(config.serve_assist))
+                    }
+                    Ok(ConsoleCommand::ToggleKeepBalls) => {
+                        config.keep_balls_in_play = !config.keep_balls_in_play;
+                        format!("keep balls in play {}", on_off(config.keep_balls_in_play))
+                    }
+                    Ok(ConsoleCommand::ToggleLifePerBall) => {
+                        config.lose_life_per_ball = !config.lose_life_per_ball;
+                        format!("life per lost ball {}", on_off(config.lose_life_per_ball))
+                    }
+                    Ok(ConsoleCommand::ToggleMultiBallScoring) => {
+                        config.multi_ball_scoring = !config.multi_ball_scoring;
+                        format!("multi-ball scoring {}", on_off(config.multi_ball_scoring))
+                    }
+                    Ok(ConsoleCommand::TogglePowerUpDrops) => {
+                        config.powerup_drops = !config.powerup_drops;
+                        format!("power-up drops {}", on_off(config.powerup_drops))
+                    }
+                    Ok(ConsoleCommand::ToggleRowPoints) => {
+                        config.row_points = !config.row_points;
+                        format!("per-row points {}", on_off(config.row_points))
+                    }
+                    Ok(ConsoleCommand::ToggleBallSkip) => {
+                        config.ball_skip = !config.ball_skip;
+                        format!("ball skip {}", on_off(config.ball_skip))
+                    }
+                    Ok(ConsoleCommand::ToggleBoss) => {
+                        config.boss = !config.boss;
+                        format!("boss level {} (from the next run)", on_off(config.boss))
+                    }
+                    Err(error) => format!("error: {error}"),
+                };
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Space => console.input.push(' '),
+            Key::Character(text) => console.input.push_str(text),
+            _ => {}
+        }
+    }
+}
+
+/// Mirrors the input line and last output into the console text.
+fn update_console_ui(console: Res<ConsoleState>, mut query: Query<&mut Text, With<ConsoleUi>>) {
+    if !console.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = if console.output.is_empty() {
+            format!("> {}", console.input)
+        } else {
+            format!("{}\n> {}", console.output, console.input)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- parse_command ---
+
+    #[test]
+    fn parses_set_lives() {
+        assert_eq!(
+            parse_command("set lives 9"),
+            Ok(ConsoleCommand::SetLives(9))
+        );
+    }
+
+    #[test]
+    fn parses_set_score() {
+        assert_eq!(
+            parse_command("set score 1200"),
+            Ok(ConsoleCommand::SetScore(1200))
+        );
+    }
+
+    #[test]
+    fn parses_set_guarantee() {
+        assert_eq!(
+            parse_command("set guarantee 5"),
+            Ok(ConsoleCommand::SetComboDropStreak(5))
+        );
+        assert_eq!(
+            parse_command("set maxspeed 600"),
+            Ok(ConsoleCommand::SetMaxPaddleSpeed(600))
+        );
+    }
+
+    #[test]
+    fn parses_set_difficulty() {
+        assert_eq!(
+            parse_command("set difficulty hard"),
+            Ok(ConsoleCommand::SetDifficulty(Difficulty::Hard))
+        );
+        assert_eq!(
+            parse_command("set difficulty brutal"),
+            Err(ConsoleError::InvalidDifficulty("brutal".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_set_speed() {
+        assert_eq!(
+            parse_command("set speed 1.5"),
+            Ok(ConsoleCommand::SetSpeed(1.5))
+        );
+        assert_eq!(
+            parse_command("set speed fast"),
+            Err(ConsoleError::InvalidNumber("fast".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_set_smoothing() {
+        assert_eq!(
+            parse_command("set smoothing 0.3"),
+            Ok(ConsoleCommand::SetMouseSmoothing(0.3))
+        );
+    }
+
+    #[test]
+    fn parses_set_walls() {
+        assert_eq!(
+            parse_command("set walls 25"),
+            Ok(ConsoleCommand::SetWallThickness(25.0))
+        );
+        assert_eq!(
+            parse_command("set friction 0.3"),
+            Ok(ConsoleCommand::SetPaddleFriction(0.3))
+        );
+    }
+
+    #[test]
+    fn parses_set_ball_size() {
+        assert_eq!(
+            parse_command("set ball big"),
+            Ok(ConsoleCommand::SetBallSize(BallSize::Big))
+        );
+        assert_eq!(
+            parse_command("set ball huge"),
+            Err(ConsoleError::InvalidBallSize("huge".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_set_bottom_mode() {
+        assert_eq!(
+            parse_command("set bottom shield"),
+            Ok(ConsoleCommand::SetBottomMode(BottomMode::OneBounceShield))
+        );
+        assert_eq!(
+            parse_command("set bottom lava"),
+            Err(ConsoleError::InvalidBottomMode("lava".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_set_victory() {
+        assert_eq!(
+            parse_command("set victory all"),
+            Ok(ConsoleCommand::SetVictory(VictoryCondition::AllBricks))
+        );
+        assert_eq!(
+            parse_command("set victory 30"),
+            Ok(ConsoleCommand::SetVictory(VictoryCondition::Count(30)))
+        );
+        assert_eq!(
+            parse_command("set victory 75%"),
+            Ok(ConsoleCommand::SetVictory(VictoryCondition::Percent(75)))
+        );
+        assert_eq!(
+            parse_command("set victory most"),
+            Err(ConsoleError::InvalidNumber("most".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_set_ball_owner() {
+        assert_eq!(
+            parse_command("set owner 2"),
+            Ok(ConsoleCommand::SetBallOwner(Some(PlayerId::Two)))
+        );
+        assert_eq!(
+            parse_command("set owner none"),
+            Ok(ConsoleCommand::SetBallOwner(None))
+        );
+        assert_eq!(
+            parse_command("set owner 3"),
+            Err(ConsoleError::InvalidPlayer("3".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_spawn_powerup() {
+        assert_eq!(
+            parse_command("spawn_powerup multiball"),
+            Ok(ConsoleCommand::SpawnPowerUp(PowerUpType::MultiBall))
+        );
+        assert_eq!(
+            parse_command("spawn_powerup extralife"),
+            Ok(ConsoleCommand::SpawnPowerUp(PowerUpType::ExtraLife))
+        );
+        assert_eq!(
+            parse_command("spawn_powerup jetpack"),
+            Err(ConsoleError::InvalidPowerUp("jetpack".to_string()))
+        );
+        assert_eq!(
+            parse_command("spawn_powerup"),
+            Err(ConsoleError::MissingArgument)
+        );
+    }
+
+    #[test]
+    fn parses_goto_level() {
+        assert_eq!(
+            parse_command("goto level 3"),
+            Ok(ConsoleCommand::GotoLevel(3))
+        );
+        assert_eq!(
+            parse_command("goto level three"),
+            Err(ConsoleError::InvalidNumber("three".to_string()))
+        );
+        assert_eq!(
+            parse_command("goto level 0"),
+            Err(ConsoleError::InvalidLevel("0".to_string()))
+        );
+        assert_eq!(
+            parse_command("goto menu"),
+            Err(ConsoleError::UnknownCommand("goto menu".to_string()))
+        );
+    }
+
+    #[test]
+    fn parses_clear_bricks_with_extra_whitespace() {
+        assert_eq!(
+            parse_command("  clear   bricks "),
+            Ok(ConsoleCommand::ClearBricks)
+        );
+    }
+
+    #[test]
+    fn parses_toggles() {
+        assert_eq!(
+            parse_command("toggle landing"),
+            Ok(ConsoleCommand::ToggleLandingIndicator)
+        );
+        assert_eq!(
+            parse_command("toggle motion"),
+            Ok(ConsoleCommand::ToggleReduceMotion)
+        );
+        assert_eq!(
+            parse_command("toggle steering"),
+            Ok(ConsoleCommand::ToggleSteering)
+        );
+        assert_eq!(
+            parse_command("toggle bumpers"),
+            Ok(ConsoleCommand::ToggleEdgeBumpers)
+        );
+        assert_eq!(
+            parse_command("toggle invert"),
+            Ok(ConsoleCommand::ToggleInvertPaddle)
+        );
+        assert_eq!(
+            parse_command("toggle zone"),
+            Ok(ConsoleCommand::TogglePaddleZoneAssist)
+        );
+        assert_eq!(
+            parse_command("toggle legend"),
+            Ok(ConsoleCommand::ToggleScoreLegend)
+        );
+        assert_eq!(parse_command("toggle boss"), Ok(ConsoleCommand::ToggleBoss));
+        assert_eq!(
+            parse_command("toggle penalty"),
+            Ok(ConsoleCommand::ToggleDeathPenalty)
+        );
+        assert_eq!(
+            parse_command("toggle random"),
+            Ok(ConsoleCommand::ToggleRandomLayout)
+        );
+        assert_eq!(
+            parse_command("toggle serve"),
+            Ok(ConsoleCommand::ToggleRandomServe)
+        );
+        assert_eq!(
+            parse_command("toggle quakes"),
+            Ok(ConsoleCommand::ToggleEarthquakes)
+        );
+        assert_eq!(
+            parse_command("toggle assist"),
+            Ok(ConsoleCommand::ToggleServeAssist)
+        );
+        assert_eq!(
+            parse_command("toggle keepballs"),
+            Ok(ConsoleCommand::ToggleKeepBalls)
+        );
+        assert_eq!(
+            parse_command("toggle lifeperball"),
+            Ok(ConsoleCommand::ToggleLifePerBall)
+        );
+        assert_eq!(
+            parse_command("toggle multiscore"),
+            Ok(ConsoleCommand::ToggleMultiBallScoring)
+        );
+        assert_eq!(
+            parse_command("toggle drops"),
+            Ok(ConsoleCommand::TogglePowerUpDrops)
+        );
+        assert_eq!(
+            parse_command("toggle rowpoints"),
+            Ok(ConsoleCommand::ToggleRowPoints)
+        );
+        assert_eq!(
+            parse_command("toggle skip"),
+            Ok(ConsoleCommand::ToggleBallSkip)
+        );
+        assert_eq!(
+            parse_command("toggle mouse"),
+            Ok(ConsoleCommand::ToggleMousePaddle)
+        );
+        assert_eq!(
+            parse_command("toggle arrows"),
+            Ok(ConsoleCommand::ToggleVelocityArrows)
+        );
+        assert_eq!(
+            parse_command("toggle danger"),
+            Ok(ConsoleCommand::ToggleDangerLine)
+        );
+        assert_eq!(
+            parse_command("toggle sessionscores"),
+            Ok(ConsoleCommand::ToggleSessionScores)
+        );
+        assert_eq!(
+            parse_command("toggle gravity"),
+            Err(ConsoleError::UnknownCommand("toggle gravity".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(parse_command("   "), Err(ConsoleError::Empty));
+    }
+
+    #[test]
+    fn rejects_unknown_command() {
+        assert_eq!(
+            parse_command("fly"),
+            Err(ConsoleError::UnknownCommand("fly".to_string()))
+        );
+        assert_eq!(
+            parse_command("set gravity 3"),
+            Err(ConsoleError::UnknownCommand("set gravity".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_missing_and_invalid_arguments() {
+        assert_eq!(
+            parse_command("set lives"),
+            Err(ConsoleError::MissingArgument)
+        );
+        assert_eq!(parse_command("clear"), Err(ConsoleError::MissingArgument));
+        assert_eq!(
+            parse_command("set lives -1"),
+            Err(ConsoleError::InvalidNumber("-1".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        assert_eq!(
+            parse_command("clear bricks now"),
+            Err(ConsoleError::TrailingInput("now".to_string()))
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShowColliders>().add_systems(
+            Update,
+            (
+                toggle_show_colliders,
+                draw_colliders.run_if(|show: Res<ShowColliders>| show.0),
+                draw_velocity_arrows.run_if(|settings: Res<Settings>| settings.velocity_arrows),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Whether collision boxes are outlined; toggled with F3.
+#[derive(Resource, Default)]
+struct ShowColliders(bool);
+
+fn toggle_show_colliders(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut show_colliders: ResMut<ShowColliders>,
+) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        show_colliders.0 = !show_colliders.0;
+    }
+}
+
+/// Returns the collision box of every collider and ball, as collision code sees them.
+#[allow(clippy::type_complexity)]
+fn collider_outlines(
+    collider_query: &Query<(&Transform, &ColliderSize), Or<(With<Collider>, With<Ball>)>>,
+) -> Vec<Rect> {
+    collider_query
+        .iter()
+        .map(|(transform, size)| Rect::from_center_size(transform.translation.truncate(), size.0))
+        .collect()
+}
+
+/// Outlines every collision box with gizmos.
+#[allow(clippy::type_complexity)]
+fn draw_colliders(
+    mut gizmos: Gizmos,
+    collider_query: Query<(&Transform, &ColliderSize), Or<(With<Collider>, With<Ball>)>>,
+) {
+    for outline in collider_outlines(&collider_query) {
+        gizmos.rect_2d(outline.center(), outline.size(), COLLIDER_DEBUG_COLOR);
+    }
+}
+
+/// Returns the start and end of every ball's velocity arrow, spanning
+/// `VELOCITY_ARROW_SECONDS` of travel.
+fn velocity_arrows(ball_query: &Query<(&Transform, &Velocity), With<Ball>>) -> Vec<(Vec2, Vec2)> {
+    ball_query
+        .iter()
+        .map(|(transform, velocity)| {
+            let start = transform.translation.truncate();
+            (start, start + velocity.0 * VELOCITY_ARROW_SECONDS)
+        })
+        .collect()
+}
+
+/// Draws each ball's velocity arrow, pulsing its brightness unless motion is reduced.
+fn draw_velocity_arrows(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    ball_query: Query<(&Transform, &Velocity), With<Ball>>,
+) {
+    let pulse = if settings.reduce_motion {
+        1.0
+    } else {
+        0.75 + 0.25 * (time.elapsed_secs() * VELOCITY_ARROW_PULSE_SPEED).sin()
+    };
+    let color = VELOCITY_ARROW_COLOR.with_alpha(pulse);
+    for (start, end) in velocity_arrows(&ball_query) {
+        gizmos.arrow_2d(start, end, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Settings>();
+        app
+    }
+
+    // --- collider_outlines ---
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn outlines_cover_colliders_and_ball() {
+        let mut app = test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(10.0, 20.0, 0.0).with_scale(Vec3::splat(3.0)),
+            Collider,
+            ColliderSize(Vec2::new(100.0, 20.0)),
+        ));
+        app.world_mut()
+            .spawn((Transform::from_xyz(-5.0, 0.0, 1.0), Ball));
+
+        let outlines = app
+            .world_mut()
+            .run_system_once(
+                |collider_query: Query<
+                    (&Transform, &ColliderSize),
+                    Or<(With<Collider>, With<Ball>)>,
+                >| collider_outlines(&collider_query),
+            )
+            .unwrap();
+
+        assert_eq!(outlines.len(), 2);
+        assert!(outlines.contains(&Rect::from_center_size(
+            Vec2::new(10.0, 20.0),
+            Vec2::new(100.0, 20.0)
+        )));
+        assert!(outlines.contains(&Rect::from_center_size(
+            Vec2::new(-5.0, 0.0),
+            Vec2::splat(BALL_SIZE)
+        )));
+    }
+
+    // --- toggle_show_colliders ---
+
+    #[test]
+    fn f3_toggles_collider_view_and_draws_without_panicking() {
+        let mut app = test_app();
+        app.add_plugins((
+            AssetPlugin::default(),
+            bevy::gizmos::GizmoPlugin,
+            DebugPlugin,
+        ));
+        app.world_mut().spawn((Transform::default(), Ball));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F3);
+        app.update();
+        assert!(app.world().resource::<ShowColliders>().0);
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::F3);
+        keyboard.clear();
+        keyboard.press(KeyCode::F3);
+        app.update();
+        assert!(!app.world().resource::<ShowColliders>().0);
+    }
+
+    // --- velocity_arrows ---
+
+    #[test]
+    fn velocity_arrows_cover_every_ball_and_scale_with_speed() {
+        let mut app = test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(10.0, 20.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(100.0, 0.0)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(-30.0, 0.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, -300.0)),
+        ));
+
+        let arrows = app
+            .world_mut()
+            .run_system_once(|ball_query: Query<(&Transform, &Velocity), With<Ball>>| {
+                velocity_arrows(&ball_query)
+            })
+            .unwrap();
+
+        assert_eq!(arrows.len(), 2);
+        let lengths: Vec<f32> = arrows
+            .iter()
+            .map(|(start, end)| start.distance(*end))
+            .collect();
+        assert!(lengths.contains(&(100.0 * VELOCITY_ARROW_SECONDS)));
+        assert!(lengths.contains(&(300.0 * VELOCITY_ARROW_SECONDS)));
+        assert!(arrows.contains(&(
+            Vec2::new(-30.0, 0.0),
+            Vec2::new(-30.0, -300.0 * VELOCITY_ARROW_SECONDS)
+        )));
+    }
+
+    #[test]
+    fn velocity_arrows_draw_when_enabled_without_panicking() {
+        let mut app = test_app();
+        app.add_plugins((
+            AssetPlugin::default(),
+            bevy::gizmos::GizmoPlugin,
+            DebugPlugin,
+        ));
+        app.world_mut().resource_mut::<Settings>().velocity_arrows = true;
+        app.world_mut().spawn((
+            Transform::default(),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+        app.update();
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+        app.update();
+    }
+}
@@ -0,0 +1,267 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+use crate::{collision, game, movement};
+
+pub struct DemoPlugin;
+
+impl Plugin for DemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_sub_state::<MenuMode>()
+            .add_systems(Update, count_menu_idle.run_if(in_state(MenuMode::Idle)))
+            .add_systems(OnEnter(MenuMode::Demo), start_demo)
+            .add_systems(OnExit(MenuMode::Demo), reset_demo_field)
+            .add_systems(
+                Update,
+                (
+                    steer_ai_paddle,
+                    movement::move_paddle,
+                    movement::hold_stuck_ball,
+                    serve_demo_ball,
+                    movement::launch_ball,
+                    movement::apply_velocity,
+                    collision::ball_collision_walls_and_paddle,
+                    collision::ball_collision_bricks,
+                    collision::powerup_collision_paddle,
+                    collision::clamp_ball_to_bounds,
+                    collision::recover_stuck_ball,
+                    collision::ball_death_zone,
+                    game::tick_powerup_timers,
+                    game::apply_powerup_effects,
+                    game::fade_hit_flashes,
+                    end_demo_when_over,
+                    exit_demo_on_input,
+                )
+                    .chain()
+                    .run_if(in_state(MenuMode::Demo)),
+            );
+    }
+}
+
+/// Whether the menu is waiting for input or running the attract-mode demo behind it.
+#[derive(SubStates, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[source(GameState = GameState::Menu)]
+enum MenuMode {
+    #[default]
+    Idle,
+    Demo,
+}
+
+/// Starts the demo once the menu has sat untouched for `DEMO_IDLE_DELAY` seconds.
+fn count_menu_idle(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut next_mode: ResMut<NextState<MenuMode>>,
+    mut idle_secs: Local<f32>,
+) {
+    if keyboard.get_just_pressed().next().is_some() {
+        *idle_secs = 0.0;
+        return;
+    }
+
+    *idle_secs += time.delta_secs();
+    if *idle_secs >= DEMO_IDLE_DELAY {
+        *idle_secs = 0.0;
+        next_mode.set(MenuMode::Demo);
+    }
+}
+
+/// Hands the paddle over to the AI.
+fn start_demo(mut commands: Commands, paddle_query: Query<Entity, With<Paddle>>) {
+    for paddle in &paddle_query {
+        commands.entity(paddle).insert(AiPaddle { direction: 0.0 });
+    }
+}
+
+/// Points the AI paddle toward the ball's x position.
+fn steer_ai_paddle(
+    ball_query: Query<&Transform, With<Ball>>,
+    mut paddle_query: Query<(&Transform, &mut AiPaddle), Without<Ball>>,
+) {
+    let Ok(ball_transform) = ball_query.single() else {
+        return;
+    };
+    let Ok((paddle_transform, mut ai)) = paddle_query.single_mut() else {
+        return;
+    };
+
+    let offset = ball_transform.translation.x - paddle_transform.translation.x;
+    ai.direction = if offset.abs() < AI_PADDLE_DEAD_ZONE {
+        0.0
+    } else {
+        offset.signum()
+    };
+}
+
+/// Queues a serve for the AI as soon as the countdown allows, so `launch_ball` fires it.
+fn serve_demo_ball(
+    time: Res<Time>,
+    mut serve: ResMut<Serve>,
+    stuck_query: Query<(), With<StuckToPaddle>>,
+) {
+    if stuck_query.is_empty() || !serve.countdown.is_finished() || serve.buffered_at.is_some() {
+        return;
+    }
+    serve.buffered_at = Some(time.elapsed_secs());
+}
+
+/// Restarts the idle wait once the demo runs out of bricks or lives.
+fn end_demo_when_over(
+    lives: Res<Lives>,
+    mut next_mode: ResMut<NextState<MenuMode>>,
+    brick_query: Query<(), With<Brick>>,
+) {
+    if lives.count == 0 || brick_query.is_empty() {
+        next_mode.set(MenuMode::Idle);
+    }
+}
+
+/// Leaves the demo for a real game on any key press (SPACE and T are handled by `menu_input`,
+/// V by `cycle_frame_limit`, E by the editor).
+fn exit_demo_on_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut time_attack: ResMut<TimeAttack>,
+) {
+    if keyboard.get_just_pressed().any(|key| {
+        !matches!(
+            key,
+            KeyCode::Space | KeyCode::KeyT | KeyCode::KeyV | KeyCode::KeyE
+        )
+    }) {
+        time_attack.active = false;
+        next_state.set(GameState::Ready);
+    }
+}
+
+/// Replaces the field the demo played on with a fresh one and clears its score and lives.
+#[allow(clippy::too_many_arguments)]
+fn reset_demo_field(
+    mut commands: Commands,
+    mut level: ResMut<CurrentLevel>,
+    config: Res<GameConfig>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    mut serve: ResMut<Serve>,
+    mut combo: ResMut<Combo>,
+    run_query: Query<Entity, RunEntity>,
+) {
+    crate::game::reset_game_state(
+        &mut commands,
+        &mut scoreboard,
+        &mut lives,
+        &mut level,
+        &mut active_powerups,
+        &mut destroyed_bricks,
+        run_query,
+    );
+    *serve = Serve::default();
+    combo.streak = 0;
+    crate::setup::spawn_game(commands, level.into(), config);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Settings>();
+        app.init_resource::<GameConfig>();
+        app
+    }
+
+    fn spawn_ai_paddle(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                Paddle,
+                ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+                AiPaddle { direction: 0.0 },
+            ))
+            .id()
+    }
+
+    // --- steer_ai_paddle ---
+
+    #[test]
+    fn ai_paddle_moves_toward_ball_x() {
+        for ball_x in [200.0, -200.0] {
+            let mut app = test_app();
+            app.add_systems(Update, (steer_ai_paddle, movement::move_paddle).chain());
+            app.world_mut()
+                .spawn((Transform::from_xyz(ball_x, 0.0, 1.0), Ball));
+            let paddle = spawn_ai_paddle(&mut app);
+
+            app.update();
+            app.update();
+
+            let paddle_x = app.world().get::<Transform>(paddle).unwrap().translation.x;
+            assert!(
+                paddle_x * ball_x > 0.0 && paddle_x.abs() < ball_x.abs(),
+                "Paddle should head toward the ball at {ball_x}, got {paddle_x}"
+            );
+        }
+    }
+
+    #[test]
+    fn ai_paddle_holds_still_under_ball() {
+        let mut app = test_app();
+        app.add_systems(Update, (steer_ai_paddle, movement::move_paddle).chain());
+        app.world_mut().spawn((
+            Transform::from_xyz(AI_PADDLE_DEAD_ZONE / 2.0, 0.0, 1.0),
+            Ball,
+        ));
+        let paddle = spawn_ai_paddle(&mut app);
+
+        app.update();
+        app.update();
+
+        let paddle_x = app.world().get::<Transform>(paddle).unwrap().translation.x;
+        assert_eq!(paddle_x, 0.0);
+    }
+
+    // --- MenuMode ---
+
+    #[test]
+    fn idle_menu_starts_demo_and_key_press_leaves_it() {
+        let mut app = test_app();
+        app.init_state::<GameState>();
+        app.add_sub_state::<MenuMode>();
+        app.init_resource::<TimeAttack>();
+        app.add_systems(
+            Update,
+            (
+                count_menu_idle.run_if(in_state(MenuMode::Idle)),
+                exit_demo_on_input.run_if(in_state(MenuMode::Demo)),
+            ),
+        );
+
+        for _ in 0..(DEMO_IDLE_DELAY * 10.0) as usize + 4 {
+            app.update();
+        }
+        assert_eq!(
+            *app.world().resource::<State<MenuMode>>().get(),
+            MenuMode::Demo
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Enter);
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Ready
+        );
+        assert!(app.world().get_resource::<State<MenuMode>>().is_none());
+    }
+}
@@ -0,0 +1,669 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::*;
+use crate::setup::{BrickKind, BrickSpec};
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LevelEditor>()
+            .add_systems(Update, enter_editor.run_if(in_state(GameState::Menu)))
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Ready,
+                },
+                spawn_saved_level
+                    .after(crate::setup::spawn_random_layout)
+                    .before(crate::game::start_brick_spawn_anim),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Victory,
+                    entered: GameState::Ready,
+                },
+                spawn_saved_level
+                    .after(crate::setup::spawn_random_layout)
+                    .before(crate::game::start_brick_spawn_anim),
+            )
+            .add_systems(OnEnter(GameState::Editor), open_editor)
+            .add_systems(OnExit(GameState::Editor), close_editor)
+            .add_systems(
+                Update,
+                (
+                    editor_keyboard,
+                    editor_mouse,
+                    sync_editor_bricks,
+                    update_editor_ui,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Editor)),
+            );
+    }
+}
+
+/// Brick kinds the editor can place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditorBrick {
+    Plain,
+    Bomb,
+    Explosive,
+    Enemy,
+    /// Paired with the next portal in reading order.
+    Portal,
+}
+
+impl EditorBrick {
+    const ALL: [EditorBrick; 5] = [
+        EditorBrick::Plain,
+        EditorBrick::Bomb,
+        EditorBrick::Explosive,
+        EditorBrick::Enemy,
+        EditorBrick::Portal,
+    ];
+
+    /// Returns the character that stands for this brick in a level file.
+    fn symbol(self) -> char {
+        match self {
+            EditorBrick::Plain => '#',
+            EditorBrick::Bomb => 'B',
+            EditorBrick::Explosive => 'X',
+            EditorBrick::Enemy => 'E',
+            EditorBrick::Portal => 'P',
+        }
+    }
+
+    fn from_symbol(symbol: char) -> Option<EditorBrick> {
+        EditorBrick::ALL
+            .into_iter()
+            .find(|brick| brick.symbol() == symbol)
+    }
+
+    /// Returns the brick after this one, wrapping around.
+    fn next(self) -> EditorBrick {
+        let index = EditorBrick::ALL.iter().position(|brick| *brick == self);
+        EditorBrick::ALL[index.map_or(0, |index| (index + 1) % EditorBrick::ALL.len())]
+    }
+
+    /// Returns the kind of brick the game spawns for this one.
+    fn kind(self) -> BrickKind {
+        match self {
+            EditorBrick::Plain => BrickKind::Plain,
+            EditorBrick::Bomb => BrickKind::Bomb,
+            EditorBrick::Explosive => BrickKind::Explosive,
+            EditorBrick::Enemy => BrickKind::Enemy,
+            EditorBrick::Portal => BrickKind::Portal,
+        }
+    }
+}
+
+/// A grid of optional bricks, stored row by row.
+#[derive(Debug, PartialEq)]
+struct LevelLayout {
+    rows: usize,
+    cols: usize,
+    cells: Vec<Option<EditorBrick>>,
+}
+
+/// Reasons a level file could not be loaded.
+#[derive(Debug, PartialEq)]
+enum LevelError {
+    Missing,
+    Unreadable(String),
+    Outdated(String),
+    Malformed(String),
+}
+
+impl std::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelError::Missing => write!(f, "no level file"),
+            LevelError::Unreadable(error) => write!(f, "could not read level: {error}"),
+            LevelError::Outdated(header) => write!(f, "unsupported level version: {header}"),
+            LevelError::Malformed(line) => write!(f, "malformed level row: {line}"),
+        }
+    }
+}
+
+impl LevelLayout {
+    fn empty(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![None; rows * cols],
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<EditorBrick> {
+        self.cells.get(row * self.cols + col).copied().flatten()
+    }
+
+    /// Places or removes the brick at a cell; cells outside the grid are ignored.
+    fn set(&mut self, row: usize, col: usize, brick: Option<EditorBrick>) {
+        if row < self.rows
+            && col < self.cols
+            && let Some(cell) = self.cells.get_mut(row * self.cols + col)
+        {
+            *cell = brick;
+        }
+    }
+
+    /// Returns the bricks to spawn for this layout on `level`, or `None` if it does not match the
+    /// fitted grid's rows and columns.
+    fn specs(&self, bricks: &BrickLayoutConfig, level: u32) -> Option<Vec<BrickSpec>> {
+        if self.rows != bricks.rows || self.cols != bricks.cols {
+            return None;
+        }
+        let layout = BrickGridLayout::from_config(bricks);
+        let size = Vec2::new(layout.brick_width, bricks.brick_height);
+        let mut specs = Vec::new();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                if let Some(brick) = self.get(row, col) {
+                    specs.push(BrickSpec {
+                        row,
+                        position: cell_center(bricks, row, col),
+                        size,
+                        hits: brick_health(level, row, bricks.rows),
+                        kind: brick.kind(),
+                    });
+                }
+            }
+        }
+        Some(specs)
+    }
+
+    /// Serializes the grid as one line of brick symbols per row under a version header.
+    fn to_text(&self) -> String {
+        let mut lines = vec![format!("breakout-level {LEVEL_VERSION}")];
+        for row in self.cells.chunks(self.cols.max(1)) {
+            lines.push(
+                row.iter()
+                    .map(|cell| cell.map_or('.', EditorBrick::symbol))
+                    .collect(),
+            );
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Parses a level written by `to_text`, rejecting other versions and ragged or unknown rows.
+    fn parse(text: &str) -> Result<Self, LevelError> {
+        let mut lines = text.lines();
+        let header = lines.next().unwrap_or_default();
+        if header != format!("breakout-level {LEVEL_VERSION}") {
+            return Err(LevelError::Outdated(header.to_string()));
+        }
+
+        let mut layout = Self::empty(0, 0);
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let malformed = || LevelError::Malformed(line.to_string());
+            let row = line
+                .trim()
+                .chars()
+                .map(|symbol| match symbol {
+                    '.' => Ok(None),
+                    _ => EditorBrick::from_symbol(symbol)
+                        .map(Some)
+                        .ok_or_else(malformed),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if layout.rows > 0 && row.len() != layout.cols {
+                return Err(malformed());
+            }
+            layout.cols = row.len();
+            layout.rows += 1;
+            layout.cells.extend(row);
+        }
+        Ok(layout)
+    }
+}
+
+/// Reads and parses the level file at `path`.
+fn read_level(path: &std::path::Path) -> Result<LevelLayout, LevelError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => LevelLayout::parse(&text),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Err(LevelError::Missing),
+        Err(error) => Err(LevelError::Unreadable(error.to_string())),
+    }
+}
+
+/// Writes `layout` to `path`, including missing parent directories.
+fn write_level(path: &std::path::Path, layout: &LevelLayout) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, layout.to_text())
+}
+
+/// Returns the world-space centre of a grid cell, matching where `spawn_game` places bricks.
+fn cell_center(bricks: &BrickLayoutConfig, row: usize, col: usize) -> Vec2 {
+    let layout = BrickGridLayout::from_config(bricks);
+    Vec2::new(
+        layout.column_x(col),
+        WINDOW_HEIGHT / 2.0 - bricks.top_margin - row as f32 * (bricks.brick_height + bricks.gap),
+    )
+}
+
+/// Returns the grid cell under a world position; each cell owns half of the gap around it.
+fn cell_at(bricks: &BrickLayoutConfig, position: Vec2) -> Option<(usize, usize)> {
+    let layout = BrickGridLayout::from_config(bricks);
+    let left = layout.offset_x - layout.width() / 2.0 - layout.gap / 2.0;
+    let top =
+        WINDOW_HEIGHT / 2.0 - bricks.top_margin + bricks.brick_height / 2.0 + bricks.gap / 2.0;
+
+    let col = (position.x - left) / (layout.brick_width + layout.gap);
+    let row = (top - position.y) / (bricks.brick_height + bricks.gap);
+    if col < 0.0 || row < 0.0 {
+        return None;
+    }
+    let (row, col) = (row as usize, col as usize);
+    (row < bricks.rows && col < layout.cols).then_some((row, col))
+}
+
+/// Layout being edited, the selected tool, the last status message, and the level file it is
+/// saved to and played from.
+#[derive(Resource)]
+struct LevelEditor {
+    layout: LevelLayout,
+    brick: EditorBrick,
+    eraser: bool,
+    message: String,
+    path: std::path::PathBuf,
+}
+
+impl Default for LevelEditor {
+    fn default() -> Self {
+        Self {
+            path: LEVEL_PATH.into(),
+            layout: LevelLayout::empty(0, 0),
+            brick: EditorBrick::Plain,
+            eraser: false,
+            message: String::new(),
+        }
+    }
+}
+
+/// Sprite of a brick placed in the editor; not a gameplay `Brick`.
+#[derive(Component)]
+struct EditorBrickSprite;
+
+#[derive(Component)]
+struct EditorUi;
+
+/// Opens the editor when E is pressed on the menu.
+fn enter_editor(keyboard: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<GameState>>) {
+    if keyboard.just_pressed(KeyCode::KeyE) {
+        next_state.set(GameState::Editor);
+    }
+}
+
+/// Loads the saved level (or starts an empty grid), hides the game's bricks, and shows the help.
+fn open_editor(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut editor: ResMut<LevelEditor>,
+    mut brick_query: Query<&mut Visibility, With<Brick>>,
+) {
+    let bricks = config.fitted_bricks();
+    let (layout, message) = match read_level(&editor.path) {
+        Ok(layout) if layout.rows == bricks.rows && layout.cols == bricks.cols => {
+            (layout, "Loaded saved level".to_string())
+        }
+        Ok(_) => (
+            LevelLayout::empty(bricks.rows, bricks.cols),
+            "Saved level does not fit the brick grid".to_string(),
+        ),
+        Err(LevelError::Missing) => (LevelLayout::empty(bricks.rows, bricks.cols), String::new()),
+        Err(error) => (
+            LevelLayout::empty(bricks.rows, bricks.cols),
+            format!("Ignoring saved level: {error}"),
+        ),
+    };
+    editor.layout = layout;
+    editor.eraser = false;
+    editor.message = message;
+
+    for mut visibility in &mut brick_query {
+        *visibility = Visibility::Hidden;
+    }
+
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(30.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        EditorUi,
+    ));
+}
+
+/// Removes the editor's sprites and text and shows the game's bricks again.
+#[allow(clippy::type_complexity)]
+fn close_editor(
+    mut commands: Commands,
+    editor_query: Query<Entity, Or<(With<EditorBrickSprite>, With<EditorUi>)>>,
+    mut brick_query: Query<&mut Visibility, With<Brick>>,
+) {
+    for entity in &editor_query {
+        commands.entity(entity).despawn();
+    }
+    for mut visibility in &mut brick_query {
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Handles the editor keys: TAB cycles the brick, X toggles the eraser, C clears the grid,
+/// S saves, and ESC returns to the menu.
+fn editor_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut editor: ResMut<LevelEditor>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Tab) {
+        editor.brick = editor.brick.next();
+        editor.eraser = false;
+    }
+    if keyboard.just_pressed(KeyCode::KeyX) {
+        editor.eraser = !editor.eraser;
+    }
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        let (rows, cols) = (editor.layout.rows, editor.layout.cols);
+        editor.layout = LevelLayout::empty(rows, cols);
+        editor.message = "Cleared".to_string();
+    }
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        editor.message = match write_level(&editor.path, &editor.layout) {
+            Ok(()) => format!("Saved to {}", editor.path.display()),
+            Err(error) => format!("Could not save level: {error}"),
+        };
+    }
+    if keyboard.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::Menu);
+    }
+}
+
+/// Places the selected brick (or erases, with the eraser or a right click) in the clicked cell.
+fn editor_mouse(
+    mouse: Res<ButtonInput<MouseButton>>,
+    config: Res<GameConfig>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut editor: ResMut<LevelEditor>,
+) {
+    let place = mouse.just_pressed(MouseButton::Left);
+    let erase = mouse.just_pressed(MouseButton::Right);
+    if !place && !erase {
+        return;
+    }
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+    let Some(position) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+    let Some((row, col)) = cell_at(&config.fitted_bricks(), position) else {
+        return;
+    };
+
+    let brick = (place && !editor.eraser).then_some(editor.brick);
+    if editor.layout.get(row, col) != brick {
+        editor.layout.set(row, col, brick);
+    }
+}
+
+/// Swaps the level's bricks for the saved level as play starts, when one fits the brick grid.
+/// The random layout, practice, and boss modes keep their own bricks, and a level without any
+/// bricks keeps the default grid.
+fn spawn_saved_level(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    level: Res<CurrentLevel>,
+    editor: Res<LevelEditor>,
+    brick_query: Query<Entity, With<Brick>>,
+) {
+    if config.random_layout || config.practice_row.is_some() || config.boss {
+        return;
+    }
+    let layout = match read_level(&editor.path) {
+        Ok(layout) => layout,
+        Err(LevelError::Missing) => return,
+        Err(error) => {
+            warn!("Ignoring saved level: {error}");
+            return;
+        }
+    };
+    let Some(specs) = layout.specs(&config.fitted_bricks(), level.number) else {
+        warn!("Ignoring saved level: it does not fit the brick grid");
+        return;
+    };
+    if specs.is_empty() {
+        return;
+    }
+
+    for entity in &brick_query {
+        commands.entity(entity).despawn();
+    }
+    crate::setup::spawn_bricks(&mut commands, &specs, config.row_points);
+}
+
+/// Rebuilds the editor's brick sprites whenever the layout changes.
+fn sync_editor_bricks(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    editor: Res<LevelEditor>,
+    sprite_query: Query<Entity, With<EditorBrickSprite>>,
+) {
+    if !editor.is_changed() {
+        return;
+    }
+    for entity in &sprite_query {
+        commands.entity(entity).despawn();
+    }
+
+    let bricks = config.fitted_bricks();
+    let layout = BrickGridLayout::from_config(&bricks);
+    let size = Vec2::new(layout.brick_width, bricks.brick_height);
+    for row in 0..editor.layout.rows {
+        for col in 0..editor.layout.cols {
+            let Some(brick) = editor.layout.get(row, col) else {
+                continue;
+            };
+            commands.spawn((
+                Sprite {
+                    color: brick.kind().color(0),
+                    custom_size: Some(size),
+                    ..default()
+                },
+                Transform::from_translation(cell_center(&bricks, row, col).extend(0.0)),
+                EditorBrickSprite,
+            ));
+        }
+    }
+}
+
+/// Shows the selected tool, the key help, and the last status message.
+fn update_editor_ui(editor: Res<LevelEditor>, mut query: Query<&mut Text, With<EditorUi>>) {
+    if !editor.is_changed() {
+        return;
+    }
+    let tool = if editor.eraser {
+        "Eraser".to_string()
+    } else {
+        format!("{:?} brick", editor.brick)
+    };
+    for mut text in &mut query {
+        **text = format!(
+            "LEVEL EDITOR - {tool}\n\
+             Click to place, right click to erase | TAB brick | X eraser | C clear | S save | ESC menu\n\
+             {}",
+            editor.message
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- cell_at ---
+
+    #[test]
+    fn clicks_map_to_the_cell_under_them() {
+        let bricks = GameConfig::default().bricks.fit_to_playfield();
+        for (row, col) in [(0, 0), (2, 3), (bricks.rows - 1, bricks.cols - 1)] {
+            let center = cell_center(&bricks, row, col);
+            assert_eq!(cell_at(&bricks, center), Some((row, col)));
+
+            // Anywhere inside the brick still picks the same cell
+            let inside = center + Vec2::new(-20.0, 5.0);
+            assert_eq!(cell_at(&bricks, inside), Some((row, col)));
+        }
+    }
+
+    #[test]
+    fn clicks_outside_the_grid_map_to_nothing() {
+        let bricks = GameConfig::default().bricks.fit_to_playfield();
+        assert_eq!(cell_at(&bricks, Vec2::new(0.0, PADDLE_Y)), None);
+        assert_eq!(cell_at(&bricks, Vec2::new(0.0, WINDOW_HEIGHT / 2.0)), None);
+        assert_eq!(
+            cell_at(
+                &bricks,
+                Vec2::new(-WINDOW_WIDTH / 2.0, cell_center(&bricks, 0, 0).y)
+            ),
+            None
+        );
+    }
+
+    // --- LevelLayout ---
+
+    fn edited_layout() -> LevelLayout {
+        let mut layout = LevelLayout::empty(3, 4);
+        layout.set(0, 0, Some(EditorBrick::Plain));
+        layout.set(1, 2, Some(EditorBrick::Bomb));
+        layout.set(2, 3, Some(EditorBrick::Enemy));
+        layout.set(2, 1, Some(EditorBrick::Explosive));
+        layout.set(2, 1, None); // erased again
+        layout
+    }
+
+    #[test]
+    fn level_round_trips_through_text() {
+        let layout = edited_layout();
+        assert_eq!(
+            layout.to_text(),
+            "breakout-level 1\n#...\n..B.\n...E\n".to_string()
+        );
+        assert_eq!(LevelLayout::parse(&layout.to_text()), Ok(layout));
+    }
+
+    #[test]
+    fn edited_level_round_trips_through_file() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-level")
+            .join("level");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(read_level(&path), Err(LevelError::Missing));
+        assert!(write_level(&path, &edited_layout()).is_ok());
+        assert_eq!(read_level(&path), Ok(edited_layout()));
+    }
+
+    #[test]
+    fn level_specs_place_bricks_on_the_game_grid() {
+        let bricks = GameConfig::default().bricks.fit_to_playfield();
+        let mut layout = LevelLayout::empty(bricks.rows, bricks.cols);
+        layout.set(0, 1, Some(EditorBrick::Bomb));
+        layout.set(4, 2, Some(EditorBrick::Plain));
+        layout.set(4, 3, Some(EditorBrick::Portal));
+
+        let specs = layout.specs(&bricks, 3).unwrap();
+        assert_eq!(specs.len(), 3);
+        assert_eq!(specs[0].position, cell_center(&bricks, 0, 1));
+        assert_eq!(specs[0].kind, BrickKind::Bomb);
+        assert_eq!(specs[0].hits, brick_health(3, 0, bricks.rows));
+        assert_eq!(specs[1].position, cell_center(&bricks, 4, 2));
+        assert_eq!(specs[1].kind, BrickKind::Plain);
+        assert_eq!(specs[2].kind, BrickKind::Portal);
+
+        assert_eq!(edited_layout().specs(&bricks, 1), None);
+    }
+
+    #[test]
+    fn saved_level_replaces_the_bricks_when_play_starts() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-play-level")
+            .join("level");
+        let bricks = GameConfig::default().bricks.fit_to_playfield();
+        let mut layout = LevelLayout::empty(bricks.rows, bricks.cols);
+        layout.set(1, 1, Some(EditorBrick::Explosive));
+        layout.set(2, 5, Some(EditorBrick::Plain));
+        assert!(write_level(&path, &layout).is_ok());
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<GameConfig>();
+        app.init_resource::<CurrentLevel>();
+        app.insert_resource(LevelEditor {
+            path: path.clone(),
+            ..default()
+        });
+        app.world_mut().spawn((Transform::default(), Brick));
+        app.add_systems(Update, spawn_saved_level);
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, Has<ExplosiveBrick>), With<Brick>>();
+        let mut placed: Vec<(Vec2, bool)> = q
+            .iter(app.world())
+            .map(|(transform, explosive)| (transform.translation.truncate(), explosive))
+            .collect();
+        placed.sort_by(|a, b| b.0.y.total_cmp(&a.0.y));
+        assert_eq!(
+            placed,
+            vec![
+                (cell_center(&bricks, 1, 1), true),
+                (cell_center(&bricks, 2, 5), false)
+            ]
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn level_parse_rejects_ragged_and_unknown_rows() {
+        assert_eq!(
+            LevelLayout::parse("breakout-level 1\n#..\n#.\n"),
+            Err(LevelError::Malformed("#.".to_string()))
+        );
+        assert_eq!(
+            LevelLayout::parse("breakout-level 1\nP.P\n").map(|layout| layout.cells.len()),
+            Ok(3)
+        );
+        assert_eq!(
+            LevelLayout::parse("breakout-level 1\n#?.\n"),
+            Err(LevelError::Malformed("#?.".to_string()))
+        );
+        assert_eq!(
+            LevelLayout::parse("breakout-level 9\n"),
+            Err(LevelError::Outdated("breakout-level 9".to_string()))
+        );
+    }
+}
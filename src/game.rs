@@ -1,5 +1,6 @@
 use bevy::app::AppExit;
 use bevy::ecs::prelude::MessageWriter;
+use bevy::input::gamepad::GamepadConnectionEvent;
 use bevy::prelude::*;
 
 use crate::components::*;
@@ -7,13 +8,14 @@ use crate::components::*;
 /// Updates the score text when the score changes.
 pub fn update_scoreboard_ui(
     scoreboard: Res<Scoreboard>,
+    settings: Res<Settings>,
     mut query: Query<&mut Text, With<ScoreboardUi>>,
 ) {
-    if !scoreboard.is_changed() {
+    if !scoreboard.is_changed() && !settings.is_changed() {
         return;
     }
     for mut text in &mut query {
-        **text = format!("Score: {}", scoreboard.score);
+        **text = settings.score_format.score(scoreboard.score);
     }
 }
 
@@ -27,6 +29,346 @@ pub fn update_lives_ui(lives: Res<Lives>, mut query: Query<&mut Text, With<Lives
     }
 }
 
+/// Returns the ball tint for `speed`, shifting from white toward red above base speed.
+pub fn ball_speed_color(speed: f32) -> Color {
+    let t = ((speed - BALL_SPEED) / (BALL_FAST_COLOR_SPEED - BALL_SPEED)).clamp(0.0, 1.0);
+    BALL_COLOR.mix(&BALL_FAST_COLOR, t)
+}
+
+/// Tints each ball according to its own speed.
+pub fn update_ball_color(mut query: Query<(&Velocity, &mut Sprite), With<Ball>>) {
+    for (velocity, mut sprite) in &mut query {
+        sprite.color = ball_speed_color(velocity.0.length());
+    }
+}
+
+/// Predicts where the ball centre crosses the paddle line (`bounds.min.y`), reflecting off the
+/// side walls (`bounds.min.x`/`max.x`) and the ceiling (`bounds.max.y`) but ignoring bricks.
+pub fn predict_landing_x(ball_pos: Vec2, velocity: Vec2, bounds: Rect) -> f32 {
+    if velocity.y == 0.0 {
+        return ball_pos.x;
+    }
+
+    // Vertical distance still to travel, bouncing off the ceiling first if heading up
+    let distance = if velocity.y > 0.0 {
+        (bounds.max.y - ball_pos.y) + (bounds.max.y - bounds.min.y)
+    } else {
+        ball_pos.y - bounds.min.y
+    };
+    let unfolded_x = ball_pos.x + velocity.x * distance.max(0.0) / velocity.y.abs();
+
+    // Fold the straight-line x back into the walls; each fold is one bounce
+    let width = bounds.max.x - bounds.min.x;
+    if width <= 0.0 {
+        return bounds.min.x;
+    }
+    let folded = (unfolded_x - bounds.min.x).rem_euclid(2.0 * width);
+    bounds.min.x
+        + if folded > width {
+            2.0 * width - folded
+        } else {
+            folded
+        }
+}
+
+/// Returns the area a ball of `ball_size` can travel through above the paddle within `bounds`,
+/// for `predict_landing_x`.
+pub fn landing_bounds(bounds: &PlayfieldBounds, ball_size: Vec2) -> Rect {
+    let (min_x, max_x) = bounds.ball_x_range(ball_size.x);
+    Rect::new(
+        min_x,
+        PADDLE_Y + PADDLE_HEIGHT / 2.0 + ball_size.y / 2.0,
+        max_x,
+        bounds.top - ball_size.y / 2.0,
+    )
+}
+
+/// Moves the landing indicator under the ball's predicted landing point, hidden unless enabled.
+#[allow(clippy::type_complexity)]
+pub fn update_landing_indicator(
+    settings: Res<Settings>,
+    config: Res<GameConfig>,
+    ball_query: Query<(&Transform, &Velocity, &ColliderSize), With<Ball>>,
+    mut indicator_query: Query<
+        (&mut Transform, &mut Visibility),
+        (With<LandingIndicator>, Without<Ball>),
+    >,
+) {
+    let Ok((mut indicator_transform, mut visibility)) = indicator_query.single_mut() else {
+        return;
+    };
+    let ball = ball_query
+        .single()
+        .ok()
+        .filter(|_| settings.landing_indicator);
+    let Some((ball_transform, velocity, ball_collider)) = ball else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    indicator_transform.translation.x = predict_landing_x(
+        ball_transform.translation.truncate(),
+        velocity.0,
+        landing_bounds(&PlayfieldBounds::from_config(&config), ball_collider.0),
+    );
+    *visibility = Visibility::Visible;
+}
+
+/// Shows or hides the brick points legend to match the settings, and keeps its points in step
+/// with per-row scoring.
+pub fn update_score_legend(
+    settings: Res<Settings>,
+    config: Res<GameConfig>,
+    mut legend_query: Query<&mut Visibility, With<ScoreLegendUi>>,
+    mut points_query: Query<(&ScoreLegendPoints, &mut Text)>,
+) {
+    if config.is_changed() {
+        for (entry, mut text) in &mut points_query {
+            let points = brick_row_points(entry.0, config.row_points).to_string();
+            if text.0 != points {
+                text.0 = points;
+            }
+        }
+    }
+    if !settings.is_changed() {
+        return;
+    }
+    let visibility = if settings.score_legend {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut legend in &mut legend_query {
+        *legend = visibility;
+    }
+}
+
+/// Shrinks each boss's health bar toward its left end as the boss loses health.
+pub fn update_boss_health_bar(
+    boss_query: Query<(&BrickHealth, &ColliderSize, &Children), With<BossBrick>>,
+    mut bar_query: Query<(&mut Sprite, &mut Transform), With<BossHealthBar>>,
+) {
+    for (health, collider_size, children) in &boss_query {
+        let width = collider_size.0.x * (health.hits as f32 / BOSS_HEALTH as f32).min(1.0);
+        for child in children {
+            let Ok((mut sprite, mut transform)) = bar_query.get_mut(*child) else {
+                continue;
+            };
+            sprite.custom_size = Some(Vec2::new(width, BOSS_BAR_HEIGHT));
+            transform.translation.x = (width - collider_size.0.x) / 2.0;
+        }
+    }
+}
+
+/// Points the aim arrow out of a stuck ball along the serve direction, hiding it otherwise.
+#[allow(clippy::type_complexity)]
+pub fn update_aim_arrow(
+    aim: Res<AimAngle>,
+    ball_query: Query<(&Transform, &ColliderSize), (With<Ball>, With<StuckToPaddle>)>,
+    mut arrow_query: Query<(&mut Transform, &mut Visibility), (With<AimArrow>, Without<Ball>)>,
+) {
+    let Ok((mut arrow_transform, mut visibility)) = arrow_query.single_mut() else {
+        return;
+    };
+    let Ok((ball_transform, ball_collider)) = ball_query.single() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let direction = Vec2::new(aim.0.sin(), aim.0.cos());
+    let center = ball_transform.translation.truncate()
+        + direction * (ball_collider.0.y / 2.0 + AIM_ARROW_LENGTH / 2.0);
+    arrow_transform.translation = center.extend(arrow_transform.translation.z);
+    arrow_transform.rotation = Quat::from_rotation_z(-aim.0);
+    *visibility = Visibility::Visible;
+}
+
+/// Hangs a catch tray under the paddle while a ball is stuck to it and removes it on launch.
+pub fn sync_catch_indicator(
+    mut commands: Commands,
+    stuck_query: Query<(), With<StuckToPaddle>>,
+    paddle_query: Query<(Entity, &ColliderSize), With<Paddle>>,
+    indicator_query: Query<Entity, With<CatchIndicator>>,
+) {
+    if stuck_query.is_empty() {
+        for entity in &indicator_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !indicator_query.is_empty() {
+        return;
+    }
+    let Ok((paddle, paddle_collider)) = paddle_query.single() else {
+        return;
+    };
+
+    // A child of the paddle, so it follows every paddle move
+    let offset = paddle_collider.0.y / 2.0 + CATCH_INDICATOR_GAP + CATCH_INDICATOR_HEIGHT / 2.0;
+    commands.entity(paddle).with_child((
+        Sprite {
+            color: CATCH_INDICATOR_COLOR,
+            custom_size: Some(Vec2::new(paddle_collider.0.x, CATCH_INDICATOR_HEIGHT)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, -offset, 0.1),
+        CatchIndicator,
+    ));
+}
+
+/// Advances the UI clock by the frame's real time.
+pub fn tick_ui_time(real_time: Res<Time<Real>>, mut ui_time: ResMut<UiTime>) {
+    ui_time.delta = real_time.delta();
+    ui_time.elapsed = real_time.elapsed();
+}
+
+/// Shows a pulsing red border while exactly one life is left and removes it otherwise.
+/// The border holds steady when reduce motion is on.
+pub fn update_last_life_warning(
+    lives: Res<Lives>,
+    ui_time: Res<UiTime>,
+    settings: Res<Settings>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut BorderColor), With<LastLifeWarningUi>>,
+) {
+    if lives.count != 1 {
+        for (entity, _) in &query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if query.is_empty() {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                border: UiRect::all(Val::Px(LAST_LIFE_BORDER_WIDTH)),
+                ..default()
+            },
+            BorderColor::all(LAST_LIFE_COLOR),
+            UI_Z_LAST_LIFE_WARNING,
+            LastLifeWarningUi,
+        ));
+        return;
+    }
+
+    let pulse = if settings.reduce_motion {
+        1.0
+    } else {
+        0.5 + 0.5 * (ui_time.elapsed_secs() * LAST_LIFE_PULSE_SPEED).sin()
+    };
+    for (_, mut border) in &mut query {
+        *border = BorderColor::all(LAST_LIFE_COLOR.with_alpha(0.3 + 0.7 * pulse));
+    }
+}
+
+/// Starts a short camera shake whenever a life is lost.
+pub fn trigger_screen_shake(
+    lives: Res<Lives>,
+    mut shake: ResMut<ScreenShake>,
+    mut last_count: Local<Option<u32>>,
+) {
+    if last_count.is_some_and(|last| lives.count < last) {
+        shake.remaining = SCREEN_SHAKE_DURATION;
+    }
+    *last_count = Some(lives.count);
+}
+
+/// Returns the camera centre and scale framing `bricks` once few are left, or the whole
+/// playfield otherwise. The frame always reaches down to the paddle and stays inside the field.
+pub fn zoom_target(bricks: &[Vec2]) -> (Vec2, f32) {
+    if bricks.is_empty() || bricks.len() > ZOOM_BRICK_THRESHOLD {
+        return (Vec2::ZERO, 1.0);
+    }
+
+    let (mut min, mut max) = (bricks[0], bricks[0]);
+    for brick in bricks {
+        min = min.min(*brick);
+        max = max.max(*brick);
+    }
+    min -= Vec2::splat(ZOOM_MARGIN);
+    max += Vec2::splat(ZOOM_MARGIN);
+    min.y = min.y.min(PADDLE_Y - PADDLE_HEIGHT);
+
+    let size = max - min;
+    let scale = (size.x / WINDOW_WIDTH)
+        .max(size.y / WINDOW_HEIGHT)
+        .clamp(ZOOM_MIN_SCALE, 1.0);
+    let limit = Vec2::new(WINDOW_WIDTH, WINDOW_HEIGHT) / 2.0 * (1.0 - scale);
+    let center = ((min + max) / 2.0).clamp(-limit, limit);
+    (center, scale)
+}
+
+/// Eases the finale zoom toward the remaining bricks and applies its scale to the camera;
+/// reduce motion keeps the whole playfield in view.
+pub fn update_camera_zoom(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut zoom: ResMut<CameraZoom>,
+    brick_query: Query<&Transform, With<Brick>>,
+    mut camera_query: Query<&mut Projection, With<Camera2d>>,
+) {
+    if settings.reduce_motion {
+        if zoom.scale != 1.0 || zoom.center != Vec2::ZERO {
+            *zoom = CameraZoom::default();
+        }
+    } else {
+        let bricks: Vec<Vec2> = brick_query
+            .iter()
+            .map(|transform| transform.translation.truncate())
+            .collect();
+        let (center, scale) = zoom_target(&bricks);
+        let t = (ZOOM_EASE_RATE * time.delta_secs()).min(1.0);
+        if zoom.scale != scale || zoom.center != center {
+            zoom.center = zoom.center.lerp(center, t);
+            zoom.scale += (scale - zoom.scale) * t;
+        }
+    }
+
+    for mut projection in &mut camera_query {
+        if let Projection::Orthographic(ortho) = projection.as_mut()
+            && ortho.scale != zoom.scale
+        {
+            ortho.scale = zoom.scale;
+        }
+    }
+}
+
+/// Snaps the camera back to the whole playfield (on victory and back on the menu).
+pub fn reset_camera_zoom(mut zoom: ResMut<CameraZoom>) {
+    *zoom = CameraZoom::default();
+}
+
+/// Jitters the camera while a shake is playing, easing out, around the finale zoom's centre;
+/// reduce motion keeps it still.
+pub fn apply_screen_shake(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    zoom: Res<CameraZoom>,
+    mut shake: ResMut<ScreenShake>,
+    mut rng: Local<SeededRng>,
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+) {
+    let Ok(mut camera_transform) = camera_query.single_mut() else {
+        return;
+    };
+
+    if settings.reduce_motion || shake.remaining <= 0.0 {
+        shake.remaining = 0.0;
+        camera_transform.translation.x = zoom.center.x;
+        camera_transform.translation.y = zoom.center.y;
+        return;
+    }
+
+    shake.remaining = (shake.remaining - time.delta_secs()).max(0.0);
+    let strength = SCREEN_SHAKE_INTENSITY * shake.remaining / SCREEN_SHAKE_DURATION;
+    let angle = rng.f32() * std::f32::consts::TAU;
+    camera_transform.translation.x = zoom.center.x + angle.cos() * strength;
+    camera_transform.translation.y = zoom.center.y + angle.sin() * strength;
+}
+
 /// Transitions to GameOver when lives reach 0.
 pub fn check_game_over(
     lives: Res<Lives>,
@@ -36,7 +378,7 @@ pub fn check_game_over(
     if lives.count == 0 {
         next_state.set(GameState::GameOver);
         commands.spawn((
-            Text::new("GAME OVER\n\nPress SPACE to restart"),
+            Text::new("GAME OVER"),
             TextFont {
                 font_size: 40.0,
                 ..default()
@@ -44,429 +386,3261 @@ pub fn check_game_over(
             TextColor(Color::srgb(1.0, 0.3, 0.3)),
             TextLayout::new_with_justify(Justify::Center),
             Node {
-                position_type: PositionType::Absolute,
-                top: Val::Percent(35.0),
-                width: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                ..default()
+                top: Val::Percent(25.0),
+                ..crate::setup::overlay_node()
             },
+            UI_Z_OVERLAY,
             OverlayUi,
         ));
     }
 }
 
-/// Transitions to Victory when all bricks are destroyed.
-pub fn check_victory(
-    brick_query: Query<&Brick>,
+/// Updates the countdown text in time attack mode.
+pub fn update_timer_ui(time_attack: Res<TimeAttack>, mut query: Query<&mut Text, With<TimerUi>>) {
+    if !time_attack.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = if time_attack.active {
+            format!("Time: {}", time_attack.remaining.ceil())
+        } else {
+            String::new()
+        };
+    }
+}
+
+/// Counts down the time attack timer and transitions to GameOver when it runs out.
+pub fn tick_time_attack(
+    time: Res<Time>,
+    mut time_attack: ResMut<TimeAttack>,
     mut next_state: ResMut<NextState<GameState>>,
     mut commands: Commands,
-    scoreboard: Res<Scoreboard>,
 ) {
-    if brick_query.is_empty() {
-        next_state.set(GameState::Victory);
+    if !time_attack.active || time_attack.remaining <= 0.0 {
+        return;
+    }
+
+    time_attack.remaining = (time_attack.remaining - time.delta_secs()).max(0.0);
+
+    if time_attack.remaining == 0.0 {
+        next_state.set(GameState::GameOver);
         commands.spawn((
-            Text::new(format!(
-                "YOU WIN!\n\nScore: {}\n\nPress SPACE to restart",
-                scoreboard.score
-            )),
+            Text::new("TIME UP"),
             TextFont {
                 font_size: 40.0,
                 ..default()
             },
-            TextColor(Color::srgb(0.3, 1.0, 0.3)),
+            TextColor(Color::srgb(1.0, 0.3, 0.3)),
             TextLayout::new_with_justify(Justify::Center),
             Node {
-                position_type: PositionType::Absolute,
-                top: Val::Percent(30.0),
-                width: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                ..default()
+                top: Val::Percent(25.0),
+                ..crate::setup::overlay_node()
             },
+            UI_Z_OVERLAY,
             OverlayUi,
         ));
     }
 }
 
-/// Handles SPACE press on the menu screen to start the game.
-pub fn menu_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    if keyboard.just_pressed(KeyCode::Space) {
-        next_state.set(GameState::Playing);
-    }
-}
-
-/// Handles SPACE press on GameOver/Victory screens to restart.
+/// Transitions to Victory when the configured share of bricks is destroyed or a boss is beaten,
+/// clearing whatever bricks are left behind.
 #[allow(clippy::too_many_arguments)]
-pub fn restart_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+pub fn check_victory(
+    brick_query: Query<Entity, With<Brick>>,
+    boss_query: Query<&BrickHealth, With<BossBrick>>,
+    config: Res<GameConfig>,
+    destroyed_bricks: Res<DestroyedBricks>,
     mut next_state: ResMut<NextState<GameState>>,
     mut commands: Commands,
     mut scoreboard: ResMut<Scoreboard>,
-    mut lives: ResMut<Lives>,
-    brick_query: Query<Entity, With<Brick>>,
-    ball_query: Query<Entity, With<Ball>>,
-    paddle_query: Query<Entity, With<Paddle>>,
-    wall_query: Query<Entity, With<Wall>>,
+    time_attack: Res<TimeAttack>,
+    settings: Res<Settings>,
 ) {
-    if keyboard.just_pressed(KeyCode::Space) {
-        // Reset resources
-        scoreboard.score = 0;
-        lives.count = 3;
+    let boss_defeated = boss_query.iter().any(|health| health.hits == 0);
+    // Regenerated bricks leave the destroyed list, so destroyed plus remaining stays the total
+    let cleared = config
+        .victory
+        .is_met(destroyed_bricks.bricks.len(), brick_query.iter().len());
+    if cleared || boss_defeated {
+        next_state.set(GameState::Victory);
+        for brick in &brick_query {
+            commands.entity(brick).despawn();
+        }
 
-        // Despawn all game entities
-        for entity in brick_query
-            .iter()
-            .chain(ball_query.iter())
-            .chain(paddle_query.iter())
-            .chain(wall_query.iter())
-        {
-            commands.entity(entity).despawn();
+        // Leftover time attack seconds become bonus points
+        if time_attack.active {
+            scoreboard.score += time_attack.remaining.ceil() as u32 * TIME_ATTACK_BONUS_PER_SECOND;
         }
 
-        // Re-spawn the game
-        next_state.set(GameState::Menu);
+        // The tally counts up from the total the board started with
+        commands.spawn((
+            Text::new(victory_text(scoreboard.board_start, &settings.score_format)),
+            TextFont {
+                font_size: 40.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.3, 1.0, 0.3)),
+            TextLayout::new_with_justify(Justify::Center),
+            Node {
+                top: Val::Percent(15.0),
+                ..crate::setup::overlay_node()
+            },
+            ScoreTally {
+                from: scoreboard.board_start,
+                to: scoreboard.score,
+                timer: Timer::from_seconds(SCORE_TALLY_DURATION, TimerMode::Once),
+            },
+            UI_Z_OVERLAY,
+            OverlayUi,
+        ));
     }
 }
 
-/// Re-spawns game entities when entering Menu (after a restart).
-pub fn respawn_on_menu_enter(
-    commands: Commands,
-    paddle_query: Query<&Paddle>,
-    mut first_run: Local<bool>,
-) {
-    // Skip on first run — entities already spawned by Startup, but commands
-    // haven't been applied yet so the query would be empty.
-    if !*first_run {
-        *first_run = true;
-        return;
-    }
-
-    // Only respawn if there's no paddle (i.e., coming from a restart)
-    if paddle_query.is_empty() {
-        crate::setup::spawn_game(commands);
-    }
+/// Formats the victory headline with the tallied score.
+fn victory_text(score: u32, format: &ScoreFormat) -> String {
+    format!("YOU WIN!\n\n{}", format.score(score))
 }
 
-/// Toggles pause when ESC is pressed during gameplay.
-pub fn pause_input(
+/// Counts the victory score up, jumping to the total on any key press.
+pub fn update_score_tally(
     keyboard: Res<ButtonInput<KeyCode>>,
-    state: Res<State<GameState>>,
-    mut next_state: ResMut<NextState<GameState>>,
+    ui_time: Res<UiTime>,
+    settings: Res<Settings>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Text, &mut ScoreTally)>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
-        match state.get() {
-            GameState::Playing => next_state.set(GameState::Paused),
-            GameState::Paused => next_state.set(GameState::Playing),
-            _ => {}
+    let skip = keyboard.get_just_pressed().next().is_some();
+
+    for (entity, mut text, mut tally) in &mut query {
+        let step = if skip {
+            tally.timer.duration()
+        } else {
+            ui_time.delta
+        };
+        tally.timer.tick(step);
+
+        **text = victory_text(tally.value(), &settings.score_format);
+        if tally.timer.is_finished() {
+            commands.entity(entity).remove::<ScoreTally>();
         }
     }
 }
 
-/// Spawns the pause menu with Resume and Quit buttons.
-pub fn spawn_pause_overlay(mut commands: Commands, mut menu_state: ResMut<PauseMenuState>) {
-    // Reset menu selection to Resume
-    menu_state.selected = 0;
-
-    // Semi-transparent full-screen background (z-index via spawn order)
-    commands.spawn((
-        Node {
-            position_type: PositionType::Absolute,
-            width: Val::Percent(100.0),
-            height: Val::Percent(100.0),
-            ..default()
-        },
-        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
-        GlobalZIndex(10),
-        OverlayUi,
-    ));
+/// Formats the run statistics listed on the results screen.
+fn results_text(stats: &GameStats, high_score: u32, seed: u64, format: &ScoreFormat) -> String {
+    let bricks_per_ball = stats.bricks_destroyed as f32 / stats.balls_lost.max(1) as f32;
+    let seconds = stats.playtime as u32;
+    format!(
+        "{}\nHigh score: {}\nBricks destroyed: {}\nMax combo: {}\nBalls lost: {}\nBricks per ball: {bricks_per_ball:.1}\nTime: {}:{:02}\nSeed: {seed}",
+        format.score(stats.score),
+        format.number(high_score),
+        stats.bricks_destroyed,
+        stats.max_combo,
+        stats.balls_lost,
+        seconds / 60,
+        seconds % 60
+    )
+}
 
-    // Menu container (centered, in front of background)
+/// Spawns the results column under the GameOver/Victory headline.
+pub fn spawn_results_screen(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    stats: Res<GameStats>,
+    high_score: Res<HighScore>,
+    game_rng: Res<GameRng>,
+    settings: Res<Settings>,
+) {
+    let prompt = if *state.get() == GameState::Victory {
+        "Press C to keep playing, SPACE or ENTER for a new run, R to retry this seed"
+    } else {
+        "Press SPACE or ENTER to continue, R to retry this seed"
+    };
     commands
         .spawn((
             Node {
-                position_type: PositionType::Absolute,
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
+                top: Val::Percent(45.0),
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
                 row_gap: Val::Px(20.0),
-                ..default()
+                ..crate::setup::overlay_node()
             },
-            GlobalZIndex(11),
+            UI_Z_OVERLAY,
             OverlayUi,
         ))
         .with_children(|parent| {
-            // Title
             parent.spawn((
-                Text::new("PAUSED"),
+                Text::new(results_text(
+                    &stats,
+                    high_score.score,
+                    game_rng.seed,
+                    &settings.score_format,
+                )),
                 TextFont {
-                    font_size: 48.0,
+                    font_size: 22.0,
                     ..default()
                 },
                 TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+                ResultsUi,
+            ));
+            parent.spawn((
+                Text::new(prompt),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                Visibility::Hidden,
+                ResultsPromptUi,
             ));
+        });
+}
 
-            // Spacer
-            parent.spawn(Node {
-                height: Val::Px(30.0),
-                ..default()
-            });
+/// Keeps the results in sync with the final stats and reveals the prompt once any tally is done.
+pub fn update_results_screen(
+    stats: Res<GameStats>,
+    high_score: Res<HighScore>,
+    game_rng: Res<GameRng>,
+    settings: Res<Settings>,
+    tally_query: Query<(), With<ScoreTally>>,
+    mut text_query: Query<&mut Text, With<ResultsUi>>,
+    mut prompt_query: Query<&mut Visibility, With<ResultsPromptUi>>,
+) {
+    if stats.is_changed() || high_score.is_changed() || settings.is_changed() {
+        for mut text in &mut text_query {
+            **text = results_text(
+                &stats,
+                high_score.score,
+                game_rng.seed,
+                &settings.score_format,
+            );
+        }
+    }
 
-            // Resume button
-            parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(200.0),
-                        height: Val::Px(50.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BackgroundColor(BUTTON_HOVERED), // Selected by default
-                    ResumeButton,
-                ))
-                .with_child((
-                    Text::new("Resume"),
-                    TextFont {
-                        font_size: 24.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                ));
+    let prompt = if tally_query.is_empty() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in &mut prompt_query {
+        if *visibility != prompt {
+            *visibility = prompt;
+        }
+    }
+}
 
-            // Quit button
-            parent
-                .spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(200.0),
-                        height: Val::Px(50.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BackgroundColor(BUTTON_NORMAL),
-                    QuitButton,
-                ))
-                .with_child((
-                    Text::new("Quit"),
-                    TextFont {
-                        font_size: 24.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                ));
-        });
+/// Plays a tick at a steady rate while a score tally is counting.
+pub fn play_score_tally_ticks(
+    ui_time: Res<UiTime>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    mut since_tick: Local<f32>,
+    query: Query<(), With<ScoreTally>>,
+) {
+    if query.is_empty() {
+        *since_tick = SCORE_TALLY_TICK_INTERVAL;
+        return;
+    }
+
+    *since_tick += ui_time.delta_secs();
+    if *since_tick >= SCORE_TALLY_TICK_INTERVAL {
+        *since_tick = 0.0;
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(SCORE_TALLY_TICK_SOUND_PATH)),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
 }
 
-/// Handles mouse interaction with pause menu buttons.
-#[allow(clippy::type_complexity)]
-pub fn pause_menu_mouse_interaction(
-    mut interaction_query: Query<
-        (
-            &Interaction,
-            &mut BackgroundColor,
-            Option<&ResumeButton>,
-            Option<&QuitButton>,
-        ),
-        (Changed<Interaction>, With<Button>),
-    >,
+/// Returns the playback speed for a wall bounce: higher off the ceiling and for faster balls.
+pub fn bounce_playback_speed(side: CollisionSide, speed: f32) -> f32 {
+    let wall_pitch = match side {
+        CollisionSide::Top | CollisionSide::Bottom => BOUNCE_PITCH_CEILING,
+        CollisionSide::Left | CollisionSide::Right => 1.0,
+    };
+    let speed_pitch = 1.0 + (speed / BALL_SPEED - 1.0) * BOUNCE_PITCH_PER_SPEED;
+    (wall_pitch * speed_pitch).clamp(BOUNCE_PITCH_MIN, BOUNCE_PITCH_MAX)
+}
+
+/// Plays the bounce sound for each wall bounce, pitched by `bounce_playback_speed`.
+pub fn play_bounce_sounds(
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+    mut bounces: MessageReader<BounceEvent>,
+) {
+    for bounce in bounces.read() {
+        commands.spawn((
+            AudioPlayer::new(asset_server.load(BOUNCE_SOUND_PATH)),
+            PlaybackSettings::DESPAWN.with_speed(bounce_playback_speed(bounce.side, bounce.speed)),
+        ));
+    }
+}
+
+/// Puts a fresh ball on the paddle if play has no ball left but lives remain, so a lost ball
+/// that skipped the death zone can't soft-lock the run.
+pub fn respawn_missing_ball(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    lives: Res<Lives>,
+    mut serve: ResMut<Serve>,
+    ball_query: Query<(), With<Ball>>,
+    paddle_query: Query<&Transform, With<Paddle>>,
+) {
+    if !ball_query.is_empty() || lives.count == 0 {
+        return;
+    }
+
+    warn!("No ball left in play; respawning one on the paddle");
+    let paddle_x = paddle_query
+        .single()
+        .map_or(0.0, |transform| transform.translation.x);
+    let ball = crate::setup::spawn_ball(&mut commands, config.ball_size.diameter(), paddle_x);
+    commands.entity(ball).insert(StuckToPaddle);
+    *serve = Serve::default();
+}
+
+/// Returns to Ready once the ball is back on the paddle (after a lost life or a resume).
+pub fn enter_ready_when_ball_stuck(
     mut next_state: ResMut<NextState<GameState>>,
-    mut app_exit: MessageWriter<AppExit>,
-    mut menu_state: ResMut<PauseMenuState>,
+    stuck_query: Query<(), (With<Ball>, With<StuckToPaddle>)>,
 ) {
-    for (interaction, mut bg_color, is_resume, is_quit) in &mut interaction_query {
-        match *interaction {
-            Interaction::Pressed => {
-                *bg_color = BUTTON_PRESSED.into();
-                if is_resume.is_some() {
-                    next_state.set(GameState::Playing);
-                } else if is_quit.is_some() {
-                    app_exit.write(AppExit::Success);
-                }
-            }
-            Interaction::Hovered => {
-                *bg_color = BUTTON_HOVERED.into();
-                // Update keyboard selection to match hovered button
-                if is_resume.is_some() {
-                    menu_state.selected = 0;
-                } else if is_quit.is_some() {
-                    menu_state.selected = 1;
-                }
-            }
-            Interaction::None => {
-                // Only reset to normal if not currently keyboard-selected
-                let is_selected = (is_resume.is_some() && menu_state.selected == 0)
-                    || (is_quit.is_some() && menu_state.selected == 1);
-                if !is_selected {
-                    *bg_color = BUTTON_NORMAL.into();
-                }
+    if !stuck_query.is_empty() {
+        next_state.set(GameState::Ready);
+    }
+}
+
+/// Starts play once `launch_ball` has served the ball.
+pub fn enter_playing_on_launch(
+    mut next_state: ResMut<NextState<GameState>>,
+    stuck_query: Query<(), (With<Ball>, With<StuckToPaddle>)>,
+) {
+    if stuck_query.is_empty() {
+        next_state.set(GameState::Playing);
+    }
+}
+
+/// Handles SPACE (normal) or T (time attack) on the menu screen to start the game.
+pub fn menu_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut time_attack: ResMut<TimeAttack>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        time_attack.active = false;
+        next_state.set(GameState::Ready);
+    } else if keyboard.just_pressed(KeyCode::KeyT) {
+        time_attack.active = true;
+        time_attack.remaining = TIME_ATTACK_DURATION;
+        next_state.set(GameState::Ready);
+    }
+}
+
+/// Clears the run's score, lives, level, and power-ups and despawns its entities, ready for the
+/// game to be spawned afresh.
+pub fn reset_game_state(
+    commands: &mut Commands,
+    scoreboard: &mut Scoreboard,
+    lives: &mut Lives,
+    level: &mut CurrentLevel,
+    active_powerups: &mut ActivePowerUps,
+    destroyed_bricks: &mut DestroyedBricks,
+    entities: impl IntoIterator<Item = Entity>,
+) {
+    *scoreboard = Scoreboard::default();
+    lives.count = 3;
+    *level = CurrentLevel::default();
+    active_powerups.timers.clear();
+    destroyed_bricks.bricks.clear();
+
+    for entity in entities {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Handles SPACE or ENTER (fresh seed) or R (same seed) on GameOver/Victory screens to restart
+/// once any score tally is done.
+#[allow(clippy::too_many_arguments)]
+pub fn restart_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut level: ResMut<CurrentLevel>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    mut game_rng: ResMut<GameRng>,
+    leaderboard: Res<Leaderboard>,
+    mut initials_entry: ResMut<InitialsEntry>,
+    run_query: Query<Entity, RunEntity>,
+    tally_query: Query<(), With<ScoreTally>>,
+) {
+    let retry = keyboard.just_pressed(KeyCode::KeyR);
+    // The first press while the score is still counting only skips the tally
+    let accept = keyboard.any_just_pressed([KeyCode::Space, KeyCode::Enter]);
+    if (accept || retry) && tally_query.is_empty() {
+        game_rng.retry_seed = retry;
+        let score = scoreboard.score;
+        reset_game_state(
+            &mut commands,
+            &mut scoreboard,
+            &mut lives,
+            &mut level,
+            &mut active_powerups,
+            &mut destroyed_bricks,
+            run_query,
+        );
+
+        // A qualifying run asks for initials before the game is re-spawned on the menu
+        if leaderboard.qualifies(score) {
+            *initials_entry = InitialsEntry {
+                score,
+                initials: String::new(),
+            };
+            next_state.set(GameState::EnterInitials);
+        } else {
+            next_state.set(GameState::Menu);
+        }
+    }
+}
+
+/// Warns when a results screen is spawned on top of another one, which means an overlay was
+/// orphaned by a state transition.
+pub fn warn_stacked_results_screens(
+    added_query: Query<(), Added<ResultsUi>>,
+    results_query: Query<(), With<ResultsUi>>,
+) {
+    if added_query.is_empty() {
+        return;
+    }
+    let count = results_query.iter().count();
+    if count > 1 {
+        warn!("{count} results screens are open; an earlier overlay was not despawned");
+    }
+}
+
+/// Handles C on the Victory screen to move on to the next level's board with the current score
+/// and lives once any score tally is done. Time attack ends with the cleared board, so its bonus
+/// is only paid once.
+#[allow(clippy::too_many_arguments)]
+pub fn continue_after_victory(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut level: ResMut<CurrentLevel>,
+    config: Res<GameConfig>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    mut time_attack: ResMut<TimeAttack>,
+    run_query: Query<Entity, RunEntity>,
+    tally_query: Query<(), With<ScoreTally>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyC) || !tally_query.is_empty() {
+        return;
+    }
+    active_powerups.timers.clear();
+    destroyed_bricks.bricks.clear();
+    *time_attack = TimeAttack::default();
+    scoreboard.board_start = scoreboard.score;
+    level.number += 1;
+    for entity in &run_query {
+        commands.entity(entity).despawn();
+    }
+
+    crate::setup::spawn_game(commands, level.into(), config);
+    next_state.set(GameState::Ready);
+}
+
+/// Restarts the run from Playing when R is held for `QUICK_RESTART_HOLD` seconds or tapped twice
+/// within `QUICK_RESTART_DOUBLE_TAP`; a single tap does nothing.
+#[allow(clippy::too_many_arguments)]
+pub fn quick_restart_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut level: ResMut<CurrentLevel>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    run_query: Query<Entity, RunEntity>,
+    mut held_since: Local<Option<f32>>,
+    mut last_tap: Local<Option<f32>>,
+) {
+    let now = time.elapsed_secs();
+    let mut restart = false;
+
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        restart = last_tap.is_some_and(|tapped| now - tapped <= QUICK_RESTART_DOUBLE_TAP);
+        *held_since = Some(now);
+        *last_tap = Some(now);
+    } else if !keyboard.pressed(KeyCode::KeyR) {
+        *held_since = None;
+    }
+    if held_since.is_some_and(|since| now - since >= QUICK_RESTART_HOLD) {
+        restart = true;
+    }
+
+    if restart {
+        *held_since = None;
+        *last_tap = None;
+        reset_game_state(
+            &mut commands,
+            &mut scoreboard,
+            &mut lives,
+            &mut level,
+            &mut active_powerups,
+            &mut destroyed_bricks,
+            run_query,
+        );
+        next_state.set(GameState::Menu);
+    }
+}
+
+/// Cycles the practice row when P is pressed on the menu and rebuilds the field to match.
+pub fn select_practice_row(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut config: ResMut<GameConfig>,
+    level: Res<CurrentLevel>,
+    run_query: Query<Entity, RunEntity>,
+    demo_query: Query<(), With<AiPaddle>>,
+) {
+    // While the attract-mode demo runs, any key just leaves it
+    if !keyboard.just_pressed(KeyCode::KeyP) || !demo_query.is_empty() {
+        return;
+    }
+
+    config.practice_row = config.next_practice_row();
+    for entity in &run_query {
+        commands.entity(entity).despawn();
+    }
+    crate::setup::spawn_game(commands, level, config.into());
+}
+
+/// Refreshes the menu's practice row line when the setting changes.
+pub fn update_practice_row_text(
+    config: Res<GameConfig>,
+    mut query: Query<&mut Text, With<PracticeRowUi>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = crate::setup::practice_row_text(config.practice_row);
+    }
+}
+
+/// Leaves a practice run for the menu when P is pressed.
+#[allow(clippy::too_many_arguments)]
+pub fn leave_practice(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut level: ResMut<CurrentLevel>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    run_query: Query<Entity, RunEntity>,
+) {
+    if config.practice_row.is_none() || !keyboard.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+    reset_game_state(
+        &mut commands,
+        &mut scoreboard,
+        &mut lives,
+        &mut level,
+        &mut active_powerups,
+        &mut destroyed_bricks,
+        run_query,
+    );
+    next_state.set(GameState::Menu);
+}
+
+/// Drops every ball below the death zone on `BALL_SKIP_KEY`, so `ball_death_zone` handles it as a
+/// lost ball; only in practice or with ball skipping enabled, and never over a walled bottom.
+pub fn skip_ball_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    mut ball_query: Query<(&mut Transform, &ColliderSize), With<Ball>>,
+) {
+    let allowed = config.ball_skip || config.practice_row.is_some();
+    if !allowed || config.bottom_mode == BottomMode::Wall || !keyboard.just_pressed(BALL_SKIP_KEY) {
+        return;
+    }
+    let death_y = PlayfieldBounds::from_config(&config).death_y;
+    for (mut transform, collider) in &mut ball_query {
+        transform.translation.y = death_y - collider.0.y;
+    }
+}
+
+/// Counts down to the next earthquake when they are enabled. A quake shakes the screen, turns
+/// the ball slightly and jostles the bricks for a short burst, keeping them inside the playfield.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn run_earthquakes(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut quake: ResMut<Earthquake>,
+    mut shake: ResMut<ScreenShake>,
+    mut game_rng: ResMut<GameRng>,
+    mut ball_query: Query<&mut Velocity, (With<Ball>, Without<StuckToPaddle>)>,
+    mut brick_query: Query<(&mut Transform, &ColliderSize), (With<Brick>, Without<BossBrick>)>,
+) {
+    if !config.earthquakes {
+        return;
+    }
+
+    quake.timer.tick(time.delta());
+    if quake.timer.just_finished() {
+        quake.burst_remaining = EARTHQUAKE_DURATION;
+        shake.remaining = SCREEN_SHAKE_DURATION;
+        for mut velocity in &mut ball_query {
+            let turn = game_rng
+                .rng
+                .range_f32(-EARTHQUAKE_BALL_NUDGE..=EARTHQUAKE_BALL_NUDGE);
+            velocity.0 = Vec2::from_angle(turn).rotate(velocity.0);
+        }
+        let next = (quake.timer.duration().as_secs_f32() - EARTHQUAKE_INTERVAL_STEP)
+            .max(EARTHQUAKE_INTERVAL_MIN);
+        quake.timer = Timer::from_seconds(next, TimerMode::Once);
+    }
+
+    if quake.burst_remaining <= 0.0 {
+        return;
+    }
+    quake.burst_remaining -= time.delta_secs();
+
+    let bounds = PlayfieldBounds::from_config(&config);
+    let step = EARTHQUAKE_DRIFT_SPEED * time.delta_secs();
+    for (mut transform, collider_size) in &mut brick_query {
+        let half = collider_size.0 / 2.0;
+        let drift = Vec2::new(
+            game_rng.rng.range_f32(-step..=step),
+            game_rng.rng.range_f32(-step..=step),
+        );
+        let position = transform.translation.truncate() + drift;
+        transform.translation.x = position
+            .x
+            .min(bounds.right - half.x)
+            .max(bounds.left + half.x);
+        transform.translation.y = position
+            .y
+            .min(bounds.top - half.y)
+            .max(PADDLE_Y + BRICK_PADDLE_CLEARANCE + half.y);
+    }
+}
+
+/// Restarts the earthquake countdown for a new run.
+pub fn reset_earthquake(mut quake: ResMut<Earthquake>) {
+    *quake = Earthquake::default();
+}
+
+/// Seeds the run's random source as play starts from the menu, reusing the seed after a retry.
+pub fn start_run_rng(mut game_rng: ResMut<GameRng>) {
+    game_rng.start_run();
+}
+
+/// Re-spawns game entities when entering Menu (after a restart).
+pub fn respawn_on_menu_enter(
+    commands: Commands,
+    level: Res<CurrentLevel>,
+    config: Res<GameConfig>,
+    paddle_query: Query<&Paddle>,
+    mut first_run: Local<bool>,
+) {
+    // Skip on first run — entities already spawned by Startup, but commands
+    // haven't been applied yet so the query would be empty.
+    if !*first_run {
+        *first_run = true;
+        return;
+    }
+
+    // Only respawn if there's no paddle (i.e., coming from a restart)
+    if paddle_query.is_empty() {
+        crate::setup::spawn_game(commands, level, config);
+    }
+}
+
+/// Shows the control hints the first time Ready is entered.
+pub fn spawn_tutorial_hint(mut commands: Commands, mut seen_tutorial: ResMut<SeenTutorial>) {
+    if seen_tutorial.seen {
+        return;
+    }
+    seen_tutorial.seen = true;
+
+    commands.spawn((
+        Text::new(
+            "Arrow keys or A/D to move, hold SPACE to aim and release to launch, ESC to pause",
+        ),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(25.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        FadeOut {
+            timer: Timer::from_seconds(TUTORIAL_HINT_DURATION, TimerMode::Once),
+        },
+        TutorialHintUi,
+        UI_Z_OVERLAY,
+        OverlayUi,
+    ));
+}
+
+/// Fades the control hints out over time, dismissing them early on first movement.
+pub fn update_tutorial_hint(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    ui_time: Res<UiTime>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut FadeOut, &mut TextColor), With<TutorialHintUi>>,
+) {
+    let moved = keyboard.any_pressed([
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowRight,
+        KeyCode::KeyA,
+        KeyCode::KeyD,
+    ]);
+
+    for (entity, mut fade, mut color) in &mut query {
+        fade.timer.tick(ui_time.delta);
+        if moved || fade.timer.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            color.0.set_alpha(fade.timer.fraction_remaining());
+        }
+    }
+}
+
+/// Fires a slow hazard shot from each enemy brick whose cooldown has run out, up to `ENEMY_SHOT_LIMIT` in flight.
+pub fn fire_enemy_bricks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut enemy_query: Query<(&Transform, &mut EnemyBrick)>,
+    shot_query: Query<(), With<EnemyShot>>,
+) {
+    let mut shots = shot_query.iter().count();
+    for (transform, mut enemy) in &mut enemy_query {
+        enemy.cooldown.tick(time.delta());
+        if !enemy.cooldown.just_finished() || shots >= ENEMY_SHOT_LIMIT {
+            continue;
+        }
+        shots += 1;
+        commands.spawn((
+            Sprite {
+                color: HAZARD_COLOR,
+                custom_size: Some(Vec2::splat(HAZARD_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(transform.translation.x, transform.translation.y, 0.5),
+            Hazard,
+            EnemyShot,
+            Velocity(Vec2::new(0.0, -ENEMY_SHOT_SPEED)),
+        ));
+    }
+}
+
+/// Counts down timed power-ups and drops the ones that have run out.
+pub fn tick_powerup_timers(time: Res<Time>, mut active: ResMut<ActivePowerUps>) {
+    if active.timers.is_empty() {
+        return;
+    }
+
+    for (_, timer) in &mut active.timers {
+        timer.tick(time.delta());
+    }
+    active.timers.retain(|(_, timer)| !timer.is_finished());
+}
+
+/// Rebuilds the power-up drop table when the difficulty setting changes.
+pub fn sync_powerup_weights(settings: Res<Settings>, mut weights: ResMut<PowerUpWeights>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let wanted = PowerUpWeights::for_difficulty(settings.difficulty);
+    if *weights != wanted {
+        *weights = wanted;
+    }
+}
+
+/// Resizes the ball's collider and sprite to the configured `BallSize`.
+pub fn sync_ball_size(
+    config: Res<GameConfig>,
+    mut ball_query: Query<(&mut ColliderSize, &mut Sprite), With<Ball>>,
+) {
+    let size = Vec2::splat(config.ball_size.diameter());
+    for (mut collider_size, mut sprite) in &mut ball_query {
+        if collider_size.0 != size {
+            collider_size.0 = size;
+            sprite.custom_size = Some(size);
+        }
+    }
+}
+
+/// Rebuilds the bottom wall when the configured `BottomMode` changes mid-game.
+pub fn sync_bottom_mode(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    bottom_query: Query<Entity, With<BottomWall>>,
+    mut applied: Local<Option<BottomMode>>,
+) {
+    let mode = config.bottom_mode;
+    // `spawn_game` builds the first bottom wall; only later changes need a rebuild
+    if applied.is_some_and(|applied| applied != mode) {
+        for entity in &bottom_query {
+            commands.entity(entity).despawn();
+        }
+        crate::setup::spawn_bottom_wall(&mut commands, mode, PlayfieldBounds::from_config(&config));
+    }
+    *applied = Some(mode);
+}
+
+/// Inserts the configured backdrop as the `ClearColor` cameras clear to, and updates it when the
+/// config changes.
+pub fn sync_clear_color(mut commands: Commands, config: Res<GameConfig>) {
+    if config.is_changed() {
+        commands.insert_resource(ClearColor(config.clear_color.0));
+    }
+}
+
+/// Keeps the danger line just above the death zone for the current bounds and shows it when the
+/// setting is on, pulsing it gently unless motion is reduced.
+pub fn sync_danger_line(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    settings: Res<Settings>,
+    mut query: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<DangerLine>>,
+) {
+    let bounds = PlayfieldBounds::from_config(&config);
+    let size = Vec2::new(bounds.size().x, DANGER_LINE_HEIGHT);
+    let visible = if settings.danger_line {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    let pulse = if settings.reduce_motion {
+        1.0
+    } else {
+        0.75 + 0.25 * (time.elapsed_secs() * DANGER_LINE_PULSE_SPEED).sin()
+    };
+
+    for (mut transform, mut sprite, mut visibility) in &mut query {
+        if *visibility != visible {
+            *visibility = visible;
+        }
+        if visible == Visibility::Hidden {
+            continue;
+        }
+        transform.translation.y = bounds.danger_line_y();
+        sprite.custom_size = Some(size);
+        sprite.color = DANGER_LINE_COLOR.with_alpha(DANGER_LINE_COLOR.alpha() * pulse);
+    }
+}
+
+/// Scales virtual time to the game speed setting when it changes.
+pub fn apply_game_speed(settings: Res<Settings>, mut time: ResMut<Time<Virtual>>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let speed = settings.game_speed.clamped();
+    if time.relative_speed() != speed {
+        time.set_relative_speed(speed);
+    }
+}
+
+/// Cycles the frame limit setting when V is pressed on the menu.
+pub fn cycle_frame_limit(keyboard: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    if keyboard.just_pressed(KeyCode::KeyV) {
+        settings.frame_limit = settings.frame_limit.next();
+    }
+}
+
+/// Refreshes the menu's frame limit line when the setting changes.
+pub fn update_frame_limit_text(
+    settings: Res<Settings>,
+    mut query: Query<&mut Text, With<FrameLimitUi>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = crate::setup::frame_limit_text(settings.frame_limit);
+    }
+}
+
+/// Switches the primary window's present mode to match the frame limit setting.
+pub fn apply_frame_limit(
+    settings: Res<Settings>,
+    mut window_query: Query<&mut Window, With<bevy::window::PrimaryWindow>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let present_mode = settings.frame_limit.present_mode();
+    for mut window in &mut window_query {
+        if window.present_mode != present_mode {
+            window.present_mode = present_mode;
+        }
+    }
+}
+
+/// Sleeps at the end of a frame so frames are no shorter than the frame cap allows.
+pub fn limit_frame_rate(
+    settings: Res<Settings>,
+    mut last_frame: Local<Option<bevy::platform::time::Instant>>,
+) {
+    if let (Some(cap), Some(last_frame)) = (settings.frame_limit.frame_cap(), *last_frame) {
+        let target = std::time::Duration::from_secs_f32(1.0 / cap);
+        let elapsed = last_frame.elapsed();
+        if elapsed < target {
+            std::thread::sleep(target - elapsed);
+        }
+    }
+    *last_frame = Some(bevy::platform::time::Instant::now());
+}
+
+/// Widens the paddle sprite and collider while the WiderPaddle power-up is active.
+pub fn apply_powerup_effects(
+    active: Res<ActivePowerUps>,
+    mut paddle_query: Query<(&mut ColliderSize, &mut Sprite), With<Paddle>>,
+) {
+    let Ok((mut collider_size, mut sprite)) = paddle_query.single_mut() else {
+        return;
+    };
+
+    let width = if active.is_active(PowerUpType::WiderPaddle) {
+        PADDLE_WIDTH * WIDER_PADDLE_SCALE
+    } else {
+        PADDLE_WIDTH
+    };
+    if collider_size.0.x != width {
+        collider_size.0.x = width;
+        sprite.custom_size = Some(collider_size.0);
+    }
+}
+
+/// Blinks the paddle while WiderPaddle is about to run out; with reduce motion on it dims
+/// steadily instead.
+pub fn blink_expiring_powerups(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    active: Res<ActivePowerUps>,
+    mut paddle_query: Query<&mut Sprite, With<Paddle>>,
+) {
+    let alpha = if !active.is_expiring(PowerUpType::WiderPaddle) {
+        1.0
+    } else if settings.reduce_motion || (time.elapsed_secs() * POWERUP_BLINK_RATE) as u32 % 2 == 1 {
+        POWERUP_BLINK_ALPHA
+    } else {
+        1.0
+    };
+    for mut sprite in &mut paddle_query {
+        if sprite.color.alpha() != alpha {
+            sprite.color.set_alpha(alpha);
+        }
+    }
+}
+
+/// Fades a hit brick from white back to its color, then drops the flash.
+pub fn fade_hit_flashes(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut HitFlash, &mut Sprite)>,
+) {
+    for (entity, mut flash, mut sprite) in &mut query {
+        flash.timer.tick(time.delta());
+        if flash.timer.is_finished() {
+            sprite.color = flash.color;
+            commands.entity(entity).remove::<HitFlash>();
+        } else {
+            sprite.color = Color::WHITE.mix(&flash.color, flash.timer.fraction());
+        }
+    }
+}
+
+/// Hides the bricks as a level starts and queues their entrance, a row at a time from the top;
+/// with reduced motion they stay in place.
+pub fn start_brick_spawn_anim(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut query: Query<(Entity, &mut Transform), With<Brick>>,
+) {
+    if settings.reduce_motion {
+        return;
+    }
+
+    let mut rows: Vec<f32> = query.iter().map(|(_, t)| t.translation.y).collect();
+    rows.sort_by(|a, b| b.total_cmp(a));
+    rows.dedup_by(|a, b| (*a - *b).abs() < 1.0);
+    for (entity, mut transform) in &mut query {
+        let row = rows
+            .iter()
+            .take_while(|&&y| y > transform.translation.y + 1.0)
+            .count();
+        transform.scale = Vec3::ZERO;
+        commands
+            .entity(entity)
+            .remove::<Collider>()
+            .insert(SpawnAnim {
+                delay: row as f32 * BRICK_SPAWN_ROW_DELAY,
+                timer: Timer::from_seconds(BRICK_SPAWN_DURATION, TimerMode::Once),
+            });
+    }
+}
+
+/// Scales and fades bricks into place, making each collidable once its entrance ends.
+pub fn animate_brick_spawns(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SpawnAnim, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut anim, mut transform, mut sprite) in &mut query {
+        if anim.delay > 0.0 {
+            anim.delay -= time.delta_secs();
+            continue;
+        }
+        anim.timer.tick(time.delta());
+        let progress = anim.timer.fraction();
+        transform.scale = Vec3::splat(progress);
+        sprite.color.set_alpha(progress);
+        if anim.timer.is_finished() {
+            commands
+                .entity(entity)
+                .remove::<SpawnAnim>()
+                .insert(Collider);
+        }
+    }
+}
+
+/// Pulses each brick's lightness, staggered by position; the offset applied last frame is
+/// undone first so damage tinting is preserved.
+pub fn shimmer_bricks(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut applied: Local<std::collections::HashMap<Entity, f32>>,
+    mut query: Query<(Entity, &Transform, &mut Sprite, Has<HitFlash>), With<Brick>>,
+) {
+    // Rebuilt every frame so despawned bricks drop out
+    let mut next_applied = std::collections::HashMap::new();
+    for (entity, transform, mut sprite, flashing) in &mut query {
+        let previous = applied.get(&entity).copied().unwrap_or(0.0);
+        // A flash owns the color until it ends; its settled color still carries this offset
+        if flashing {
+            next_applied.insert(entity, previous);
+            continue;
+        }
+        let offset = if settings.reduce_motion {
+            0.0
+        } else {
+            let phase = transform
+                .translation
+                .truncate()
+                .dot(BRICK_SHIMMER_PHASE_SCALE);
+            BRICK_SHIMMER_AMOUNT * (time.elapsed_secs() * BRICK_SHIMMER_SPEED + phase).sin()
+        };
+        if offset != previous {
+            sprite.color = sprite.color.darker(previous).lighter(offset);
+        }
+        if offset != 0.0 {
+            next_applied.insert(entity, offset);
+        }
+    }
+    *applied = next_applied;
+}
+
+/// Eases a squashed paddle's sprite scale back to 1.0, then drops the squash.
+pub fn animate_paddle_squash(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Transform, &mut PaddleSquash)>,
+) {
+    for (entity, mut transform, mut squash) in &mut query {
+        squash.timer.tick(time.delta());
+
+        let amount = if settings.reduce_motion || squash.timer.is_finished() {
+            0.0
+        } else {
+            PADDLE_SQUASH_AMOUNT * (1.0 - squash.timer.fraction())
+        };
+        transform.scale = Vec3::new(1.0 + amount, 1.0 - amount, 1.0);
+
+        if squash.timer.is_finished() {
+            commands.entity(entity).remove::<PaddleSquash>();
+        }
+    }
+}
+
+/// Restarts the combo timer on each broken brick and drops the combo once it runs out.
+pub fn tick_combo_timer(
+    time: Res<Time>,
+    mut combo: ResMut<Combo>,
+    mut timer: ResMut<ComboTimer>,
+    mut brick_hits: MessageReader<BrickHitEvent>,
+) {
+    if brick_hits.read().any(|hit| hit.destroyed) {
+        timer.0.reset();
+        return;
+    }
+
+    timer.0.tick(time.delta());
+    if timer.0.is_finished() && combo.streak != 0 {
+        combo.streak = 0;
+    }
+}
+
+/// Spawns a "Nx COMBO!" popup at the last broken brick each time a streak grows past 1x,
+/// colored like that brick.
+pub fn spawn_combo_popup(
+    mut commands: Commands,
+    combo: Res<Combo>,
+    settings: Res<Settings>,
+    mut brick_hits: MessageReader<BrickHitEvent>,
+) {
+    let broken_color = brick_hits
+        .read()
+        .filter(|hit| hit.destroyed)
+        .last()
+        .map(|hit| hit.color);
+    if settings.reduce_motion || !combo.is_changed() || combo.streak < 2 {
+        return;
+    }
+
+    commands.spawn((
+        Text2d::new(format!("{}x COMBO! +{}", combo.streak, combo.last_points)),
+        TextFont {
+            font_size: COMBO_POPUP_FONT_SIZE,
+            ..default()
+        },
+        TextColor(broken_color.unwrap_or(COMBO_POPUP_COLOR)),
+        Transform::from_translation(combo.last_position.extend(5.0)),
+        FadeOut {
+            timer: Timer::from_seconds(COMBO_POPUP_DURATION, TimerMode::Once),
+        },
+        ComboPopup,
+    ));
+}
+
+/// Grows and fades combo popups, despawning them when their timer runs out.
+pub fn update_combo_popups(
+    ui_time: Res<UiTime>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut FadeOut, &mut TextColor, &mut Transform), With<ComboPopup>>,
+) {
+    for (entity, mut fade, mut color, mut transform) in &mut query {
+        fade.timer.tick(ui_time.delta);
+        if fade.timer.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            color.0.set_alpha(fade.timer.fraction_remaining());
+            transform.scale = Vec3::splat(1.0 + COMBO_POPUP_GROWTH * fade.timer.fraction());
+        }
+    }
+}
+
+/// Toggles pause when ESC is pressed during gameplay.
+pub fn pause_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        match state.get() {
+            GameState::Ready | GameState::Playing => next_state.set(GameState::Paused),
+            GameState::Paused => next_state.set(GameState::Playing),
+            _ => {}
+        }
+    }
+}
+
+/// Pauses play when a controller disconnects so the frozen paddle can't cost a life.
+pub fn pause_on_controller_disconnect(
+    mut connections: MessageReader<GamepadConnectionEvent>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut menu_state: ResMut<PauseMenuState>,
+) {
+    for connection in connections.read() {
+        menu_state.controller_disconnected = connection.disconnected();
+        if connection.disconnected() && matches!(state.get(), GameState::Ready | GameState::Playing)
+        {
+            next_state.set(GameState::Paused);
+        }
+    }
+}
+
+/// Returns the pause menu's controller line for the current connection state.
+fn controller_note_text(disconnected: bool) -> &'static str {
+    if disconnected {
+        "Controller disconnected"
+    } else {
+        "Controller reconnected"
+    }
+}
+
+/// Refreshes the pause menu's controller line when a controller connects or disconnects.
+pub fn update_controller_note(
+    menu_state: Res<PauseMenuState>,
+    mut query: Query<&mut Text, With<ControllerNoteUi>>,
+) {
+    if !menu_state.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = controller_note_text(menu_state.controller_disconnected).to_string();
+    }
+}
+
+/// Spawns the pause menu with Resume and Quit buttons.
+pub fn spawn_pause_overlay(mut commands: Commands, mut menu_state: ResMut<PauseMenuState>) {
+    // Reset menu selection to Resume
+    menu_state.selected = 0;
+
+    // Semi-transparent full-screen background
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        UI_Z_PAUSE_DIM,
+        OverlayUi,
+    ));
+
+    // Menu container (centered, in front of background)
+    commands
+        .spawn((
+            Node {
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.0),
+                ..crate::setup::overlay_node()
+            },
+            UI_Z_PAUSE_MENU,
+            OverlayUi,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("PAUSED"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            if menu_state.controller_disconnected {
+                parent.spawn((
+                    Text::new(controller_note_text(true)),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.6, 0.3)),
+                    ControllerNoteUi,
+                ));
+            }
+
+            // Spacer
+            parent.spawn(Node {
+                height: Val::Px(30.0),
+                ..default()
+            });
+
+            // Resume button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_HOVERED), // Selected by default
+                    ResumeButton,
+                ))
+                .with_child((
+                    Text::new("Resume"),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+
+            // Quit button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_NORMAL),
+                    QuitButton,
+                ))
+                .with_child((
+                    Text::new("Quit"),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+        });
+}
+
+/// Handles mouse interaction with pause menu buttons (selection and activation only).
+#[allow(clippy::type_complexity)]
+pub fn pause_menu_mouse_interaction(
+    interaction_query: Query<
+        (&Interaction, Option<&ResumeButton>, Option<&QuitButton>),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit: MessageWriter<AppExit>,
+    mut menu_state: ResMut<PauseMenuState>,
+) {
+    for (interaction, is_resume, is_quit) in &interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if is_resume.is_some() {
+                    next_state.set(GameState::Playing);
+                } else if is_quit.is_some() {
+                    app_exit.write(AppExit::Success);
+                }
+            }
+            Interaction::Hovered => {
+                // Update keyboard selection to match hovered button
+                if is_resume.is_some() {
+                    menu_state.selected = 0;
+                } else if is_quit.is_some() {
+                    menu_state.selected = 1;
+                }
+            }
+            Interaction::None => {}
+        }
+    }
+}
+
+/// Handles keyboard navigation in the pause menu; holding a direction repeats after a delay.
+pub fn pause_menu_keyboard_navigation(
+    time: Res<Time<Real>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut menu_state: ResMut<PauseMenuState>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit: MessageWriter<AppExit>,
+    mut repeat_up: Local<KeyRepeat>,
+    mut repeat_down: Local<KeyRepeat>,
+) {
+    // Navigate up/down
+    let delta = time.delta_secs();
+    let up = [KeyCode::ArrowUp, KeyCode::KeyW];
+    let down = [KeyCode::ArrowDown, KeyCode::KeyS];
+    let up_steps = repeat_up.steps(
+        keyboard.any_just_pressed(up),
+        keyboard.any_pressed(up),
+        delta,
+    );
+    let down_steps = repeat_down.steps(
+        keyboard.any_just_pressed(down),
+        keyboard.any_pressed(down),
+        delta,
+    );
+    menu_state.selected = menu_state.selected.saturating_sub(up_steps);
+    menu_state.selected = (menu_state.selected + down_steps).min(PAUSE_MENU_ITEMS - 1);
+
+    // Activate selected button
+    if keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space) {
+        match menu_state.selected {
+            0 => next_state.set(GameState::Playing), // Resume
+            1 => {
+                app_exit.write(AppExit::Success); // Quit
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns a pause button's color: pressed, else highlighted when hovered or keyboard-selected.
+pub fn pause_button_color(interaction: Interaction, selected: bool) -> Color {
+    match interaction {
+        Interaction::Pressed => BUTTON_PRESSED,
+        Interaction::Hovered => BUTTON_HOVERED,
+        Interaction::None if selected => BUTTON_HOVERED,
+        Interaction::None => BUTTON_NORMAL,
+    }
+}
+
+/// Recomputes button colors every frame from both mouse interaction and keyboard selection.
+pub fn update_pause_menu_visuals(
+    menu_state: Res<PauseMenuState>,
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, Option<&QuitButton>),
+        With<Button>,
+    >,
+) {
+    for (interaction, mut bg_color, is_quit) in &mut button_query {
+        let index = if is_quit.is_some() { 1 } else { 0 };
+        let color = pause_button_color(*interaction, menu_state.selected == index);
+        if bg_color.0 != color {
+            *bg_color = color.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::gamepad::GamepadConnection;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin));
+        app.init_state::<GameState>();
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Lives>();
+        app.init_resource::<TimeAttack>();
+        app.init_resource::<Settings>();
+        app.init_resource::<GameRng>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<UiTime>();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<DestroyedBricks>();
+        app.init_resource::<Leaderboard>();
+        app.init_resource::<InitialsEntry>();
+        app.init_resource::<CurrentLevel>();
+        app.add_systems(First, tick_ui_time.after(bevy::time::TimeSystems));
+        app
+    }
+
+    // --- check_game_over ---
+
+    #[test]
+    fn game_over_when_no_lives() {
+        let mut app = test_app();
+        app.add_systems(Update, check_game_over);
+        app.world_mut().resource_mut::<Lives>().count = 0;
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&OverlayUi>();
+        let overlay_count = q.iter(app.world()).count();
+        assert_eq!(overlay_count, 1, "Should spawn a game-over overlay");
+    }
+
+    #[test]
+    fn game_over_overlay_wraps_within_side_margins() {
+        let mut app = test_app();
+        app.add_systems(Update, check_game_over);
+        app.world_mut().resource_mut::<Lives>().count = 0;
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Node, With<OverlayUi>>();
+        let node = q.single(app.world()).unwrap();
+        assert_eq!(node.max_width, Val::Px(OVERLAY_MAX_WIDTH));
+        assert!(node.padding.left != Val::Px(0.0) && node.padding.right != Val::Px(0.0));
+    }
+
+    #[test]
+    fn no_game_over_with_lives_remaining() {
+        let mut app = test_app();
+        app.add_systems(Update, check_game_over);
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&OverlayUi>();
+        let overlay_count = q.iter(app.world()).count();
+        assert_eq!(overlay_count, 0, "Should not spawn overlay when lives > 0");
+    }
+
+    // --- check_victory ---
+
+    #[test]
+    fn victory_when_all_bricks_destroyed() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+        // The only brick has been broken
+        app.world_mut().resource_mut::<DestroyedBricks>().bricks =
+            vec![Rect::new(0.0, 0.0, 1.0, 1.0)];
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&OverlayUi>();
+        let overlay_count = q.iter(app.world()).count();
+        assert_eq!(overlay_count, 1, "Should spawn a victory overlay");
+    }
+
+    #[test]
+    fn empty_layout_does_not_win_on_the_first_playing_frame() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory.run_if(in_state(GameState::Playing)));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::Playing
+        );
+        let mut q = app.world_mut().query::<&OverlayUi>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn no_victory_with_bricks_remaining() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+
+        // Spawn a brick
+        app.world_mut()
+            .spawn((Transform::from_xyz(0.0, 100.0, 0.0), Brick));
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&OverlayUi>();
+        let overlay_count = q.iter(app.world()).count();
+        assert_eq!(
+            overlay_count, 0,
+            "Should not spawn overlay when bricks remain"
+        );
+    }
+
+    #[test]
+    fn victory_waits_for_boss_health_to_reach_zero() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+        let boss = app
+            .world_mut()
+            .spawn((Brick, BossBrick, BrickHealth { hits: 1 }))
+            .id();
+        let other = app.world_mut().spawn(Brick).id();
+
+        app.update();
+        assert_eq!(*app.world().resource::<State<GameState>>(), GameState::Menu);
+
+        app.world_mut().get_mut::<BrickHealth>(boss).unwrap().hits = 0;
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::Victory
+        );
+        assert!(
+            app.world().get_entity(other).is_err(),
+            "Leftover bricks clear"
+        );
+    }
+
+    /// Runs `check_victory` under `victory` with `destroyed` of ten bricks cleared and returns
+    /// whether the level was won.
+    fn won_with(victory: VictoryCondition, destroyed: usize) -> bool {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+        app.insert_resource(GameConfig {
+            victory,
+            ..default()
+        });
+        app.world_mut().resource_mut::<DestroyedBricks>().bricks =
+            vec![Rect::new(0.0, 0.0, 1.0, 1.0); destroyed];
+        for _ in destroyed..10 {
+            app.world_mut().spawn(Brick);
+        }
+
+        app.update();
+        app.update();
+        *app.world().resource::<State<GameState>>() == GameState::Victory
+    }
+
+    #[test]
+    fn count_victory_fires_at_the_threshold() {
+        assert!(!won_with(VictoryCondition::Count(4), 3));
+        assert!(won_with(VictoryCondition::Count(4), 4));
+        assert!(!won_with(VictoryCondition::AllBricks, 9));
+    }
+
+    #[test]
+    fn percent_victory_fires_at_the_cleared_fraction() {
+        assert!(!won_with(VictoryCondition::Percent(75), 7));
+        assert!(won_with(VictoryCondition::Percent(75), 8));
+        assert!(won_with(VictoryCondition::Percent(70), 7));
+    }
+
+    #[test]
+    fn partial_victory_clears_the_leftover_bricks() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+        app.world_mut().resource_mut::<GameConfig>().victory = VictoryCondition::Count(0);
+        app.world_mut().spawn(Brick);
+
+        app.update();
+
+        let mut bricks = app.world_mut().query_filtered::<(), With<Brick>>();
+        assert_eq!(bricks.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn victory_in_time_attack_awards_time_bonus() {
+        let mut app = test_app();
+        app.add_systems(Update, check_victory);
+        app.insert_resource(TimeAttack {
+            active: true,
+            remaining: 10.0,
+        });
+        app.world_mut().resource_mut::<DestroyedBricks>().bricks =
+            vec![Rect::new(0.0, 0.0, 1.0, 1.0)];
+
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Scoreboard>().score,
+            10 * TIME_ATTACK_BONUS_PER_SECOND
+        );
+    }
+
+    // --- results screen ---
+
+    #[test]
+    fn results_screen_shows_stats() {
+        let mut app = test_app();
+        app.init_resource::<HighScore>();
+        app.insert_resource(GameStats {
+            score: 420,
+            bricks_destroyed: 37,
+            max_combo: 6,
+            balls_lost: 2,
+            playtime: 75.0,
+            cleared: false,
+        });
+        app.insert_resource(GameRng::new(1405));
+        app.add_systems(Update, spawn_results_screen);
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Text, With<ResultsUi>>();
+        let text = q.single(app.world()).unwrap().0.clone();
+        assert!(text.contains("Score: 420"), "got {text}");
+        assert!(text.contains("Bricks destroyed: 37"), "got {text}");
+        assert!(text.contains("Bricks per ball: 18.5"), "got {text}");
+        assert!(text.contains("Time: 1:15"), "got {text}");
+        assert!(text.contains("Seed: 1405"), "got {text}");
+    }
+
+    #[test]
+    fn results_prompt_waits_for_tally() {
+        let mut app = test_app();
+        app.init_resource::<GameStats>();
+        app.init_resource::<HighScore>();
+        app.add_systems(Update, update_results_screen);
+        let prompt = app
+            .world_mut()
+            .spawn((Visibility::Hidden, ResultsPromptUi))
+            .id();
+        let tally = app
+            .world_mut()
+            .spawn(ScoreTally {
+                from: 0,
+                to: 100,
+                timer: Timer::from_seconds(SCORE_TALLY_DURATION, TimerMode::Once),
+            })
+            .id();
+
+        app.update();
+        assert_eq!(
+            *app.world().get::<Visibility>(prompt).unwrap(),
+            Visibility::Hidden
+        );
+
+        app.world_mut().entity_mut(tally).remove::<ScoreTally>();
+        app.update();
+        assert_eq!(
+            *app.world().get::<Visibility>(prompt).unwrap(),
+            Visibility::Inherited
+        );
+    }
+
+    // --- update_score_tally ---
+
+    fn tally_test_app(total: u32) -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(Update, update_score_tally);
+        let overlay = app
+            .world_mut()
+            .spawn((
+                Text::new(victory_text(0, &ScoreFormat::default())),
+                ScoreTally {
+                    from: 0,
+                    to: total,
+                    timer: Timer::from_seconds(SCORE_TALLY_DURATION, TimerMode::Once),
+                },
+            ))
+            .id();
+        (app, overlay)
+    }
+
+    #[test]
+    fn tally_counts_up_to_total_after_duration() {
+        let (mut app, overlay) = tally_test_app(1234);
+
+        app.update();
+        app.update();
+        let text = app.world().get::<Text>(overlay).unwrap().0.clone();
+        assert!(
+            text != victory_text(1234, &ScoreFormat::default()),
+            "Tally should still be counting, got {text}"
+        );
+
+        for _ in 0..(SCORE_TALLY_DURATION * 10.0) as usize {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get::<Text>(overlay).unwrap().0,
+            victory_text(1234, &ScoreFormat::default())
+        );
+        assert!(app.world().get::<ScoreTally>(overlay).is_none());
+    }
+
+    #[test]
+    fn key_press_skips_tally_to_total() {
+        let (mut app, overlay) = tally_test_app(1234);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Text>(overlay).unwrap().0,
+            victory_text(1234, &ScoreFormat::default())
+        );
+        assert!(app.world().get::<ScoreTally>(overlay).is_none());
+    }
+
+    #[test]
+    fn skipping_tally_does_not_restart() {
+        let (mut app, _) = tally_test_app(1234);
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<DestroyedBricks>();
+        app.add_systems(Update, restart_input.before(update_score_tally));
+        app.world_mut().resource_mut::<Scoreboard>().score = 1234;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+
+        assert_eq!(app.world().resource::<Scoreboard>().score, 1234);
+    }
+
+    // --- continue_after_victory ---
+
+    /// Presses `key` on the Victory screen of a run scored at 500 with two lives left and returns
+    /// the app once the choice has been handled.
+    fn victory_choice(key: KeyCode) -> App {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<DestroyedBricks>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<GameConfig>();
+        app.add_systems(
+            Update,
+            (restart_input, continue_after_victory).run_if(in_state(GameState::Victory)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Victory);
+        app.update();
+        app.world_mut().resource_mut::<Scoreboard>().score = 500;
+        app.world_mut().resource_mut::<Lives>().count = 2;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+        app.update();
+        app
+    }
+
+    #[test]
+    fn continue_after_victory_respawns_bricks_and_keeps_score() {
+        let mut app = victory_choice(KeyCode::KeyC);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::Ready
+        );
+        assert_eq!(app.world().resource::<Scoreboard>().score, 500);
+        assert_eq!(app.world().resource::<Lives>().count, 2);
+        let mut bricks = app.world_mut().query_filtered::<(), With<Brick>>();
+        assert_eq!(bricks.iter(app.world()).count(), 50);
+        let mut paddles = app.world_mut().query_filtered::<(), With<Paddle>>();
+        assert_eq!(paddles.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn continue_after_victory_spawns_the_next_level_tougher() {
+        let mut app = victory_choice(KeyCode::KeyC);
+        assert_eq!(app.world().resource::<CurrentLevel>().number, 2);
+
+        // Level 2 only toughens the upper half of the grid
+        let rows = app.world().resource::<GameConfig>().bricks.rows;
+        let mut q = app.world_mut().query::<(&BrickHealth, &Transform)>();
+        let top_y = q
+            .iter(app.world())
+            .map(|(_, transform)| transform.translation.y)
+            .fold(f32::MIN, f32::max);
+        let top_row_hits: Vec<u32> = q
+            .iter(app.world())
+            .filter(|(_, transform)| transform.translation.y == top_y)
+            .map(|(health, _)| health.hits)
+            .collect();
+        assert!(!top_row_hits.is_empty());
+        assert!(
+            top_row_hits
+                .iter()
+                .all(|&hits| hits == brick_health(2, 0, rows) && hits > brick_health(1, 0, rows)),
+            "The next board's top row should take more hits than on level 1"
+        );
+    }
+
+    #[test]
+    fn second_victory_tallies_up_from_the_previous_total() {
+        let mut app = victory_choice(KeyCode::KeyC);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .reset_all();
+        app.add_systems(Update, check_victory.run_if(in_state(GameState::Playing)));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+
+        // Clear the second board for another 200 points
+        app.world_mut().resource_mut::<Scoreboard>().score += 200;
+        let mut bricks = app.world_mut().query_filtered::<Entity, With<Brick>>();
+        let bricks: Vec<Entity> = bricks.iter(app.world()).collect();
+        for brick in bricks {
+            app.world_mut().despawn(brick);
+        }
+        app.world_mut().resource_mut::<DestroyedBricks>().bricks =
+            vec![Rect::new(0.0, 0.0, 1.0, 1.0)];
+        app.update();
+
+        let mut q = app.world_mut().query::<&ScoreTally>();
+        let tally = q.single(app.world()).unwrap();
+        assert_eq!((tally.from, tally.to), (500, 700));
+    }
+
+    #[test]
+    fn restart_after_continuing_starts_back_at_level_one() {
+        let mut app = victory_choice(KeyCode::KeyC);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .reset_all();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Victory);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        assert_eq!(app.world().resource::<CurrentLevel>().number, 1);
+    }
+
+    #[test]
+    fn continue_after_victory_ends_time_attack() {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<CurrentLevel>();
+        app.insert_resource(TimeAttack {
+            active: true,
+            remaining: 42.0,
+        });
+        app.add_systems(
+            Update,
+            continue_after_victory.run_if(in_state(GameState::Victory)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Victory);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.update();
+
+        let time_attack = app.world().resource::<TimeAttack>();
+        assert!(
+            !time_attack.active,
+            "The next clear must not pay the bonus again"
+        );
+        assert_eq!(time_attack.remaining, 0.0);
+    }
+
+    #[test]
+    fn restart_with_a_qualifying_score_asks_for_initials() {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        app.add_systems(Update, restart_input.run_if(in_state(GameState::GameOver)));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        app.world_mut().resource_mut::<Scoreboard>().score = 640;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::EnterInitials
+        );
+        assert_eq!(app.world().resource::<InitialsEntry>().score, 640);
+        assert_eq!(app.world().resource::<Scoreboard>().score, 0);
+    }
+
+    /// Presses `key` on the GameOver screen of a scoreless run and returns the state after.
+    fn game_over_key(key: KeyCode) -> GameState {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        app.add_systems(Update, restart_input.run_if(in_state(GameState::GameOver)));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+        app.update();
+        *app.world().resource::<State<GameState>>().get()
+    }
+
+    #[test]
+    fn space_and_enter_both_restart_from_game_over() {
+        assert_eq!(game_over_key(KeyCode::Space), GameState::Menu);
+        assert_eq!(game_over_key(KeyCode::Enter), GameState::Menu);
+        assert_eq!(game_over_key(KeyCode::KeyX), GameState::GameOver);
+    }
+
+    #[test]
+    fn restart_after_victory_resets_score() {
+        let mut app = victory_choice(KeyCode::Space);
+        // 500 makes the empty leaderboard, so initials come before the menu
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::EnterInitials
+        );
+        assert_eq!(app.world().resource::<Scoreboard>().score, 0);
+        assert_eq!(app.world().resource::<Lives>().count, 3);
+        let mut bricks = app.world_mut().query_filtered::<(), With<Brick>>();
+        assert_eq!(bricks.iter(app.world()).count(), 0);
+    }
+
+    // --- skip_ball_input ---
+
+    /// Presses the skip key mid-play under `config` and returns the lives left and whether the
+    /// ball ended up held on the paddle.
+    fn skip_ball(config: GameConfig) -> (u32, bool) {
+        let mut app = test_app();
+        app.insert_resource(config);
+        app.init_resource::<Serve>();
+        app.init_resource::<Combo>();
+        app.add_systems(
+            Update,
+            (skip_ball_input, crate::collision::ball_death_zone).chain(),
+        );
+        let ball = crate::setup::spawn_ball(&mut app.world_mut().commands(), BALL_SIZE, 0.0);
+        app.world_mut().flush();
+        app.world_mut()
+            .get_mut::<Transform>(ball)
+            .unwrap()
+            .translation
+            .y = 0.0;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(BALL_SKIP_KEY);
+        app.update();
+
+        let stuck = app.world().get::<StuckToPaddle>(ball).is_some();
+        (app.world().resource::<Lives>().count, stuck)
+    }
+
+    #[test]
+    fn ball_skip_costs_a_life_in_normal_play() {
+        let (lives, stuck) = skip_ball(GameConfig {
+            ball_skip: true,
+            ..default()
+        });
+        assert_eq!(lives, 2);
+        assert!(stuck, "Ball should be re-served");
+    }
+
+    #[test]
+    fn ball_skip_reserves_for_free_in_practice() {
+        let (lives, stuck) = skip_ball(GameConfig {
+            practice_row: Some(0),
+            ..default()
+        });
+        assert_eq!(lives, 3);
+        assert!(stuck, "Ball should be re-served");
+    }
+
+    #[test]
+    fn ball_skip_is_off_in_normal_play_by_default() {
+        let (lives, stuck) = skip_ball(GameConfig::default());
+        assert_eq!(lives, 3);
+        assert!(!stuck);
+    }
+
+    // --- start_run_rng ---
+
+    /// Plays a run from the menu, restarts it with `key` and returns the seed and first drop
+    /// rolls of both runs.
+    fn seeds_across_restart(key: KeyCode) -> [(u64, Vec<f32>); 2] {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<DestroyedBricks>();
+        app.add_systems(
+            OnTransition {
+                exited: GameState::Menu,
+                entered: GameState::Ready,
+            },
+            start_run_rng,
+        );
+        app.add_systems(Update, restart_input.run_if(in_state(GameState::GameOver)));
+        app.update();
+
+        let play_run = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::Ready);
+            app.update();
+            let mut game_rng = app.world_mut().resource_mut::<GameRng>();
+            let rolls = (0..5).map(|_| game_rng.rng.f32()).collect();
+            (game_rng.seed, rolls)
+        };
+
+        let first = play_run(&mut app);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::GameOver);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+        app.update();
+        assert_eq!(*app.world().resource::<State<GameState>>(), GameState::Menu);
+
+        let second = play_run(&mut app);
+        [first, second]
+    }
+
+    #[test]
+    fn retry_same_seed_replays_the_drop_sequence() {
+        let [first, second] = seeds_across_restart(KeyCode::KeyR);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn normal_restart_rolls_a_fresh_seed() {
+        let [first, second] = seeds_across_restart(KeyCode::Space);
+        assert_ne!(first.0, second.0);
+        assert_ne!(first.1, second.1);
+    }
+
+    // --- quick_restart_input ---
+
+    fn quick_restart_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<DestroyedBricks>();
+        app.add_systems(
+            Update,
+            quick_restart_input.run_if(in_state(GameState::Playing)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.world_mut().resource_mut::<Scoreboard>().score = 500;
+        app.world_mut().resource_mut::<Lives>().count = 1;
+        let brick = app.world_mut().spawn(Brick).id();
+        (app, brick)
+    }
+
+    fn tap_restart_key(app: &mut App) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyR);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::KeyR);
+        keyboard.clear();
+    }
+
+    fn assert_restarted(app: &mut App, brick: Entity) {
+        assert_eq!(app.world().resource::<Scoreboard>().score, 0);
+        assert_eq!(app.world().resource::<Lives>().count, 3);
+        assert!(app.world().get_entity(brick).is_err());
+        app.update();
+        assert_eq!(*app.world().resource::<State<GameState>>(), GameState::Menu);
+    }
+
+    #[test]
+    fn double_tapping_r_restarts_the_run() {
+        let (mut app, brick) = quick_restart_app();
+
+        tap_restart_key(&mut app);
+        tap_restart_key(&mut app);
+
+        assert_restarted(&mut app, brick);
+    }
+
+    #[test]
+    fn holding_r_restarts_the_run() {
+        let (mut app, brick) = quick_restart_app();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyR);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear_just_pressed(KeyCode::KeyR);
+
+        for _ in 0..=(QUICK_RESTART_HOLD * 10.0) as usize {
+            app.update();
+        }
+
+        assert_restarted(&mut app, brick);
+    }
+
+    #[test]
+    fn single_tap_of_r_does_not_restart() {
+        let (mut app, brick) = quick_restart_app();
+
+        tap_restart_key(&mut app);
+        for _ in 0..10 {
+            app.update();
+        }
+        tap_restart_key(&mut app);
+        app.update();
+
+        assert_eq!(app.world().resource::<Scoreboard>().score, 500);
+        assert_eq!(app.world().resource::<Lives>().count, 1);
+        assert!(app.world().get_entity(brick).is_ok());
+        assert_eq!(
+            *app.world().resource::<State<GameState>>(),
+            GameState::Playing
+        );
+    }
+
+    // --- tick_time_attack ---
+
+    fn time_attack_test_app() -> App {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.insert_resource(TimeAttack {
+            active: true,
+            remaining: TIME_ATTACK_DURATION,
+        });
+        app.add_systems(
+            Update,
+            tick_time_attack.run_if(in_state(GameState::Playing)),
+        );
+        app
+    }
+
+    #[test]
+    fn time_attack_only_ticks_while_playing() {
+        let mut app = time_attack_test_app();
+
+        // Menu (default state): timer must not move
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<TimeAttack>().remaining,
+            TIME_ATTACK_DURATION
+        );
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.update();
+
+        let remaining = app.world().resource::<TimeAttack>().remaining;
+        assert!(
+            remaining < TIME_ATTACK_DURATION,
+            "Timer should tick in Playing"
+        );
+
+        // Paused: timer must hold
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        app.update();
+        let paused_at = app.world().resource::<TimeAttack>().remaining;
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<TimeAttack>().remaining, paused_at);
+    }
+
+    #[test]
+    fn time_attack_running_out_triggers_game_over() {
+        let mut app = time_attack_test_app();
+        app.world_mut().resource_mut::<TimeAttack>().remaining = 0.05;
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+
+        app.update();
+        app.update(); // Apply state transition
+
+        let state = app.world().resource::<State<GameState>>();
+        assert_eq!(*state.get(), GameState::GameOver);
+        assert_eq!(app.world().resource::<TimeAttack>().remaining, 0.0);
+    }
+
+    // --- fire_enemy_bricks ---
+
+    /// Spawns an enemy brick at `position` whose cooldown is `remaining` seconds from firing.
+    fn spawn_enemy_brick(app: &mut App, position: Vec2, remaining: f32) {
+        let mut enemy = EnemyBrick::default();
+        enemy.cooldown.tick(std::time::Duration::from_secs_f32(
+            ENEMY_FIRE_COOLDOWN - remaining,
+        ));
+        app.world_mut().spawn((
+            Transform::from_translation(position.extend(0.0)),
+            Brick,
+            enemy,
+        ));
+    }
+
+    fn count_enemy_shots(app: &mut App) -> usize {
+        let mut q = app.world_mut().query::<(&Hazard, &EnemyShot)>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn enemy_brick_fires_after_cooldown() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(Update, fire_enemy_bricks);
+        spawn_enemy_brick(&mut app, Vec2::new(0.0, 200.0), 0.05);
+
+        app.update();
+        assert_eq!(count_enemy_shots(&mut app), 0, "Cooldown still running");
+
+        app.update();
+        assert_eq!(count_enemy_shots(&mut app), 1);
+    }
+
+    #[test]
+    fn enemy_shots_are_limited() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(Update, fire_enemy_bricks);
+        for i in 0..ENEMY_SHOT_LIMIT + 2 {
+            spawn_enemy_brick(&mut app, Vec2::new(i as f32 * 80.0, 200.0), 0.05);
+        }
+
+        app.update();
+        app.update();
+        assert_eq!(count_enemy_shots(&mut app), ENEMY_SHOT_LIMIT);
+    }
+
+    #[test]
+    fn enemy_shot_costs_a_life_on_paddle_contact() {
+        let mut app = test_app();
+        app.init_resource::<GameConfig>();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(
+            Update,
+            (fire_enemy_bricks, crate::collision::hazard_collision_paddle).chain(),
+        );
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+            Paddle,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
+        spawn_enemy_brick(&mut app, Vec2::new(0.0, PADDLE_Y), 0.05);
+        let lives_before = app.world().resource::<Lives>().count;
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Lives>().count, lives_before - 1);
+        assert_eq!(count_enemy_shots(&mut app), 0, "Shot should be consumed");
+    }
+
+    // --- tick_powerup_timers ---
+
+    fn remaining_wider_paddle(app: &App) -> f32 {
+        let active = app.world().resource::<ActivePowerUps>();
+        active
+            .timers
+            .iter()
+            .find(|(kind, _)| *kind == PowerUpType::WiderPaddle)
+            .map(|(_, timer)| timer.remaining_secs())
+            .unwrap()
+    }
+
+    #[test]
+    fn powerup_timers_hold_while_paused() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<ActivePowerUps>();
+        app.world_mut()
+            .resource_mut::<ActivePowerUps>()
+            .activate(PowerUpType::WiderPaddle);
+        app.add_systems(
+            Update,
+            tick_powerup_timers.run_if(in_state(GameState::Playing)),
+        );
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.update();
+        let playing = remaining_wider_paddle(&app);
+        assert!(playing < POWERUP_DURATION, "Timer should tick in Playing");
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Paused);
+        app.update();
+        let paused_at = remaining_wider_paddle(&app);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(remaining_wider_paddle(&app), paused_at);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        app.update();
+        assert!(remaining_wider_paddle(&app) < paused_at);
+    }
+
+    #[test]
+    fn expired_powerup_is_removed_and_paddle_restored() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<ActivePowerUps>();
+        {
+            let mut active = app.world_mut().resource_mut::<ActivePowerUps>();
+            active.activate(PowerUpType::WiderPaddle);
+            // Leave less than one frame on the clock
+            active.timers[0]
+                .1
+                .set_elapsed(std::time::Duration::from_secs_f32(POWERUP_DURATION - 0.05));
+        }
+        app.add_systems(Update, (tick_powerup_timers, apply_powerup_effects).chain());
+        let paddle = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                Sprite::default(),
+                Paddle,
+                ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            ))
+            .id();
+
+        app.update();
+        let width = app.world().get::<ColliderSize>(paddle).unwrap().0.x;
+        assert_eq!(width, PADDLE_WIDTH * WIDER_PADDLE_SCALE);
+        let sprite_size = app.world().get::<Sprite>(paddle).unwrap().custom_size;
+        assert_eq!(sprite_size, Some(Vec2::new(width, PADDLE_HEIGHT)));
+
+        app.update();
+        assert!(app.world().resource::<ActivePowerUps>().timers.is_empty());
+        let width = app.world().get::<ColliderSize>(paddle).unwrap().0.x;
+        assert_eq!(width, PADDLE_WIDTH);
+    }
+
+    // --- blink_expiring_powerups ---
+
+    #[test]
+    fn paddle_blinks_only_when_powerup_is_nearly_over() {
+        let mut app = test_app();
+        app.init_resource::<ActivePowerUps>();
+        // Reduce motion holds the blink dimmed, so the check doesn't depend on the clock
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+        app.add_systems(Update, blink_expiring_powerups);
+        let paddle = app.world_mut().spawn((Sprite::default(), Paddle)).id();
+        let set_remaining = |app: &mut App, fraction: f32| {
+            let mut active = app.world_mut().resource_mut::<ActivePowerUps>();
+            active.activate(PowerUpType::WiderPaddle);
+            active.timers[0]
+                .1
+                .set_elapsed(std::time::Duration::from_secs_f32(
+                    POWERUP_DURATION * (1.0 - fraction),
+                ));
+        };
+
+        set_remaining(&mut app, 0.5);
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<ActivePowerUps>()
+                .is_expiring(PowerUpType::WiderPaddle)
+        );
+        assert_eq!(
+            app.world().get::<Sprite>(paddle).unwrap().color.alpha(),
+            1.0
+        );
+
+        set_remaining(&mut app, 0.1);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<ActivePowerUps>()
+                .is_expiring(PowerUpType::WiderPaddle)
+        );
+        assert_eq!(
+            app.world().get::<Sprite>(paddle).unwrap().color.alpha(),
+            POWERUP_BLINK_ALPHA
+        );
+
+        app.world_mut()
+            .resource_mut::<ActivePowerUps>()
+            .timers
+            .clear();
+        app.update();
+        assert_eq!(
+            app.world().get::<Sprite>(paddle).unwrap().color.alpha(),
+            1.0
+        );
+    }
+
+    // --- update_score_legend ---
+
+    #[test]
+    fn score_legend_follows_setting() {
+        let mut app = test_app();
+        app.add_systems(Update, update_score_legend);
+        let legend = app
+            .world_mut()
+            .spawn((Visibility::Hidden, ScoreLegendUi))
+            .id();
+
+        app.world_mut().resource_mut::<Settings>().score_legend = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(legend),
+            Some(&Visibility::Inherited)
+        );
+
+        app.world_mut().resource_mut::<Settings>().score_legend = false;
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(legend),
+            Some(&Visibility::Hidden)
+        );
+    }
+
+    #[test]
+    fn score_legend_points_follow_row_scoring() {
+        let mut app = test_app();
+        app.add_systems(Update, update_score_legend);
+        let label = app
+            .world_mut()
+            .spawn((Text::new("0"), ScoreLegendPoints(0)))
+            .id();
+
+        app.update();
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().0,
+            POINTS_PER_BRICK.to_string()
+        );
+
+        app.world_mut().resource_mut::<GameConfig>().row_points = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<Text>(label).unwrap().0,
+            BRICK_ROW_POINTS[0].to_string()
+        );
+    }
+
+    // --- update_boss_health_bar ---
+
+    #[test]
+    fn boss_health_bar_shrinks_with_health() {
+        let mut app = test_app();
+        app.add_systems(Update, update_boss_health_bar);
+        let size = Vec2::new(200.0, 60.0);
+        let boss = app
+            .world_mut()
+            .spawn((
+                BossBrick,
+                BrickHealth {
+                    hits: BOSS_HEALTH / 2,
+                },
+                ColliderSize(size),
+            ))
+            .id();
+        let bar = app
+            .world_mut()
+            .spawn((crate::setup::boss_health_bar(size), ChildOf(boss)))
+            .id();
+
+        app.update();
+
+        let width = app
+            .world()
+            .get::<Sprite>(bar)
+            .unwrap()
+            .custom_size
+            .unwrap()
+            .x;
+        assert_eq!(width, size.x / 2.0);
+        let x = app.world().get::<Transform>(bar).unwrap().translation.x;
+        assert_eq!(
+            x - width / 2.0,
+            -size.x / 2.0,
+            "Bar stays pinned to the left"
+        );
+    }
+
+    // --- update_aim_arrow ---
+
+    #[test]
+    fn aim_arrow_follows_stuck_ball_and_hides_after_launch() {
+        let mut app = test_app();
+        app.insert_resource(AimAngle(0.0));
+        app.add_systems(Update, update_aim_arrow);
+
+        let ball = app
+            .world_mut()
+            .spawn((Transform::from_xyz(50.0, 0.0, 1.0), Ball, StuckToPaddle))
+            .id();
+        let arrow = app
+            .world_mut()
+            .spawn((Transform::default(), Visibility::Hidden, AimArrow))
+            .id();
+
+        app.update();
+        let transform = app.world().get::<Transform>(arrow).unwrap();
+        assert!((transform.translation.x - 50.0).abs() < 0.001);
+        assert!(transform.translation.y > 0.0, "Arrow should point up");
+        assert_eq!(
+            *app.world().get::<Visibility>(arrow).unwrap(),
+            Visibility::Visible
+        );
+
+        app.world_mut().entity_mut(ball).remove::<StuckToPaddle>();
+        app.update();
+        assert_eq!(
+            *app.world().get::<Visibility>(arrow).unwrap(),
+            Visibility::Hidden
+        );
+    }
+
+    // --- sync_catch_indicator ---
+
+    #[test]
+    fn catch_indicator_shows_under_paddle_only_while_ball_is_stuck() {
+        let mut app = test_app();
+        app.add_systems(Update, sync_catch_indicator);
+
+        let paddle = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                Paddle,
+                ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            ))
+            .id();
+        let ball = app
+            .world_mut()
+            .spawn((Transform::default(), Ball, StuckToPaddle))
+            .id();
+        let mut indicators = app
+            .world_mut()
+            .query_filtered::<(&ChildOf, &Transform), With<CatchIndicator>>();
+
+        app.update();
+        app.update();
+        let found: Vec<_> = indicators.iter(app.world()).collect();
+        assert_eq!(found.len(), 1, "Exactly one tray while the ball is stuck");
+        let (parent, transform) = found[0];
+        assert_eq!(parent.parent(), paddle, "Tray should follow the paddle");
+        assert!(transform.translation.y < -PADDLE_HEIGHT / 2.0);
+
+        app.world_mut().entity_mut(ball).remove::<StuckToPaddle>();
+        app.update();
+        assert_eq!(indicators.iter(app.world()).count(), 0);
+    }
+
+    // --- camera zoom ---
+
+    #[test]
+    fn zoom_target_frames_a_brick_cluster_down_to_the_paddle() {
+        let cluster = [Vec2::new(250.0, 200.0), Vec2::new(300.0, 230.0)];
+        let (center, scale) = zoom_target(&cluster);
+
+        assert!(
+            center.x > 0.0,
+            "Centre {center} should lean toward the cluster"
+        );
+        assert!(scale < 1.0);
+        let bottom = center.y - WINDOW_HEIGHT / 2.0 * scale;
+        assert!(
+            bottom <= PADDLE_Y - PADDLE_HEIGHT,
+            "Paddle should stay in view"
+        );
+
+        let many = [Vec2::new(250.0, 200.0); ZOOM_BRICK_THRESHOLD + 1];
+        assert_eq!(zoom_target(&many), (Vec2::ZERO, 1.0));
+        assert_eq!(zoom_target(&[]), (Vec2::ZERO, 1.0));
+    }
+
+    #[test]
+    fn camera_zooms_toward_last_bricks_and_back_when_cleared() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<CameraZoom>();
+        app.add_systems(Update, update_camera_zoom);
+        let camera = app
+            .world_mut()
+            .spawn((
+                Camera2d,
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+            ))
+            .id();
+        let brick = app
+            .world_mut()
+            .spawn((Transform::from_xyz(-300.0, 220.0, 0.0), Brick))
+            .id();
+
+        for _ in 0..20 {
+            app.update();
+        }
+        let zoom = app.world().resource::<CameraZoom>();
+        assert!(
+            zoom.center.x < 0.0,
+            "Centre {} should lean left",
+            zoom.center
+        );
+        assert!(zoom.scale < 1.0);
+        let Projection::Orthographic(ortho) = app.world().get::<Projection>(camera).unwrap() else {
+            panic!("camera should stay orthographic");
+        };
+        assert_eq!(ortho.scale, app.world().resource::<CameraZoom>().scale);
+
+        app.world_mut().despawn(brick);
+        for _ in 0..40 {
+            app.update();
+        }
+        let zoom = app.world().resource::<CameraZoom>();
+        assert!(
+            zoom.center.length() < 1.0,
+            "Centre {} should return to origin",
+            zoom.center
+        );
+        assert!((zoom.scale - 1.0).abs() < 0.01);
+    }
+
+    // --- run_earthquakes ---
+
+    #[test]
+    fn earthquake_jostles_bricks_within_the_playfield() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.insert_resource(GameConfig {
+            earthquakes: true,
+            ..default()
+        });
+        app.init_resource::<Earthquake>();
+        app.init_resource::<ScreenShake>();
+        app.add_systems(Update, run_earthquakes);
+        let bounds = PlayfieldBounds::from_config(&GameConfig::default());
+        let size = Vec2::new(BRICK_WIDTH, BRICK_HEIGHT);
+        let starts = [
+            Vec2::new(bounds.left + size.x / 2.0, bounds.top - size.y / 2.0),
+            Vec2::new(0.0, 100.0),
+            Vec2::new(bounds.right - size.x / 2.0, 50.0),
+        ];
+        for start in starts {
+            app.world_mut().spawn((
+                Transform::from_translation(start.extend(0.0)),
+                Brick,
+                ColliderSize(size),
+            ));
+        }
+        app.world_mut()
+            .resource_mut::<Earthquake>()
+            .timer
+            .set_elapsed(std::time::Duration::from_secs_f32(
+                EARTHQUAKE_INTERVAL - 0.05,
+            ));
+
+        let mut moved = false;
+        for _ in 0..10 {
+            app.update();
+            let mut q = app.world_mut().query_filtered::<&Transform, With<Brick>>();
+            for (transform, start) in q.iter(app.world()).zip(starts) {
+                let position = transform.translation.truncate();
+                moved |= position != start;
+                assert!(position.x - size.x / 2.0 >= bounds.left - 0.01);
+                assert!(position.x + size.x / 2.0 <= bounds.right + 0.01);
+                assert!(position.y + size.y / 2.0 <= bounds.top + 0.01);
             }
         }
+        assert!(moved, "An earthquake should move the bricks");
+        assert!(app.world().resource::<ScreenShake>().remaining > 0.0);
     }
-}
 
-/// Handles keyboard navigation in the pause menu.
-pub fn pause_menu_keyboard_navigation(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut menu_state: ResMut<PauseMenuState>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut app_exit: MessageWriter<AppExit>,
-) {
-    // Navigate up/down
-    if keyboard.just_pressed(KeyCode::ArrowUp) || keyboard.just_pressed(KeyCode::KeyW) {
-        menu_state.selected = menu_state.selected.saturating_sub(1);
+    // --- screen shake ---
+
+    fn shake_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(20),
+        ));
+        app.init_resource::<ScreenShake>();
+        app.init_resource::<CameraZoom>();
+        app.add_systems(Update, (trigger_screen_shake, apply_screen_shake).chain());
+        let camera = app.world_mut().spawn((Camera2d, Transform::default())).id();
+        app.update();
+        (app, camera)
+    }
+
+    #[test]
+    fn losing_a_life_shakes_the_camera() {
+        let (mut app, camera) = shake_test_app();
+
+        app.world_mut().resource_mut::<Lives>().count = 2;
+        app.update();
+
+        let offset = app.world().get::<Transform>(camera).unwrap().translation;
+        assert!(offset.truncate().length() > 0.0, "Camera should be shaken");
     }
-    if keyboard.just_pressed(KeyCode::ArrowDown) || keyboard.just_pressed(KeyCode::KeyS) {
-        menu_state.selected = (menu_state.selected + 1).min(PAUSE_MENU_ITEMS - 1);
+
+    #[test]
+    fn reduce_motion_keeps_camera_at_origin() {
+        let (mut app, camera) = shake_test_app();
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+
+        app.world_mut().resource_mut::<Lives>().count = 2;
+        app.update();
+        app.update();
+
+        let offset = app.world().get::<Transform>(camera).unwrap().translation;
+        assert_eq!(offset.truncate(), Vec2::ZERO);
     }
 
-    // Activate selected button
-    if keyboard.just_pressed(KeyCode::Enter) || keyboard.just_pressed(KeyCode::Space) {
-        match menu_state.selected {
-            0 => next_state.set(GameState::Playing), // Resume
-            1 => {
-                app_exit.write(AppExit::Success); // Quit
+    #[test]
+    fn reduce_motion_skips_combo_popups() {
+        let mut app = test_app();
+        app.insert_resource(Combo {
+            streak: 3,
+            last_points: 3 * POINTS_PER_BRICK,
+            last_position: Vec2::ZERO,
+        });
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+        app.add_message::<BrickHitEvent>();
+        app.add_systems(Update, spawn_combo_popup);
+
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<(), With<ComboPopup>>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+    }
+
+    // --- tick_combo_timer ---
+
+    fn combo_timer_app() -> App {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.insert_resource(Combo {
+            streak: 4,
+            ..default()
+        });
+        app.init_resource::<ComboTimer>();
+        app.add_message::<BrickHitEvent>();
+        app.add_systems(Update, tick_combo_timer);
+        app
+    }
+
+    #[test]
+    fn combo_persists_while_bricks_keep_breaking() {
+        let mut app = combo_timer_app();
+
+        // Break a brick every second for well past the timeout
+        for frame in 0..(COMBO_TIMEOUT * 30.0) as usize {
+            if frame % 10 == 0 {
+                app.world_mut().write_message(BrickHitEvent {
+                    destroyed: true,
+                    color: Color::WHITE,
+                });
             }
-            _ => {}
+            app.update();
         }
-    }
-}
 
-/// Updates button visuals based on keyboard selection state.
-#[allow(clippy::type_complexity)]
-pub fn update_pause_menu_visuals(
-    menu_state: Res<PauseMenuState>,
-    mut resume_query: Query<(&mut BackgroundColor, &Interaction), With<ResumeButton>>,
-    mut quit_query: Query<
-        (&mut BackgroundColor, &Interaction),
-        (With<QuitButton>, Without<ResumeButton>),
-    >,
-) {
-    if !menu_state.is_changed() {
-        return;
+        assert_eq!(app.world().resource::<Combo>().streak, 4);
     }
 
-    // Update Resume button
-    if let Ok((mut bg_color, interaction)) = resume_query.single_mut()
-        && *interaction != Interaction::Hovered
-        && *interaction != Interaction::Pressed
-    {
-        *bg_color = if menu_state.selected == 0 {
-            BUTTON_HOVERED.into()
-        } else {
-            BUTTON_NORMAL.into()
-        };
+    #[test]
+    fn combo_decays_after_timeout_without_hits() {
+        let mut app = combo_timer_app();
+
+        // Damaging a multi-hit brick does not keep the combo alive
+        for _ in 0..(COMBO_TIMEOUT * 10.0) as usize {
+            app.world_mut().write_message(BrickHitEvent {
+                destroyed: false,
+                color: Color::WHITE,
+            });
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Combo>().streak, 4);
+
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Combo>().streak, 0);
     }
 
-    // Update Quit button
-    if let Ok((mut bg_color, interaction)) = quit_query.single_mut()
-        && *interaction != Interaction::Hovered
-        && *interaction != Interaction::Pressed
-    {
-        *bg_color = if menu_state.selected == 1 {
-            BUTTON_HOVERED.into()
-        } else {
-            BUTTON_NORMAL.into()
-        };
+    // --- update_last_life_warning ---
+
+    fn warning_count(app: &mut App) -> usize {
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(), With<LastLifeWarningUi>>();
+        q.iter(app.world()).count()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn last_life_warning_follows_lives() {
+        let mut app = test_app();
+        app.add_systems(Update, update_last_life_warning);
 
-    fn test_app() -> App {
-        let mut app = App::new();
-        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin));
-        app.init_state::<GameState>();
-        app.init_resource::<Scoreboard>();
-        app.init_resource::<Lives>();
-        app.init_resource::<ButtonInput<KeyCode>>();
-        app
+        app.update();
+        assert_eq!(warning_count(&mut app), 0, "No warning with 3 lives");
+
+        app.world_mut().resource_mut::<Lives>().count = 1;
+        app.update();
+        app.update();
+        assert_eq!(warning_count(&mut app), 1, "One warning at last life");
+
+        // Catching an ExtraLife takes the player back to 2
+        app.world_mut().resource_mut::<Lives>().count = 2;
+        app.update();
+        assert_eq!(warning_count(&mut app), 0, "Warning clears above 1 life");
     }
 
-    // --- check_game_over ---
+    // --- update_scoreboard_ui ---
 
     #[test]
-    fn game_over_when_no_lives() {
+    fn scoreboard_ui_updates_on_change() {
         let mut app = test_app();
-        app.add_systems(Update, check_game_over);
-        app.world_mut().resource_mut::<Lives>().count = 0;
+        app.add_systems(Update, update_scoreboard_ui);
+
+        app.world_mut().spawn((Text::new("Score: 0"), ScoreboardUi));
+
+        app.world_mut().resource_mut::<Scoreboard>().score = 42;
 
         app.update();
 
-        let mut q = app.world_mut().query::<&OverlayUi>();
-        let overlay_count = q.iter(app.world()).count();
-        assert_eq!(overlay_count, 1, "Should spawn a game-over overlay");
+        let mut q = app.world_mut().query::<(&Text, &ScoreboardUi)>();
+        let text = q.iter(app.world()).next().unwrap().0;
+        assert_eq!(**text, "Score: 42");
+    }
+
+    // --- predict_landing_x ---
+
+    fn landing_bounds() -> Rect {
+        Rect::new(-100.0, 0.0, 100.0, 200.0)
     }
 
     #[test]
-    fn no_game_over_with_lives_remaining() {
+    fn predicts_straight_drop_without_bounces() {
+        let x = predict_landing_x(
+            Vec2::new(10.0, 100.0),
+            Vec2::new(20.0, -100.0),
+            landing_bounds(),
+        );
+        assert!((x - 30.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn predicts_one_side_wall_bounce() {
+        // Unfolded x = 50 + 100 = 150, reflected off the right wall at 100
+        let x = predict_landing_x(
+            Vec2::new(50.0, 100.0),
+            Vec2::new(100.0, -100.0),
+            landing_bounds(),
+        );
+        assert!((x - 50.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn predicts_two_bounces_including_ceiling() {
+        // Rising ball: 100 up to the ceiling, 200 back down; unfolded x = -50 - 300 = -350
+        // bounces off the left wall (-100) then the right wall (100)
+        let x = predict_landing_x(
+            Vec2::new(-50.0, 100.0),
+            Vec2::new(-100.0, 100.0),
+            landing_bounds(),
+        );
+        assert!((x - 50.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn predicts_multiple_side_wall_bounces() {
+        // Unfolded x = 0 + 450: off the right wall, then the left, landing at 50
+        let x = predict_landing_x(
+            Vec2::new(0.0, 100.0),
+            Vec2::new(450.0, -100.0),
+            landing_bounds(),
+        );
+        assert!((x - 50.0).abs() < 0.001);
+    }
+
+    // --- update_ball_color ---
+
+    #[test]
+    fn ball_color_is_base_at_base_speed() {
+        assert_eq!(ball_speed_color(BALL_SPEED), BALL_COLOR);
+        assert_eq!(
+            ball_speed_color(BALL_FAST_COLOR_SPEED * 3.0),
+            BALL_FAST_COLOR
+        );
+    }
+
+    #[test]
+    fn fast_ball_gets_different_color_than_slow_ball() {
         let mut app = test_app();
-        app.add_systems(Update, check_game_over);
+        app.add_systems(Update, update_ball_color);
+
+        let slow = app
+            .world_mut()
+            .spawn((
+                Sprite::default(),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        let fast = app
+            .world_mut()
+            .spawn((
+                Sprite::default(),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_FAST_COLOR_SPEED)),
+            ))
+            .id();
 
         app.update();
 
-        let mut q = app.world_mut().query::<&OverlayUi>();
-        let overlay_count = q.iter(app.world()).count();
-        assert_eq!(overlay_count, 0, "Should not spawn overlay when lives > 0");
+        let slow_color = app.world().get::<Sprite>(slow).unwrap().color;
+        let fast_color = app.world().get::<Sprite>(fast).unwrap().color;
+        assert_eq!(slow_color, BALL_COLOR);
+        assert_ne!(slow_color, fast_color, "Fast ball should be tinted");
     }
 
-    // --- check_victory ---
+    // --- tutorial hint ---
 
     #[test]
-    fn victory_when_no_bricks() {
+    fn tutorial_hint_spawns_for_new_players() {
         let mut app = test_app();
-        app.add_systems(Update, check_victory);
-        // No bricks spawned
+        app.init_resource::<SeenTutorial>();
+        app.add_systems(Update, spawn_tutorial_hint);
 
         app.update();
 
-        let mut q = app.world_mut().query::<&OverlayUi>();
-        let overlay_count = q.iter(app.world()).count();
-        assert_eq!(overlay_count, 1, "Should spawn a victory overlay");
+        let mut q = app.world_mut().query::<&TutorialHintUi>();
+        assert_eq!(q.iter(app.world()).count(), 1, "Hint should be shown");
+        assert!(
+            app.world().resource::<SeenTutorial>().seen,
+            "Showing the hint should mark it as seen"
+        );
     }
 
     #[test]
-    fn no_victory_with_bricks_remaining() {
+    fn tutorial_hint_skipped_for_returning_players() {
         let mut app = test_app();
-        app.add_systems(Update, check_victory);
+        app.insert_resource(SeenTutorial { seen: true });
+        app.add_systems(Update, spawn_tutorial_hint);
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&TutorialHintUi>();
+        assert_eq!(q.iter(app.world()).count(), 0, "Hint should not be shown");
+    }
+
+    #[test]
+    fn tutorial_hint_dismissed_on_movement() {
+        let mut app = test_app();
+        app.add_systems(Update, update_tutorial_hint);
+
+        app.world_mut().spawn((
+            Text::new("hint"),
+            TextColor(Color::WHITE),
+            FadeOut {
+                timer: Timer::from_seconds(TUTORIAL_HINT_DURATION, TimerMode::Once),
+            },
+            TutorialHintUi,
+        ));
+        app.update();
+
+        let mut q = app.world_mut().query::<&TutorialHintUi>();
+        assert_eq!(q.iter(app.world()).count(), 1, "Hint stays without input");
 
-        // Spawn a brick
         app.world_mut()
-            .spawn((Transform::from_xyz(0.0, 100.0, 0.0), Brick));
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+        app.update();
+
+        let mut q = app.world_mut().query::<&TutorialHintUi>();
+        assert_eq!(q.iter(app.world()).count(), 0, "Moving should dismiss hint");
+    }
+
+    // --- Ready state ---
+
+    #[test]
+    fn losing_the_ball_enters_ready_and_freezes_play() {
+        let mut app = test_app();
+        app.init_resource::<Serve>();
+        app.init_resource::<Combo>();
+        app.init_resource::<GameConfig>();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(
+            Update,
+            (
+                crate::movement::apply_velocity,
+                crate::collision::ball_death_zone,
+                enter_ready_when_ball_stuck,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, -WINDOW_HEIGHT, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+            ))
+            .id();
+        let hazard = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 100.0, 0.5),
+                Hazard,
+                Velocity(Vec2::new(0.0, -HAZARD_SPEED)),
+            ))
+            .id();
 
         app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Ready
+        );
+        assert!(app.world().get::<StuckToPaddle>(ball).is_some());
 
-        let mut q = app.world_mut().query::<&OverlayUi>();
-        let overlay_count = q.iter(app.world()).count();
+        let frozen_at = app.world().get::<Transform>(hazard).unwrap().translation;
+        app.update();
+        app.update();
         assert_eq!(
-            overlay_count, 0,
-            "Should not spawn overlay when bricks remain"
+            app.world().get::<Transform>(hazard).unwrap().translation,
+            frozen_at,
+            "Physics should be frozen in Ready"
         );
     }
 
-    // --- update_scoreboard_ui ---
+    // --- play_bounce_sounds ---
+
+    fn bounce_playback_speeds(bounces: &[(CollisionSide, f32)]) -> Vec<f32> {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<AudioSource>();
+        app.add_message::<BounceEvent>();
+        app.add_systems(Update, play_bounce_sounds);
+        for &(side, speed) in bounces {
+            app.world_mut().write_message(BounceEvent { side, speed });
+        }
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&PlaybackSettings>();
+        q.iter(app.world()).map(|settings| settings.speed).collect()
+    }
 
     #[test]
-    fn scoreboard_ui_updates_on_change() {
+    fn faster_bounces_play_at_a_higher_rate() {
+        let slow = bounce_playback_speeds(&[(CollisionSide::Left, BALL_SPEED * 0.8)]);
+        let fast = bounce_playback_speeds(&[(CollisionSide::Left, BALL_SPEED * 1.5)]);
+
+        assert_eq!((slow.len(), fast.len()), (1, 1));
+        assert!(fast[0] > slow[0], "{} should be above {}", fast[0], slow[0]);
+    }
+
+    #[test]
+    fn ceiling_bounces_sound_higher_than_side_walls() {
+        assert!(
+            bounce_playback_speed(CollisionSide::Bottom, BALL_SPEED)
+                > bounce_playback_speed(CollisionSide::Right, BALL_SPEED)
+        );
+        assert_eq!(
+            bounce_playback_speed(CollisionSide::Left, BALL_SPEED * 10.0),
+            BOUNCE_PITCH_MAX
+        );
+    }
+
+    // --- respawn_missing_ball ---
+
+    fn respawn_test_app() -> App {
         let mut app = test_app();
-        app.add_systems(Update, update_scoreboard_ui);
+        app.init_resource::<Serve>();
+        app.init_resource::<GameConfig>();
+        app.add_systems(
+            Update,
+            respawn_missing_ball.run_if(in_state(GameState::Playing)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app
+    }
 
-        app.world_mut().spawn((Text::new("Score: 0"), ScoreboardUi));
+    #[test]
+    fn playing_without_a_ball_respawns_exactly_one() {
+        let mut app = respawn_test_app();
 
-        app.world_mut().resource_mut::<Scoreboard>().score = 42;
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<Has<StuckToPaddle>, With<Ball>>();
+        let balls: Vec<bool> = q.iter(app.world()).collect();
+        assert_eq!(balls, vec![true], "One ball should wait on the paddle");
+    }
+
+    #[test]
+    fn no_respawn_while_a_ball_exists() {
+        let mut app = respawn_test_app();
+        app.world_mut()
+            .spawn((Transform::from_xyz(0.0, 0.0, 1.0), Ball));
 
         app.update();
+        app.update();
 
-        let mut q = app.world_mut().query::<(&Text, &ScoreboardUi)>();
-        let text = q.iter(app.world()).next().unwrap().0;
-        assert_eq!(**text, "Score: 42");
+        let mut q = app.world_mut().query_filtered::<(), With<Ball>>();
+        assert_eq!(q.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn launching_from_ready_starts_play_moving_up() {
+        let mut app = test_app();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<AimAngle>();
+        let mut serve = Serve::default();
+        serve.countdown.tick(serve.countdown.duration());
+        app.insert_resource(serve);
+        app.add_systems(
+            Update,
+            (crate::movement::launch_ball, enter_playing_on_launch)
+                .chain()
+                .run_if(in_state(GameState::Ready)),
+        );
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Ready);
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y + 20.0, 1.0),
+                Ball,
+                StuckToPaddle,
+                Velocity(Vec2::new(BALL_SPEED * 0.7, BALL_SPEED)),
+            ))
+            .id();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::Space);
+        keyboard.clear();
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Playing
+        );
+        assert!(app.world().get::<StuckToPaddle>(ball).is_none());
+        assert!(app.world().get::<Velocity>(ball).unwrap().0.y > 0.0);
     }
 
     // --- pause_input ---
@@ -576,6 +3750,56 @@ mod tests {
         );
     }
 
+    // --- pause_on_controller_disconnect ---
+
+    #[test]
+    fn controller_disconnect_pauses_play_until_reconnected() {
+        let mut app = pause_menu_test_app();
+        app.add_message::<GamepadConnectionEvent>();
+        app.add_systems(Update, pause_on_controller_disconnect);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+        app.update();
+        let gamepad = app.world_mut().spawn_empty().id();
+
+        app.world_mut().write_message(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Disconnected,
+        ));
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Paused
+        );
+        assert!(
+            app.world()
+                .resource::<PauseMenuState>()
+                .controller_disconnected
+        );
+
+        app.world_mut().write_message(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected {
+                name: "pad".to_string(),
+                vendor_id: None,
+                product_id: None,
+            },
+        ));
+        app.update();
+        assert!(
+            !app.world()
+                .resource::<PauseMenuState>()
+                .controller_disconnected
+        );
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Paused,
+            "Reconnecting leaves resuming to the player"
+        );
+    }
+
     // --- pause_menu_keyboard_navigation ---
 
     fn pause_menu_test_app() -> App {
@@ -587,6 +3811,20 @@ mod tests {
         app
     }
 
+    #[test]
+    fn pause_overlay_uses_the_pause_layers() {
+        let mut app = pause_menu_test_app();
+        app.add_systems(Update, spawn_pause_overlay);
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&GlobalZIndex, With<OverlayUi>>();
+        let mut layers: Vec<_> = q.iter(app.world()).copied().collect();
+        layers.sort_by_key(|z| z.0);
+        assert_eq!(layers, vec![UI_Z_PAUSE_DIM, UI_Z_PAUSE_MENU]);
+    }
+
     #[test]
     fn keyboard_navigation_moves_selection_down() {
         let mut app = pause_menu_test_app();
@@ -671,6 +3909,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn single_press_moves_selection_once() {
+        let mut repeat = KeyRepeat::default();
+        assert_eq!(repeat.steps(true, true, 0.016), 1);
+        assert_eq!(repeat.steps(false, true, MENU_REPEAT_DELAY * 0.5), 0);
+        assert_eq!(repeat.steps(false, false, MENU_REPEAT_DELAY * 2.0), 0);
+    }
+
+    #[test]
+    fn holding_past_the_repeat_delay_moves_repeatedly() {
+        let mut repeat = KeyRepeat::default();
+        let held = MENU_REPEAT_DELAY + MENU_REPEAT_INTERVAL * 2.5;
+        let steps = repeat.steps(true, true, 0.0) + repeat.steps(false, true, held);
+        assert_eq!(
+            steps, 4,
+            "Press, then repeats at the delay and two intervals after"
+        );
+    }
+
+    #[test]
+    fn held_key_repeats_selection_after_delay() {
+        let mut app = pause_menu_test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(50),
+        ));
+        app.add_systems(Update, pause_menu_keyboard_navigation);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowDown);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear_just_pressed(KeyCode::ArrowDown);
+        assert_eq!(app.world().resource::<PauseMenuState>().selected, 1);
+
+        // Put the selection back while the key stays held; it moves again only after the delay
+        app.world_mut().resource_mut::<PauseMenuState>().selected = 0;
+        let frames_before_delay = (MENU_REPEAT_DELAY / 0.05) as usize - 1;
+        for _ in 0..frames_before_delay {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<PauseMenuState>().selected, 0);
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().resource::<PauseMenuState>().selected,
+            1,
+            "Holding down past the repeat delay should step the selection again"
+        );
+    }
+
+    // --- pause menu visuals ---
+
+    #[test]
+    fn pause_button_color_combines_mouse_and_keyboard() {
+        assert_eq!(
+            pause_button_color(Interaction::Pressed, false),
+            BUTTON_PRESSED
+        );
+        assert_eq!(
+            pause_button_color(Interaction::Hovered, false),
+            BUTTON_HOVERED
+        );
+        assert_eq!(pause_button_color(Interaction::None, true), BUTTON_HOVERED);
+        assert_eq!(pause_button_color(Interaction::None, false), BUTTON_NORMAL);
+    }
+
+    #[test]
+    fn keyboard_selected_button_stays_highlighted_after_mouse_leaves() {
+        let mut app = pause_menu_test_app();
+        app.add_systems(
+            Update,
+            (pause_menu_mouse_interaction, update_pause_menu_visuals).chain(),
+        );
+        app.add_message::<AppExit>();
+
+        // Quit is keyboard-selected
+        app.world_mut().resource_mut::<PauseMenuState>().selected = 1;
+        let resume = app
+            .world_mut()
+            .spawn((
+                Button,
+                Interaction::None,
+                BackgroundColor(BUTTON_NORMAL),
+                ResumeButton,
+            ))
+            .id();
+        let quit = app
+            .world_mut()
+            .spawn((
+                Button,
+                Interaction::None,
+                BackgroundColor(BUTTON_NORMAL),
+                QuitButton,
+            ))
+            .id();
+        app.update();
+
+        // Mouse hovers Quit, then leaves
+        *app.world_mut().get_mut::<Interaction>(quit).unwrap() = Interaction::Hovered;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(quit).unwrap() = Interaction::None;
+        app.update();
+
+        let quit_color = app.world().get::<BackgroundColor>(quit).unwrap().0;
+        let resume_color = app.world().get::<BackgroundColor>(resume).unwrap().0;
+        assert_eq!(
+            quit_color, BUTTON_HOVERED,
+            "Selected button should stay lit"
+        );
+        assert_eq!(resume_color, BUTTON_NORMAL);
+    }
+
     #[test]
     fn enter_on_resume_transitions_to_playing() {
         let mut app = pause_menu_test_app();
@@ -724,4 +4078,465 @@ mod tests {
             "Space on Resume should transition to Playing"
         );
     }
+
+    // --- apply_game_speed ---
+
+    #[test]
+    fn game_speed_scales_virtual_time_within_range() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_game_speed);
+
+        for (setting, expected) in [(1.5, 1.5), (5.0, GAME_SPEED_MAX), (0.1, GAME_SPEED_MIN)] {
+            app.world_mut().resource_mut::<Settings>().game_speed = GameSpeed(setting);
+            app.update();
+            assert_eq!(
+                app.world().resource::<Time<Virtual>>().relative_speed(),
+                expected
+            );
+        }
+    }
+
+    // --- apply_frame_limit ---
+
+    #[test]
+    fn frame_limit_setting_updates_window_present_mode() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_frame_limit);
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), bevy::window::PrimaryWindow))
+            .id();
+
+        for (frame_limit, expected) in [
+            (FrameLimit::Uncapped, bevy::window::PresentMode::AutoNoVsync),
+            (FrameLimit::Cap144, bevy::window::PresentMode::AutoNoVsync),
+            (FrameLimit::VSync, bevy::window::PresentMode::AutoVsync),
+        ] {
+            app.world_mut().resource_mut::<Settings>().frame_limit = frame_limit;
+            app.update();
+            let present_mode = app.world().get::<Window>(window).unwrap().present_mode;
+            assert_eq!(present_mode, expected, "{frame_limit:?}");
+        }
+    }
+
+    // --- limit_frame_rate ---
+
+    #[test]
+    fn frame_cap_stretches_short_frames() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Settings>().frame_limit = FrameLimit::Cap60;
+        app.add_systems(Last, limit_frame_rate);
+        app.update();
+
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(start.elapsed() >= std::time::Duration::from_secs_f32(2.5 / 60.0));
+    }
+
+    // --- cycle_frame_limit ---
+
+    #[test]
+    fn v_cycles_frame_limit_back_to_vsync() {
+        let mut app = test_app();
+        app.add_systems(Update, cycle_frame_limit);
+        assert_eq!(
+            app.world().resource::<Settings>().frame_limit,
+            FrameLimit::VSync
+        );
+
+        for _ in 0..5 {
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::KeyV);
+            app.update();
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(KeyCode::KeyV);
+            keyboard.clear();
+        }
+        assert_eq!(
+            app.world().resource::<Settings>().frame_limit,
+            FrameLimit::VSync
+        );
+    }
+
+    // --- sync_powerup_weights ---
+
+    #[test]
+    fn difficulty_change_updates_powerup_weights() {
+        let mut app = test_app();
+        app.init_resource::<PowerUpWeights>();
+        app.add_systems(Update, sync_powerup_weights);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<PowerUpWeights>(),
+            PowerUpWeights::for_difficulty(Difficulty::Normal)
+        );
+
+        app.world_mut().resource_mut::<Settings>().difficulty = Difficulty::Hard;
+        app.update();
+        assert_eq!(
+            *app.world().resource::<PowerUpWeights>(),
+            PowerUpWeights::for_difficulty(Difficulty::Hard)
+        );
+    }
+
+    // --- fade_hit_flashes ---
+
+    #[test]
+    fn hit_flash_fades_from_white_back_to_the_brick_color() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(50),
+        ));
+        app.add_systems(Update, fade_hit_flashes);
+        let brick = app
+            .world_mut()
+            .spawn((
+                Sprite::from_color(Color::WHITE, Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                Brick,
+                HitFlash {
+                    timer: Timer::from_seconds(HIT_FLASH_DURATION, TimerMode::Once),
+                    color: BRICK_COLORS[0],
+                },
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        let fading = app.world().get::<Sprite>(brick).unwrap().color;
+        assert!(fading != Color::WHITE && fading != BRICK_COLORS[0]);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get::<Sprite>(brick).unwrap().color,
+            BRICK_COLORS[0]
+        );
+        assert!(app.world().get::<HitFlash>(brick).is_none());
+    }
+
+    // --- brick spawn animation ---
+
+    #[test]
+    fn bricks_become_collidable_once_their_entrance_ends() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(50),
+        ));
+        app.add_systems(Startup, start_brick_spawn_anim);
+        app.add_systems(Update, animate_brick_spawns);
+        let brick_at = |app: &mut App, y: f32| {
+            app.world_mut()
+                .spawn((
+                    Sprite::from_color(BRICK_COLORS[0], Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                    Transform::from_xyz(0.0, y, 0.0),
+                    Brick,
+                    Collider,
+                ))
+                .id()
+        };
+        let top = brick_at(&mut app, 100.0);
+        let below = brick_at(&mut app, 70.0);
+
+        app.update();
+        assert!(app.world().get::<Collider>(top).is_none());
+        assert_eq!(app.world().get::<Transform>(top).unwrap().scale, Vec3::ZERO);
+        assert!(
+            app.world().get::<SpawnAnim>(below).unwrap().delay
+                > app.world().get::<SpawnAnim>(top).unwrap().delay,
+            "Lower rows should enter later"
+        );
+
+        let frames = ((BRICK_SPAWN_ROW_DELAY + BRICK_SPAWN_DURATION) / 0.05).ceil() as usize + 2;
+        for _ in 0..frames {
+            let animating = app.world().get::<SpawnAnim>(below).is_some();
+            let collidable = app.world().get::<Collider>(below).is_some();
+            assert!(!(animating && collidable), "No collider mid-entrance");
+            app.update();
+        }
+        for brick in [top, below] {
+            assert!(app.world().get::<Collider>(brick).is_some());
+            assert!(app.world().get::<SpawnAnim>(brick).is_none());
+            assert_eq!(
+                app.world().get::<Transform>(brick).unwrap().scale,
+                Vec3::ONE
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_motion_spawns_bricks_instantly() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+        app.add_systems(Startup, start_brick_spawn_anim);
+        let brick = app
+            .world_mut()
+            .spawn((Transform::default(), Brick, Collider))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get::<Collider>(brick).is_some());
+        assert!(app.world().get::<SpawnAnim>(brick).is_none());
+    }
+
+    // --- sync_clear_color ---
+
+    #[test]
+    fn clear_color_follows_the_configured_backdrop() {
+        let mut app = test_app();
+        app.add_systems(Update, sync_clear_color);
+        app.update();
+
+        assert_eq!(app.world().resource::<ClearColor>().0, BACKDROP_COLOR);
+
+        let tone = Color::srgb(0.1, 0.0, 0.1);
+        app.world_mut().resource_mut::<GameConfig>().clear_color = BackdropColor(tone);
+        app.update();
+        assert_eq!(app.world().resource::<ClearColor>().0, tone);
+    }
+
+    // --- sync_danger_line ---
+
+    #[test]
+    fn danger_line_sits_above_the_death_zone_and_follows_bounds() {
+        let mut app = test_app();
+        app.init_resource::<CurrentLevel>();
+        app.world_mut().resource_mut::<Settings>().danger_line = true;
+        app.add_systems(Startup, crate::setup::spawn_game);
+        app.add_systems(Update, sync_danger_line);
+        app.update();
+
+        let line_y = |app: &mut App| {
+            let mut q = app
+                .world_mut()
+                .query_filtered::<(&Transform, &Visibility), With<DangerLine>>();
+            let (transform, visibility) = q.single(app.world()).unwrap();
+            assert_eq!(*visibility, Visibility::Inherited);
+            transform.translation.y
+        };
+        let death_y = PlayfieldBounds::from_config(&GameConfig::default()).death_y;
+        assert_eq!(line_y(&mut app), death_y + DANGER_LINE_MARGIN);
+
+        app.world_mut().resource_mut::<GameConfig>().ball_size = BallSize::Big;
+        app.update();
+        let big_death_y = -WINDOW_HEIGHT / 2.0 - BALL_SIZE_BIG;
+        assert_eq!(line_y(&mut app), big_death_y + DANGER_LINE_MARGIN);
+    }
+
+    #[test]
+    fn danger_line_is_hidden_when_the_setting_is_off() {
+        let mut app = test_app();
+        app.add_systems(Update, sync_danger_line);
+        let line = app
+            .world_mut()
+            .spawn((Sprite::default(), Visibility::Inherited, DangerLine))
+            .id();
+
+        app.update();
+
+        assert_eq!(
+            app.world().get::<Visibility>(line),
+            Some(&Visibility::Hidden)
+        );
+    }
+
+    // --- tick_ui_time ---
+
+    #[test]
+    fn ui_animation_runs_while_gameplay_time_is_paused() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Update, update_combo_popups);
+        let popup = app
+            .world_mut()
+            .spawn((
+                Transform::default(),
+                TextColor(Color::WHITE),
+                FadeOut {
+                    timer: Timer::from_seconds(COMBO_POPUP_DURATION, TimerMode::Once),
+                },
+                ComboPopup,
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Time<Virtual>>().elapsed_secs(), 0.0);
+        let alpha = app.world().get::<TextColor>(popup).unwrap().0.alpha();
+        assert!(alpha < 1.0, "Popup should fade on the UI clock");
+    }
+
+    // --- shimmer_bricks ---
+
+    fn shimmer_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.add_systems(Update, shimmer_bricks);
+        let brick = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(40.0, 120.0, 0.0),
+                Sprite::from_color(BRICK_COLORS[0], Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                Brick,
+            ))
+            .id();
+        (app, brick)
+    }
+
+    #[test]
+    fn bricks_shimmer_over_time() {
+        let (mut app, brick) = shimmer_test_app();
+
+        app.update();
+        let first = app.world().get::<Sprite>(brick).unwrap().color;
+        app.update();
+        app.update();
+        let later = app.world().get::<Sprite>(brick).unwrap().color;
+
+        assert_ne!(first, later, "Brick color should pulse");
+    }
+
+    #[test]
+    fn reduce_motion_keeps_brick_color_fixed() {
+        let (mut app, brick) = shimmer_test_app();
+        app.world_mut().resource_mut::<Settings>().reduce_motion = true;
+
+        for _ in 0..5 {
+            app.update();
+            assert_eq!(
+                app.world().get::<Sprite>(brick).unwrap().color,
+                BRICK_COLORS[0]
+            );
+        }
+    }
+
+    // --- animate_paddle_squash ---
+
+    #[test]
+    fn paddle_squash_eases_back_to_full_scale() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(50),
+        ));
+        app.add_systems(Update, animate_paddle_squash);
+        let paddle = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, PADDLE_Y, 0.0),
+                Paddle,
+                PaddleSquash {
+                    timer: Timer::from_seconds(PADDLE_SQUASH_DURATION, TimerMode::Once),
+                },
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        let scale = app.world().get::<Transform>(paddle).unwrap().scale;
+        assert!(
+            scale.x > 1.0 && scale.y < 1.0,
+            "Paddle should be squashed, got {scale:?}"
+        );
+
+        for _ in 0..(PADDLE_SQUASH_DURATION / 0.05) as usize + 1 {
+            app.update();
+        }
+        assert_eq!(
+            app.world().get::<Transform>(paddle).unwrap().scale,
+            Vec3::ONE
+        );
+        assert!(app.world().get::<PaddleSquash>(paddle).is_none());
+    }
+
+    // --- spawn_combo_popup ---
+
+    /// Breaks a row of bricks with the given colors in turn and returns each combo popup's text
+    /// and color, sorted by text.
+    fn break_brick_chain(colors: [Color; 3]) -> Vec<(String, Color)> {
+        let mut app = test_app();
+        app.init_resource::<Combo>();
+        app.init_resource::<DestroyedBricks>();
+        app.init_resource::<PowerUpWeights>();
+        app.init_resource::<GameConfig>();
+        app.add_message::<BrickHitEvent>();
+        app.add_systems(
+            Update,
+            (crate::collision::ball_collision_bricks, spawn_combo_popup).chain(),
+        );
+
+        let ball_y = 100.0 - BRICK_HEIGHT / 2.0 - BALL_SIZE / 2.0 + 2.0;
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, ball_y, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        let xs = [0.0, 200.0, 400.0];
+        for (x, color) in xs.into_iter().zip(colors) {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, 100.0, 0.0),
+                Sprite::from_color(color, Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+                Brick,
+                Collider,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ));
+        }
+
+        for x in xs {
+            app.world_mut()
+                .get_mut::<Transform>(ball)
+                .unwrap()
+                .translation = Vec3::new(x, ball_y, 1.0);
+            app.update();
+        }
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Text2d, &TextColor), With<ComboPopup>>();
+        let mut popups: Vec<_> = q
+            .iter(app.world())
+            .map(|(text, color)| (text.0.clone(), color.0))
+            .collect();
+        popups.sort_by(|a, b| a.0.cmp(&b.0));
+        popups
+    }
+
+    #[test]
+    fn brick_chain_spawns_one_popup_per_combo_step() {
+        let texts: Vec<String> = break_brick_chain([Color::WHITE; 3])
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                format!("2x COMBO! +{}", 2 * POINTS_PER_BRICK),
+                format!("3x COMBO! +{}", 3 * POINTS_PER_BRICK),
+            ]
+        );
+    }
+
+    #[test]
+    fn combo_popup_takes_the_broken_brick_color() {
+        let red = Color::srgb(0.9, 0.1, 0.1);
+        let blue = Color::srgb(0.1, 0.2, 0.9);
+        let colors: Vec<Color> = break_brick_chain([Color::WHITE, red, blue])
+            .into_iter()
+            .map(|(_, color)| color)
+            .collect();
+        assert_eq!(colors, vec![red, blue]);
+    }
 }
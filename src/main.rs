@@ -1,9 +1,19 @@
+mod achievements;
+mod assets;
+mod autopilot;
 mod background;
 mod collision;
 mod components;
+mod console;
+mod debug;
+mod demo;
+mod editor;
 mod game;
+mod monitor;
 mod movement;
+mod persistence;
 mod setup;
+mod stats;
 
 use bevy::prelude::*;
 use components::*;
@@ -19,41 +29,226 @@ fn main() {
             }),
             ..default()
         }))
-        .add_plugins(background::BackgroundPlugin)
+        .add_plugins((
+            achievements::AchievementsPlugin,
+            assets::AssetWatchPlugin,
+            autopilot::AutopilotPlugin,
+            background::BackgroundPlugin,
+            console::ConsolePlugin,
+            debug::DebugPlugin,
+            demo::DemoPlugin,
+            editor::EditorPlugin,
+            monitor::MonitorPlugin,
+            stats::StatsPlugin,
+        ))
         // State
         .init_state::<GameState>()
         // Resources
         .init_resource::<Scoreboard>()
         .init_resource::<Lives>()
+        .init_resource::<CurrentLevel>()
         .init_resource::<PauseMenuState>()
+        .init_resource::<SeenTutorial>()
+        .init_resource::<SavedRun>()
+        .init_resource::<ExitSavePaths>()
+        .init_resource::<TimeAttack>()
+        .init_resource::<Serve>()
+        .init_resource::<AimAngle>()
+        .init_resource::<Combo>()
+        .init_resource::<ComboTimer>()
+        .init_resource::<ActivePowerUps>()
+        .init_resource::<DestroyedBricks>()
+        .init_resource::<Settings>()
+        .init_resource::<PowerUpWeights>()
+        .init_resource::<GameRng>()
+        .init_resource::<ScreenShake>()
+        .init_resource::<UiTime>()
+        .init_resource::<Earthquake>()
+        .init_resource::<CameraZoom>()
+        .init_resource::<GameConfig>()
+        // Messages
+        .add_message::<BounceEvent>()
         // Startup systems
         .add_systems(
             Startup,
-            (setup::spawn_camera, setup::spawn_game, setup::spawn_ui),
+            (
+                setup::spawn_camera,
+                (setup::validate_brick_layout, setup::spawn_game).chain(),
+                setup::spawn_ui,
+                persistence::load_seen_tutorial,
+            ),
+        )
+        // Persistence
+        .add_systems(Update, persistence::save_seen_tutorial)
+        // Window
+        .add_systems(Update, setup::fit_camera_to_window)
+        // Settings
+        .add_systems(
+            Update,
+            (
+                game::sync_powerup_weights,
+                game::apply_game_speed,
+                game::sync_ball_size,
+                game::sync_bottom_mode,
+                game::sync_danger_line,
+                game::sync_clear_color,
+                game::apply_frame_limit,
+            ),
+        )
+        // HUD
+        .add_systems(First, game::tick_ui_time.after(bevy::time::TimeSystems))
+        .add_systems(
+            Update,
+            (game::update_last_life_warning, game::update_score_legend),
+        )
+        // Camera
+        .add_systems(
+            Update,
+            (
+                game::trigger_screen_shake,
+                game::update_camera_zoom,
+                game::apply_screen_shake,
+            )
+                .chain(),
         )
         // Menu state
-        .add_systems(OnEnter(GameState::Menu), setup::spawn_menu)
+        .add_systems(
+            OnEnter(GameState::Menu),
+            (persistence::refresh_saved_run, setup::spawn_menu).chain(),
+        )
         .add_systems(OnExit(GameState::Menu), setup::despawn_overlay)
-        .add_systems(Update, game::menu_input.run_if(in_state(GameState::Menu)))
-        // Playing state
-        .add_systems(OnEnter(GameState::Playing), setup::reset_ball_and_paddle)
+        .add_systems(
+            Update,
+            (
+                game::menu_input,
+                persistence::continue_saved_run,
+                game::cycle_frame_limit,
+                game::update_frame_limit_text,
+                game::select_practice_row,
+                game::update_practice_row_text,
+            )
+                .run_if(in_state(GameState::Menu)),
+        )
+        // Exit saves: runs last so both the Quit button and closing the window are seen
+        .add_systems(
+            Last,
+            persistence::save_run_on_exit.run_if(
+                in_state(GameState::Ready)
+                    .or(in_state(GameState::Playing))
+                    .or(in_state(GameState::Paused)),
+            ),
+        )
+        // Frame cap: runs last so the sleep covers the whole frame
+        .add_systems(Last, game::limit_frame_rate)
+        // Ready state: the paddle moves and aims the serve while physics is frozen
+        // Only a fresh start serves a new ball; resuming from pause keeps the ball in play
+        .add_systems(
+            OnTransition {
+                exited: GameState::Menu,
+                entered: GameState::Ready,
+            },
+            (
+                (
+                    game::start_run_rng,
+                    setup::spawn_random_layout,
+                    game::start_brick_spawn_anim,
+                )
+                    .chain(),
+                setup::reset_ball_and_paddle,
+                game::reset_earthquake,
+            ),
+        )
+        // Continuing after a victory serves from a freshly spawned board
+        .add_systems(
+            OnTransition {
+                exited: GameState::Victory,
+                entered: GameState::Ready,
+            },
+            (
+                (setup::spawn_random_layout, game::start_brick_spawn_anim).chain(),
+                setup::reset_ball_and_paddle,
+            ),
+        )
+        .add_systems(OnEnter(GameState::Ready), game::spawn_tutorial_hint)
         .add_systems(
             Update,
             (
                 movement::move_paddle,
-                movement::move_ball,
+                movement::mouse_paddle,
+                movement::track_paddle_velocity,
+                movement::hold_stuck_ball,
+                movement::aim_serve_at_bricks,
+                movement::aim_launch,
+                movement::launch_ball,
+                game::enter_playing_on_launch,
+            )
+                .chain()
+                .run_if(in_state(GameState::Ready)),
+        )
+        // Playing state
+        .add_systems(
+            Update,
+            (
+                (
+                    game::run_earthquakes,
+                    movement::move_paddle,
+                    movement::mouse_paddle,
+                    movement::apply_paddle_zone_assist,
+                    movement::track_paddle_velocity,
+                    movement::apply_velocity,
+                )
+                    .chain(),
                 collision::ball_collision_walls_and_paddle,
                 collision::ball_collision_bricks,
+                game::fire_enemy_bricks,
+                collision::hazard_collision_paddle,
+                collision::powerup_collision_paddle,
                 collision::clamp_ball_to_bounds,
+                collision::recover_stuck_ball,
                 collision::ball_death_zone,
+                game::respawn_missing_ball,
+                game::enter_ready_when_ball_stuck,
                 game::update_scoreboard_ui,
                 game::update_lives_ui,
+                game::update_timer_ui,
+                game::tick_time_attack,
+                game::tick_powerup_timers,
+                game::apply_powerup_effects,
+                game::tick_combo_timer,
                 game::check_game_over,
                 game::check_victory,
             )
                 .chain()
                 .run_if(in_state(GameState::Playing)),
         )
+        .add_systems(
+            Update,
+            (
+                game::quick_restart_input,
+                game::leave_practice,
+                game::skip_ball_input.before(collision::ball_death_zone),
+            )
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                game::update_tutorial_hint,
+                game::update_ball_color,
+                game::spawn_combo_popup,
+                game::update_combo_popups,
+                game::animate_paddle_squash,
+                game::blink_expiring_powerups,
+                game::fade_hit_flashes,
+                game::animate_brick_spawns,
+                game::shimmer_bricks,
+                game::update_landing_indicator,
+                game::update_aim_arrow,
+                game::sync_catch_indicator,
+                game::update_boss_health_bar,
+            )
+                .run_if(in_state(GameState::Ready).or(in_state(GameState::Playing))),
+        )
         // Paused state
         .add_systems(OnEnter(GameState::Paused), game::spawn_pause_overlay)
         .add_systems(OnExit(GameState::Paused), setup::despawn_overlay)
@@ -63,21 +258,44 @@ fn main() {
                 game::pause_menu_mouse_interaction,
                 game::pause_menu_keyboard_navigation,
                 game::update_pause_menu_visuals,
+                game::update_controller_note,
             )
+                .chain()
                 .run_if(in_state(GameState::Paused)),
         )
         .add_systems(
             Update,
-            game::pause_input.run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+            (game::pause_input, game::pause_on_controller_disconnect).run_if(
+                in_state(GameState::Ready)
+                    .or(in_state(GameState::Playing))
+                    .or(in_state(GameState::Paused)),
+            ),
         )
         // GameOver / Victory
+        .add_systems(OnEnter(GameState::GameOver), game::spawn_results_screen)
+        .add_systems(OnEnter(GameState::Victory), game::spawn_results_screen)
         .add_systems(OnExit(GameState::GameOver), setup::despawn_overlay)
         .add_systems(OnExit(GameState::Victory), setup::despawn_overlay)
         .add_systems(
             Update,
-            game::restart_input
+            (
+                game::restart_input,
+                game::update_score_tally,
+                game::update_results_screen,
+                game::warn_stacked_results_screens,
+            )
+                .chain()
                 .run_if(in_state(GameState::GameOver).or(in_state(GameState::Victory))),
         )
+        .add_systems(
+            Update,
+            (game::continue_after_victory, game::play_score_tally_ticks)
+                .run_if(in_state(GameState::Victory)),
+        )
+        // Audio
+        .add_systems(Update, game::play_bounce_sounds)
         .add_systems(OnEnter(GameState::Menu), game::respawn_on_menu_enter)
+        .add_systems(OnEnter(GameState::Menu), game::reset_camera_zoom)
+        .add_systems(OnEnter(GameState::Victory), game::reset_camera_zoom)
         .run();
 }
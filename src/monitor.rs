@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy::window::{Monitor, PrimaryMonitor, PrimaryWindow, WindowPosition};
+
+pub struct MonitorPlugin;
+
+impl Plugin for MonitorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TargetMonitor {
+            index: parse_monitor_arg(std::env::args()),
+        })
+        .add_systems(Update, center_window_on_monitor);
+    }
+}
+
+/// Monitor chosen with `--monitor <n>` (counted left to right); `None` means the primary one.
+#[derive(Resource)]
+struct TargetMonitor {
+    index: Option<usize>,
+}
+
+/// Reads `--monitor <n>` or `--monitor=<n>` from the command line.
+fn parse_monitor_arg(args: impl IntoIterator<Item = String>) -> Option<usize> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--monitor" {
+            return args.next()?.parse().ok();
+        }
+        if let Some(value) = arg.strip_prefix("--monitor=") {
+            return value.parse().ok();
+        }
+    }
+    None
+}
+
+/// Returns the top-left window position that centres `window_size` on a monitor (physical pixels).
+fn centered_position(monitor_position: IVec2, monitor_size: UVec2, window_size: UVec2) -> IVec2 {
+    monitor_position + (monitor_size.as_ivec2() - window_size.as_ivec2()) / 2
+}
+
+/// Centres the primary window on the target monitor once the monitors are known.
+fn center_window_on_monitor(
+    target: Res<TargetMonitor>,
+    monitor_query: Query<(&Monitor, Has<PrimaryMonitor>)>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut placed: Local<bool>,
+) {
+    if *placed {
+        return;
+    }
+    let Ok(mut window) = window_query.single_mut() else {
+        return;
+    };
+
+    let mut monitors: Vec<_> = monitor_query.iter().collect();
+    monitors.sort_by_key(|(monitor, _)| (monitor.physical_position.x, monitor.physical_position.y));
+
+    // Unknown indices fall back to the primary monitor
+    let chosen = target
+        .index
+        .and_then(|index| monitors.get(index))
+        .or_else(|| monitors.iter().find(|(_, primary)| *primary));
+    let Some((monitor, _)) = chosen else {
+        return;
+    };
+
+    let scale = monitor.scale_factor as f32;
+    let window_size = UVec2::new(
+        (window.resolution.width() * scale) as u32,
+        (window.resolution.height() * scale) as u32,
+    );
+    window.position = WindowPosition::At(centered_position(
+        monitor.physical_position,
+        monitor.physical_size(),
+        window_size,
+    ));
+    *placed = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    // --- parse_monitor_arg ---
+
+    #[test]
+    fn no_flag_selects_primary() {
+        assert_eq!(parse_monitor_arg(args("breakout")), None);
+    }
+
+    #[test]
+    fn parses_separate_and_inline_values() {
+        assert_eq!(parse_monitor_arg(args("breakout --monitor 1")), Some(1));
+        assert_eq!(parse_monitor_arg(args("breakout --monitor=2")), Some(2));
+    }
+
+    #[test]
+    fn invalid_value_selects_primary() {
+        assert_eq!(parse_monitor_arg(args("breakout --monitor left")), None);
+        assert_eq!(parse_monitor_arg(args("breakout --monitor")), None);
+    }
+
+    // --- centered_position ---
+
+    #[test]
+    fn centers_window_on_primary_monitor() {
+        let position = centered_position(IVec2::ZERO, UVec2::new(1920, 1080), UVec2::new(900, 600));
+        assert_eq!(position, IVec2::new(510, 240));
+    }
+
+    #[test]
+    fn centers_window_on_offset_monitor() {
+        // Secondary monitor to the left of and above the primary
+        let position = centered_position(
+            IVec2::new(-2560, -200),
+            UVec2::new(2560, 1440),
+            UVec2::new(1800, 1200),
+        );
+        assert_eq!(position, IVec2::new(-2180, -80));
+    }
+}
@@ -1,38 +1,281 @@
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 
 use crate::components::*;
 
-/// Moves the paddle left/right based on keyboard input, clamped to window bounds.
+/// Moves the paddle left/right based on keyboard input (or its `AiPaddle`), clamped to window
+/// bounds. The paddle holds still while SPACE is held to aim a serve.
 pub fn move_paddle(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<Paddle>>,
+    settings: Res<Settings>,
+    config: Res<GameConfig>,
+    mut query: Query<(&mut Transform, &ColliderSize, Option<&AiPaddle>), With<Paddle>>,
+    stuck_query: Query<(), With<StuckToPaddle>>,
 ) {
-    let Ok(mut transform) = query.single_mut() else {
+    let Ok((mut transform, collider_size, ai)) = query.single_mut() else {
         return;
     };
 
+    if keyboard.pressed(KeyCode::Space) && !stuck_query.is_empty() {
+        return;
+    }
+
     let mut direction = 0.0;
 
+    if let Some(ai) = ai {
+        direction = ai.direction;
+    } else {
+        if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
+            direction -= 1.0;
+        }
+        if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
+            direction += 1.0;
+        }
+        if settings.invert_paddle {
+            direction = -direction;
+        }
+    }
+
+    transform.translation.x += direction * PADDLE_SPEED * time.delta_secs();
+
+    // Clamp within window bounds, accounting for a widened paddle
+    transform.translation.x = PlayfieldBounds::from_config(&config)
+        .clamp_paddle_x(transform.translation.x, collider_size.0.x);
+}
+
+/// Returns the paddle x after easing from `current` toward `target` for `delta` seconds, closing
+/// `smoothing` of the gap per `MOUSE_SMOOTHING_FPS` frame.
+pub fn smoothed_paddle_x(current: f32, target: f32, smoothing: MouseSmoothing, delta: f32) -> f32 {
+    let keep = (1.0 - smoothing.0.clamp(0.0, 1.0)).powf(delta * MOUSE_SMOOTHING_FPS);
+    target + (current - target) * keep
+}
+
+/// Returns the paddle x moved from `current` toward `target`, but no further than `max_speed`
+/// allows in `delta` seconds.
+pub fn limit_paddle_step(
+    current: f32,
+    target: f32,
+    max_speed: Option<MaxPaddleSpeed>,
+    delta: f32,
+) -> f32 {
+    let Some(max_speed) = max_speed else {
+        return target;
+    };
+    let step = max_speed.0.max(0.0) * delta;
+    current + (target - current).clamp(-step, step)
+}
+
+/// Eases the paddle toward the mouse cursor when mouse control is on, clamped to window bounds.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn mouse_paddle(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    config: Res<GameConfig>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut paddle_query: Query<(&mut Transform, &ColliderSize), (With<Paddle>, Without<AiPaddle>)>,
+    stuck_query: Query<(), With<StuckToPaddle>>,
+) {
+    if !settings.mouse_paddle || keyboard.pressed(KeyCode::Space) && !stuck_query.is_empty() {
+        return;
+    }
+    let (Ok(window), Ok((camera, camera_transform))) =
+        (window_query.single(), camera_query.single())
+    else {
+        return;
+    };
+    let Some(cursor) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+    let Ok((mut transform, collider_size)) = paddle_query.single_mut() else {
+        return;
+    };
+
+    let bounds = PlayfieldBounds::from_config(&config);
+    let width = collider_size.0.x;
+    let target = bounds.clamp_paddle_x(cursor.x, width);
+    let current = transform.translation.x;
+    let delta = time.delta_secs();
+    let x = smoothed_paddle_x(current, target, settings.mouse_smoothing, delta);
+    let x = limit_paddle_step(current, x, config.max_paddle_speed, delta);
+    transform.translation.x = bounds.clamp_paddle_x(x, width);
+}
+
+/// Keeps a stuck ball centred on top of the paddle until it is launched.
+pub fn hold_stuck_ball(
+    paddle_query: Query<&Transform, With<Paddle>>,
+    mut ball_query: Query<&mut Transform, (With<StuckToPaddle>, Without<Paddle>)>,
+) {
+    let Ok(paddle_transform) = paddle_query.single() else {
+        return;
+    };
+
+    for mut ball_transform in &mut ball_query {
+        ball_transform.translation.x = paddle_transform.translation.x;
+    }
+}
+
+/// Returns the serve angle pointing toward the centroid of `brick_xs`: straight up for a
+/// balanced field, leaning further the more the bricks favour one side.
+pub fn assisted_serve_angle(brick_xs: impl IntoIterator<Item = f32>) -> f32 {
+    let (sum, count) = brick_xs
+        .into_iter()
+        .fold((0.0, 0), |(sum, count), x| (sum + x, count + 1));
+    if count == 0 {
+        return AIM_DEFAULT_ANGLE;
+    }
+    let centroid = sum / count as f32;
+    (centroid / (WINDOW_WIDTH / 2.0) * SERVE_ASSIST_MAX_ANGLE)
+        .clamp(-SERVE_ASSIST_MAX_ANGLE, SERVE_ASSIST_MAX_ANGLE)
+}
+
+/// Points a newly stuck ball's serve toward the bricks while the serve assist is on.
+pub fn aim_serve_at_bricks(
+    config: Res<GameConfig>,
+    mut aim: ResMut<AimAngle>,
+    stuck_query: Query<(), Added<StuckToPaddle>>,
+    brick_query: Query<&Transform, With<Brick>>,
+) {
+    if !config.serve_assist || stuck_query.is_empty() {
+        return;
+    }
+    aim.0 = assisted_serve_angle(brick_query.iter().map(|transform| transform.translation.x));
+}
+
+/// Turns the serve aim with left/right while SPACE is held over a stuck ball.
+pub fn aim_launch(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut aim: ResMut<AimAngle>,
+    stuck_query: Query<(), With<StuckToPaddle>>,
+) {
+    if stuck_query.is_empty() || !keyboard.pressed(KeyCode::Space) {
+        return;
+    }
+
+    let mut direction = 0.0;
     if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
         direction -= 1.0;
     }
     if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
         direction += 1.0;
     }
+    if direction == 0.0 {
+        return;
+    }
 
-    transform.translation.x += direction * PADDLE_SPEED * time.delta_secs();
+    aim.0 = (aim.0 + direction * AIM_TURN_SPEED * time.delta_secs())
+        .clamp(-AIM_MAX_ANGLE, AIM_MAX_ANGLE);
+}
+
+/// Serves the stuck ball along the aim when SPACE is released after the countdown, honouring a
+/// buffered early press.
+#[allow(clippy::too_many_arguments)]
+pub fn launch_ball(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut serve: ResMut<Serve>,
+    mut aim: ResMut<AimAngle>,
+    mut game_rng: ResMut<GameRng>,
+    mut commands: Commands,
+    mut ball_query: Query<(Entity, &mut Velocity), With<StuckToPaddle>>,
+) {
+    let Ok((ball, mut velocity)) = ball_query.single_mut() else {
+        return;
+    };
+
+    serve.countdown.tick(time.delta());
+    let now = time.elapsed_secs();
 
-    // Clamp within window bounds
-    let max_x = WINDOW_WIDTH / 2.0 - PADDLE_WIDTH / 2.0;
-    transform.translation.x = transform.translation.x.clamp(-max_x, max_x);
+    if keyboard.just_pressed(KeyCode::Space) {
+        serve.buffered_at = Some(now);
+    }
+
+    if !serve.countdown.is_finished() {
+        return;
+    }
+
+    let Some(pressed_at) = serve.buffered_at else {
+        return;
+    };
+
+    // Still held: the player is aiming, serve on release
+    if keyboard.pressed(KeyCode::Space) {
+        return;
+    }
+    serve.buffered_at = None;
+
+    // A tap made too long before the ball became ready is dropped, not replayed
+    if serve.countdown.just_finished() && now - pressed_at > LAUNCH_BUFFER_WINDOW {
+        return;
+    }
+
+    velocity.0 = Vec2::new(aim.0.sin(), aim.0.cos()) * velocity.0.length();
+    *aim = AimAngle::for_serve(config.random_serve, &mut game_rng);
+    commands.entity(ball).remove::<StuckToPaddle>();
+}
+
+/// Returns the ball speed multiplier at height `y`: reduced inside the paddle zone while the
+/// assist is on, full speed everywhere else.
+pub fn paddle_zone_speed(y: f32, assist: bool) -> f32 {
+    if assist && y < PADDLE_ZONE_ASSIST_Y {
+        PADDLE_ZONE_ASSIST_SPEED
+    } else {
+        1.0
+    }
+}
+
+/// Samples how fast each paddle moved this frame, for paddle friction.
+pub fn track_paddle_velocity(
+    time: Res<Time>,
+    mut query: Query<(&Transform, &mut PaddleVelocity), With<Paddle>>,
+) {
+    let delta = time.delta_secs();
+    for (transform, mut velocity) in &mut query {
+        let x = transform.translation.x;
+        velocity.x = match velocity.last_x {
+            Some(last_x) if delta > 0.0 => (x - last_x) / delta,
+            _ => 0.0,
+        };
+        velocity.last_x = Some(x);
+    }
+}
+
+/// Updates each ball's `BallSpeedModifier` for the paddle zone assist.
+pub fn apply_paddle_zone_assist(
+    settings: Res<Settings>,
+    mut query: Query<(&Transform, &mut BallSpeedModifier)>,
+) {
+    for (transform, mut modifier) in &mut query {
+        let speed = paddle_zone_speed(transform.translation.y, settings.paddle_zone_assist);
+        if modifier.0 != speed {
+            modifier.0 = speed;
+        }
+    }
 }
 
-/// Moves the ball by its velocity each frame.
-pub fn move_ball(time: Res<Time>, mut query: Query<(&mut Transform, &Ball)>) {
-    for (mut transform, ball) in &mut query {
-        transform.translation.x += ball.velocity.x * time.delta_secs();
-        transform.translation.y += ball.velocity.y * time.delta_secs();
+/// Moves every entity with a `Velocity` (balls, hazards) by its velocity each frame, scaled by
+/// any `BallSpeedModifier`.
+pub fn apply_velocity(
+    time: Res<Time>,
+    mut query: Query<
+        (&mut Transform, &Velocity, Option<&BallSpeedModifier>),
+        Without<StuckToPaddle>,
+    >,
+) {
+    // Cap the step so a long stall (e.g. a hidden browser tab) can't teleport entities
+    let delta = time.delta_secs().min(MAX_FRAME_DELTA);
+
+    for (mut transform, velocity, modifier) in &mut query {
+        let step = delta * modifier.map_or(1.0, |modifier| modifier.0);
+        transform.translation.x += velocity.0.x * step;
+        transform.translation.y += velocity.0.y * step;
     }
 }
 
@@ -43,21 +286,22 @@ mod tests {
     fn test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        app.init_resource::<Settings>();
+        app.init_resource::<GameConfig>();
         app
     }
 
-    // --- move_ball ---
+    // --- apply_velocity ---
 
     #[test]
     fn ball_moves_in_velocity_direction() {
         let mut app = test_app();
-        app.add_systems(Update, move_ball);
+        app.add_systems(Update, apply_velocity);
 
         app.world_mut().spawn((
             Transform::from_xyz(0.0, 0.0, 1.0),
-            Ball {
-                velocity: Vec2::new(100.0, 200.0),
-            },
+            Ball,
+            Velocity(Vec2::new(100.0, 200.0)),
         ));
 
         // First update initializes Time, second update has a real delta
@@ -70,6 +314,175 @@ mod tests {
         assert!(transform.translation.y > 0.0, "Ball should move up");
     }
 
+    #[test]
+    fn any_entity_moves_by_velocity_times_delta() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_velocity);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(10.0, 20.0, 0.0),
+                Velocity(Vec2::new(-30.0, 60.0)),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let delta = app.world().resource::<Time>().delta_secs();
+        let translation = app.world().get::<Transform>(entity).unwrap().translation;
+        assert!((translation.x - (10.0 - 30.0 * delta)).abs() < 0.001);
+        assert!((translation.y - (20.0 + 60.0 * delta)).abs() < 0.001);
+    }
+
+    // --- apply_paddle_zone_assist ---
+
+    #[test]
+    fn paddle_zone_assist_slows_only_low_balls() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(16),
+        ));
+        app.world_mut()
+            .resource_mut::<Settings>()
+            .paddle_zone_assist = true;
+        app.add_systems(Update, (apply_paddle_zone_assist, apply_velocity).chain());
+
+        let velocity = Vec2::new(0.0, -BALL_SPEED);
+        let high_start = PADDLE_ZONE_ASSIST_Y + 100.0;
+        let low_start = PADDLE_ZONE_ASSIST_Y - 10.0;
+        let high = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, high_start, 1.0),
+                Ball,
+                Velocity(velocity),
+            ))
+            .id();
+        let low = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, low_start, 1.0),
+                Ball,
+                Velocity(velocity),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let travelled = |entity: Entity, start: f32| {
+            start - app.world().get::<Transform>(entity).unwrap().translation.y
+        };
+        let high_travel = travelled(high, high_start);
+        let low_travel = travelled(low, low_start);
+        assert!(
+            low_travel < high_travel,
+            "Low ball moved {low_travel}, high ball {high_travel}"
+        );
+        assert!((low_travel - high_travel * PADDLE_ZONE_ASSIST_SPEED).abs() < 0.001);
+    }
+
+    #[test]
+    fn paddle_zone_speed_is_full_when_off_or_high() {
+        assert_eq!(paddle_zone_speed(PADDLE_Y, false), 1.0);
+        assert_eq!(paddle_zone_speed(PADDLE_ZONE_ASSIST_Y + 1.0, true), 1.0);
+        assert_eq!(paddle_zone_speed(PADDLE_Y, true), PADDLE_ZONE_ASSIST_SPEED);
+    }
+
+    // --- mouse_paddle ---
+
+    #[test]
+    fn mouse_smoothing_eases_toward_the_cursor() {
+        let delta = 1.0 / MOUSE_SMOOTHING_FPS;
+        let eased = smoothed_paddle_x(0.0, 100.0, MouseSmoothing(0.25), delta);
+        assert!(
+            eased > 0.0 && eased < 100.0,
+            "Paddle should approach the cursor, got {eased}"
+        );
+        assert!((eased - 25.0).abs() < 0.001);
+
+        let later = smoothed_paddle_x(eased, 100.0, MouseSmoothing(0.25), delta);
+        assert!(later > eased && later < 100.0);
+    }
+
+    #[test]
+    fn full_mouse_smoothing_snaps_to_the_cursor() {
+        let snapped = smoothed_paddle_x(0.0, 100.0, MouseSmoothing::default(), 0.001);
+        assert_eq!(snapped, 100.0);
+    }
+
+    #[test]
+    fn max_paddle_speed_limits_a_cursor_jump_per_frame() {
+        let delta = 1.0 / 60.0;
+        let max_speed = Some(MaxPaddleSpeed(PADDLE_SPEED));
+        let target = WINDOW_WIDTH / 2.0;
+
+        let mut x = -WINDOW_WIDTH / 2.0;
+        for _ in 0..10 {
+            let next = limit_paddle_step(x, target, max_speed, delta);
+            assert!(next > x, "Paddle should still head for the cursor");
+            assert!(next - x <= PADDLE_SPEED * delta + 0.001);
+            x = next;
+        }
+        assert_eq!(limit_paddle_step(x, x + 1.0, max_speed, delta), x + 1.0);
+        assert_eq!(limit_paddle_step(0.0, target, None, delta), target);
+    }
+
+    #[test]
+    fn huge_frame_delta_is_capped() {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_secs(10),
+        ));
+        app.add_systems(Update, apply_velocity);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 1.0),
+                Ball,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+
+        // First update initializes Time, second update sees the 10s stall
+        app.update();
+        app.update();
+
+        let y = app.world().get::<Transform>(entity).unwrap().translation.y;
+        assert!(y > 0.0, "Ball should still move");
+        assert!(
+            y <= BALL_SPEED * MAX_FRAME_DELTA + 0.001,
+            "Ball moved {y}, more than one capped step"
+        );
+    }
+
+    #[test]
+    fn hazard_falls_down() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_velocity);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 100.0, 0.5),
+            Hazard,
+            Velocity(Vec2::new(0.0, -HAZARD_SPEED)),
+        ));
+
+        // First update initializes Time, second update has a real delta
+        app.update();
+        app.update();
+
+        let mut q = app.world_mut().query::<(&Transform, &Hazard)>();
+        let transform = q.iter(app.world()).next().unwrap().0;
+        assert!(transform.translation.y < 100.0, "Hazard should fall");
+        assert!(
+            transform.translation.x.abs() < 0.01,
+            "Hazard should not drift sideways"
+        );
+    }
+
     // --- move_paddle ---
 
     #[test]
@@ -78,8 +491,11 @@ mod tests {
         app.init_resource::<ButtonInput<KeyCode>>();
         app.add_systems(Update, move_paddle);
 
-        app.world_mut()
-            .spawn((Transform::from_xyz(100.0, PADDLE_Y, 0.0), Paddle));
+        app.world_mut().spawn((
+            Transform::from_xyz(100.0, PADDLE_Y, 0.0),
+            Paddle,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
 
         app.update();
 
@@ -100,8 +516,11 @@ mod tests {
         let max_x = WINDOW_WIDTH / 2.0 - PADDLE_WIDTH / 2.0;
 
         // Place paddle beyond right bound
-        app.world_mut()
-            .spawn((Transform::from_xyz(max_x + 100.0, PADDLE_Y, 0.0), Paddle));
+        app.world_mut().spawn((
+            Transform::from_xyz(max_x + 100.0, PADDLE_Y, 0.0),
+            Paddle,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
 
         app.update();
 
@@ -123,8 +542,11 @@ mod tests {
         let max_x = WINDOW_WIDTH / 2.0 - PADDLE_WIDTH / 2.0;
 
         // Place paddle beyond left bound
-        app.world_mut()
-            .spawn((Transform::from_xyz(-max_x - 100.0, PADDLE_Y, 0.0), Paddle));
+        app.world_mut().spawn((
+            Transform::from_xyz(-max_x - 100.0, PADDLE_Y, 0.0),
+            Paddle,
+            ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+        ));
 
         app.update();
 
@@ -136,4 +558,276 @@ mod tests {
             transform.translation.x
         );
     }
+
+    #[test]
+    fn inverted_controls_swap_direction_and_still_clamp() {
+        let max_x = WINDOW_WIDTH / 2.0 - PADDLE_WIDTH / 2.0;
+        for (key, start_x, expect_left) in [
+            (KeyCode::ArrowRight, 0.0, true),
+            (KeyCode::ArrowLeft, 0.0, false),
+            (KeyCode::ArrowRight, -max_x, true),
+        ] {
+            let mut app = test_app();
+            app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(100),
+            ));
+            app.world_mut().resource_mut::<Settings>().invert_paddle = true;
+            app.init_resource::<ButtonInput<KeyCode>>();
+            app.add_systems(Update, move_paddle);
+            let paddle = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(start_x, PADDLE_Y, 0.0),
+                    Paddle,
+                    ColliderSize(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(key);
+
+            app.update();
+            app.update();
+
+            let x = app.world().get::<Transform>(paddle).unwrap().translation.x;
+            assert!(x >= -max_x - 0.01, "Paddle should stay in bounds, got {x}");
+            if start_x == 0.0 {
+                assert_eq!(x < 0.0, expect_left, "{key:?} moved the paddle to {x}");
+            }
+        }
+    }
+
+    // --- launch_ball ---
+
+    /// Builds an app stepping time in fixed 100ms frames with a stuck ball.
+    fn launch_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+            std::time::Duration::from_millis(100),
+        ));
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Serve>();
+        app.init_resource::<AimAngle>();
+        app.init_resource::<GameRng>();
+        app.add_systems(Update, (aim_launch, launch_ball).chain());
+
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 1.0),
+                Ball,
+                StuckToPaddle,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+        (app, ball)
+    }
+
+    /// Presses SPACE for one update, then releases it on the next.
+    fn tap_space(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        keyboard.release(KeyCode::Space);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+    }
+
+    fn is_stuck(app: &App, ball: Entity) -> bool {
+        app.world().get::<StuckToPaddle>(ball).is_some()
+    }
+
+    #[test]
+    fn buffered_press_launches_when_countdown_ends() {
+        let (mut app, ball) = launch_test_app();
+
+        // First update initializes Time; then run most of the countdown
+        for _ in 0..8 {
+            app.update();
+        }
+        tap_space(&mut app);
+        assert!(is_stuck(&app, ball), "Ball should not launch mid-countdown");
+
+        app.update();
+        app.update();
+        assert!(
+            !is_stuck(&app, ball),
+            "Buffered press should launch the ball"
+        );
+    }
+
+    #[test]
+    fn stale_press_is_dropped() {
+        let (mut app, ball) = launch_test_app();
+
+        app.update();
+        tap_space(&mut app);
+        for _ in 0..15 {
+            app.update();
+        }
+        assert!(
+            is_stuck(&app, ball),
+            "A press long before the ball was ready should not launch it"
+        );
+    }
+
+    #[test]
+    fn press_after_countdown_launches() {
+        let (mut app, ball) = launch_test_app();
+
+        for _ in 0..15 {
+            app.update();
+        }
+        assert!(is_stuck(&app, ball));
+
+        tap_space(&mut app);
+        assert!(!is_stuck(&app, ball));
+    }
+
+    #[test]
+    fn holding_space_aims_and_release_serves_along_aim() {
+        let (mut app, ball) = launch_test_app();
+        for _ in 0..15 {
+            app.update();
+        }
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.press(KeyCode::ArrowLeft);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        app.update();
+
+        let aim = app.world().resource::<AimAngle>().0;
+        assert!(
+            aim < AIM_DEFAULT_ANGLE,
+            "Holding left should turn the aim left"
+        );
+        assert!(is_stuck(&app, ball), "Ball should wait while SPACE is held");
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::ArrowLeft);
+        keyboard.release(KeyCode::Space);
+        app.update();
+
+        assert!(!is_stuck(&app, ball), "Releasing SPACE should serve");
+        let velocity = app.world().get::<Velocity>(ball).unwrap().0;
+        let expected = Vec2::new(aim.sin(), aim.cos()) * BALL_SPEED;
+        assert!((velocity - expected).length() < 0.01);
+        assert_eq!(app.world().resource::<AimAngle>().0, AIM_DEFAULT_ANGLE);
+    }
+
+    // --- aim_serve_at_bricks ---
+
+    /// Serves a ball over bricks at `brick_xs` with the serve assist on and returns its velocity.
+    fn assisted_serve(brick_xs: &[f32]) -> Vec2 {
+        let (mut app, ball) = launch_test_app();
+        app.world_mut().resource_mut::<GameConfig>().serve_assist = true;
+        app.add_systems(Update, aim_serve_at_bricks.before(aim_launch));
+        for &x in brick_xs {
+            app.world_mut()
+                .spawn((Transform::from_xyz(x, 150.0, 0.0), Brick));
+        }
+        for _ in 0..15 {
+            app.update();
+        }
+        tap_space(&mut app);
+        assert!(!is_stuck(&app, ball));
+        app.world().get::<Velocity>(ball).unwrap().0
+    }
+
+    #[test]
+    fn serve_assist_aims_toward_the_bricks() {
+        let left_heavy = assisted_serve(&[-300.0, -250.0, -200.0, 100.0]);
+        assert!(left_heavy.x < 0.0, "Serve should head left: {left_heavy}");
+        assert!(left_heavy.y > 0.0);
+
+        let balanced = assisted_serve(&[-200.0, -100.0, 100.0, 200.0]);
+        assert!(
+            balanced.x.abs() < 1.0,
+            "Serve should be near neutral: {balanced}"
+        );
+    }
+
+    #[test]
+    fn random_serves_vary_by_seed_but_keep_speed_and_climb() {
+        let mut launch_xs = Vec::new();
+        for seed in 0..8 {
+            let (mut app, ball) = launch_test_app();
+            app.world_mut().resource_mut::<GameConfig>().random_serve = true;
+            let mut game_rng = GameRng::new(seed);
+            app.insert_resource(AimAngle::for_serve(true, &mut game_rng));
+            app.insert_resource(game_rng);
+            for _ in 0..15 {
+                app.update();
+            }
+            tap_space(&mut app);
+
+            let velocity = app.world().get::<Velocity>(ball).unwrap().0;
+            assert!(velocity.y > 0.0, "Serve should head up");
+            assert!((velocity.length() - BALL_SPEED).abs() < 0.01);
+            launch_xs.push(velocity.x);
+        }
+        assert!(
+            launch_xs.iter().any(|x| (x - launch_xs[0]).abs() > 0.01),
+            "Serves should differ across seeds: {launch_xs:?}"
+        );
+    }
+
+    #[test]
+    fn aim_is_clamped_to_upward_cone() {
+        let (mut app, _) = launch_test_app();
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.press(KeyCode::ArrowRight);
+        for _ in 0..30 {
+            app.update();
+        }
+
+        assert_eq!(app.world().resource::<AimAngle>().0, AIM_MAX_ANGLE);
+    }
+
+    #[test]
+    fn aim_ignores_input_without_stuck_ball() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<AimAngle>();
+        app.add_systems(Update, aim_launch);
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::Space);
+        keyboard.press(KeyCode::ArrowRight);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<AimAngle>().0, AIM_DEFAULT_ANGLE);
+    }
+
+    #[test]
+    fn stuck_ball_does_not_move() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_velocity);
+
+        let ball = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 0.0, 1.0),
+                Ball,
+                StuckToPaddle,
+                Velocity(Vec2::new(0.0, BALL_SPEED)),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let y = app.world().get::<Transform>(ball).unwrap().translation.y;
+        assert!(y.abs() < 0.001, "Stuck ball should stay on the paddle");
+    }
 }
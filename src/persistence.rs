@@ -0,0 +1,1069 @@
+use bevy::prelude::*;
+
+use crate::components::*;
+
+/// Returns true if the flag file at `path` exists.
+pub fn read_flag(path: &std::path::Path) -> bool {
+    path.exists()
+}
+
+/// Creates the flag file at `path`, including missing parent directories.
+pub fn write_flag(path: &std::path::Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, "")
+}
+
+/// Loads the persisted tutorial flag at startup.
+pub fn load_seen_tutorial(mut seen_tutorial: ResMut<SeenTutorial>) {
+    seen_tutorial.seen = read_flag(std::path::Path::new(TUTORIAL_FLAG_PATH));
+}
+
+/// Persists the tutorial flag once it has been set.
+pub fn save_seen_tutorial(seen_tutorial: Res<SeenTutorial>) {
+    if !seen_tutorial.is_changed() || !seen_tutorial.seen {
+        return;
+    }
+    if let Err(error) = write_flag(std::path::Path::new(TUTORIAL_FLAG_PATH)) {
+        warn!("Could not save tutorial flag: {error}");
+    }
+}
+
+/// Everything needed to rebuild an in-progress run.
+#[derive(Debug, PartialEq)]
+struct RunSave {
+    score: u32,
+    lives: u32,
+    level: u32,
+    bricks: Vec<SavedBrick>,
+    /// Timed power-ups with their remaining seconds.
+    powerups: Vec<(PowerUpType, f32)>,
+    balls: Vec<SavedBall>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SavedBrick {
+    position: Vec2,
+    size: Vec2,
+    hits: u32,
+    /// Base points before the combo multiplier.
+    points: u32,
+    color: [f32; 4],
+    kind: SavedBrickKind,
+    /// Saved as a `trigger` line right after the brick's own line.
+    on_destroy: Option<OnDestroy>,
+}
+
+#[derive(Debug, PartialEq)]
+enum SavedBrickKind {
+    Plain,
+    Bomb,
+    Explosive,
+    Enemy,
+    Boss,
+    /// `partner` is the index of the linked portal in `RunSave::bricks`.
+    Portal {
+        uses_left: u32,
+        partner: usize,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+struct SavedBall {
+    position: Vec2,
+    velocity: Vec2,
+    stuck: bool,
+}
+
+/// Reasons a run save could not be loaded.
+#[derive(Debug, PartialEq)]
+enum SaveError {
+    Missing,
+    Unreadable(String),
+    Outdated(String),
+    Malformed(String),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Missing => write!(f, "no save file"),
+            SaveError::Unreadable(error) => write!(f, "could not read save: {error}"),
+            SaveError::Outdated(header) => write!(f, "unsupported save version: {header}"),
+            SaveError::Malformed(line) => write!(f, "malformed save line: {line}"),
+        }
+    }
+}
+
+/// Returns the save-file name of a power-up.
+fn powerup_name(kind: PowerUpType) -> &'static str {
+    match kind {
+        PowerUpType::WiderPaddle => "wider_paddle",
+        PowerUpType::ExtraLife => "extra_life",
+        PowerUpType::Regen => "regen",
+        PowerUpType::MultiBall => "multi_ball",
+    }
+}
+
+/// Parses a power-up from its save-file name.
+fn parse_powerup(name: &str) -> Option<PowerUpType> {
+    [
+        PowerUpType::WiderPaddle,
+        PowerUpType::ExtraLife,
+        PowerUpType::Regen,
+        PowerUpType::MultiBall,
+    ]
+    .into_iter()
+    .find(|kind| powerup_name(*kind) == name)
+}
+
+/// Serializes a scripted brick trigger as the words after `trigger`.
+fn trigger_text(trigger: &OnDestroy) -> String {
+    match trigger {
+        OnDestroy::Nothing => "nothing".to_string(),
+        OnDestroy::SpawnPowerUp(kind) => format!("powerup {}", powerup_name(*kind)),
+        OnDestroy::AddLife => "life".to_string(),
+        OnDestroy::SpawnBricks(specs) => {
+            let mut text = format!("bricks {}", specs.len());
+            for spec in specs {
+                let [r, g, b, a] = spec.color.to_srgba().to_f32_array();
+                text += &format!(
+                    " {} {} {} {} {} {r} {g} {b} {a}",
+                    spec.offset.x, spec.offset.y, spec.size.x, spec.size.y, spec.hits
+                );
+            }
+            text
+        }
+    }
+}
+
+impl RunSave {
+    /// Serializes the run as one record per line under a version header.
+    fn to_text(&self) -> String {
+        let mut lines = vec![
+            format!("breakout-save {RUN_SAVE_VERSION}"),
+            format!("score {}", self.score),
+            format!("lives {}", self.lives),
+            format!("level {}", self.level),
+        ];
+        for brick in &self.bricks {
+            let [r, g, b, a] = brick.color;
+            let kind = match brick.kind {
+                SavedBrickKind::Plain => "plain".to_string(),
+                SavedBrickKind::Bomb => "bomb".to_string(),
+                SavedBrickKind::Explosive => "explosive".to_string(),
+                SavedBrickKind::Enemy => "enemy".to_string(),
+                SavedBrickKind::Boss => "boss".to_string(),
+                SavedBrickKind::Portal { uses_left, partner } => {
+                    format!("portal {uses_left} {partner}")
+                }
+            };
+            lines.push(format!(
+                "brick {} {} {} {} {} {} {r} {g} {b} {a} {kind}",
+                brick.position.x,
+                brick.position.y,
+                brick.size.x,
+                brick.size.y,
+                brick.hits,
+                brick.points
+            ));
+            if let Some(trigger) = &brick.on_destroy {
+                lines.push(format!("trigger {}", trigger_text(trigger)));
+            }
+        }
+        for (kind, remaining) in &self.powerups {
+            lines.push(format!("powerup {} {remaining}", powerup_name(*kind)));
+        }
+        for ball in &self.balls {
+            lines.push(format!(
+                "ball {} {} {} {} {}",
+                ball.position.x,
+                ball.position.y,
+                ball.velocity.x,
+                ball.velocity.y,
+                u8::from(ball.stuck)
+            ));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    /// Parses a save written by `to_text`, rejecting other versions and any malformed line.
+    fn parse(text: &str) -> Result<Self, SaveError> {
+        let mut lines = text.lines();
+        let header = lines.next().unwrap_or_default();
+        if header != format!("breakout-save {RUN_SAVE_VERSION}") {
+            return Err(SaveError::Outdated(header.to_string()));
+        }
+
+        let (mut score, mut lives, mut level) = (None, None, None);
+        let mut bricks = Vec::new();
+        let mut powerups = Vec::new();
+        let mut balls = Vec::new();
+
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let malformed = || SaveError::Malformed(line.to_string());
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let int = |index: usize| -> Result<u32, SaveError> {
+                words
+                    .get(index)
+                    .and_then(|word| word.parse().ok())
+                    .ok_or_else(malformed)
+            };
+            let float = |index: usize| -> Result<f32, SaveError> {
+                words
+                    .get(index)
+                    .and_then(|word| word.parse::<f32>().ok())
+                    .filter(|value| value.is_finite())
+                    .ok_or_else(malformed)
+            };
+
+            let expected_len = match words[0] {
+                "score" => {
+                    score = Some(int(1)?);
+                    2
+                }
+                "lives" => {
+                    lives = Some(int(1)?);
+                    2
+                }
+                "level" => {
+                    level = Some(int(1)?);
+                    2
+                }
+                "brick" => {
+                    let (kind, len) = match words.get(11).copied() {
+                        Some("plain") => (SavedBrickKind::Plain, 12),
+                        Some("bomb") => (SavedBrickKind::Bomb, 12),
+                        Some("explosive") => (SavedBrickKind::Explosive, 12),
+                        Some("enemy") => (SavedBrickKind::Enemy, 12),
+                        Some("boss") => (SavedBrickKind::Boss, 12),
+                        Some("portal") => (
+                            SavedBrickKind::Portal {
+                                uses_left: int(12)?,
+                                partner: int(13)? as usize,
+                            },
+                            14,
+                        ),
+                        _ => return Err(malformed()),
+                    };
+                    bricks.push(SavedBrick {
+                        position: Vec2::new(float(1)?, float(2)?),
+                        size: Vec2::new(float(3)?, float(4)?),
+                        hits: int(5)?,
+                        points: int(6)?,
+                        color: [float(7)?, float(8)?, float(9)?, float(10)?],
+                        kind,
+                        on_destroy: None,
+                    });
+                    len
+                }
+                "trigger" => {
+                    let (trigger, len) = match words.get(1).copied() {
+                        Some("nothing") => (OnDestroy::Nothing, 2),
+                        Some("life") => (OnDestroy::AddLife, 2),
+                        Some("powerup") => {
+                            let kind = words
+                                .get(2)
+                                .and_then(|name| parse_powerup(name))
+                                .ok_or_else(malformed)?;
+                            (OnDestroy::SpawnPowerUp(kind), 3)
+                        }
+                        Some("bricks") => {
+                            let count = int(2)? as usize;
+                            let specs = (0..count)
+                                .map(|i| {
+                                    let at = 3 + i * 9;
+                                    Ok(TriggeredBrick {
+                                        offset: Vec2::new(float(at)?, float(at + 1)?),
+                                        size: Vec2::new(float(at + 2)?, float(at + 3)?),
+                                        hits: int(at + 4)?,
+                                        color: Color::srgba(
+                                            float(at + 5)?,
+                                            float(at + 6)?,
+                                            float(at + 7)?,
+                                            float(at + 8)?,
+                                        ),
+                                    })
+                                })
+                                .collect::<Result<Vec<_>, SaveError>>()?;
+                            (OnDestroy::SpawnBricks(specs), 3 + count * 9)
+                        }
+                        _ => return Err(malformed()),
+                    };
+                    // Belongs to the brick above it, which can only have one
+                    match bricks.last_mut() {
+                        Some(SavedBrick {
+                            on_destroy: on_destroy @ None,
+                            ..
+                        }) => *on_destroy = Some(trigger),
+                        _ => return Err(malformed()),
+                    }
+                    len
+                }
+                "powerup" => {
+                    let kind = words
+                        .get(1)
+                        .and_then(|name| parse_powerup(name))
+                        .ok_or_else(malformed)?;
+                    powerups.push((kind, float(2)?));
+                    3
+                }
+                "ball" => {
+                    let stuck = match int(5)? {
+                        0 => false,
+                        1 => true,
+                        _ => return Err(malformed()),
+                    };
+                    balls.push(SavedBall {
+                        position: Vec2::new(float(1)?, float(2)?),
+                        velocity: Vec2::new(float(3)?, float(4)?),
+                        stuck,
+                    });
+                    6
+                }
+                _ => return Err(malformed()),
+            };
+            if words.len() != expected_len {
+                return Err(malformed());
+            }
+        }
+
+        // Portals must link to another portal that exists
+        for brick in &bricks {
+            if let SavedBrickKind::Portal { partner, .. } = brick.kind
+                && !matches!(
+                    bricks.get(partner).map(|other| &other.kind),
+                    Some(SavedBrickKind::Portal { .. })
+                )
+            {
+                return Err(SaveError::Malformed(format!("portal partner {partner}")));
+            }
+        }
+
+        let missing = |field: &str| SaveError::Malformed(format!("missing {field}"));
+        Ok(Self {
+            score: score.ok_or_else(|| missing("score"))?,
+            lives: lives.ok_or_else(|| missing("lives"))?,
+            level: level.ok_or_else(|| missing("level"))?,
+            bricks,
+            powerups,
+            balls,
+        })
+    }
+}
+
+/// Reads and parses the run save at `path`.
+fn read_run(path: &std::path::Path) -> Result<RunSave, SaveError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => RunSave::parse(&text),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Err(SaveError::Missing),
+        Err(error) => Err(SaveError::Unreadable(error.to_string())),
+    }
+}
+
+/// Writes `run` to `path`, including missing parent directories.
+fn write_run(path: &std::path::Path, run: &RunSave) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, run.to_text())
+}
+
+/// Checks for a usable run save each time the menu opens; bad saves are ignored.
+pub fn refresh_saved_run(paths: Res<ExitSavePaths>, mut saved_run: ResMut<SavedRun>) {
+    saved_run.available = match read_run(&paths.run) {
+        Ok(_) => true,
+        Err(SaveError::Missing) => false,
+        Err(error) => {
+            warn!("Ignoring saved run: {error}");
+            false
+        }
+    };
+}
+
+/// Saves the run in progress when the game closes, whether through the pause menu's Quit or
+/// the window's close button. Runs in `Last`, so each exit path is seen and the save is written
+/// exactly once.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn save_run_on_exit(
+    mut app_exit: MessageReader<AppExit>,
+    mut saved: Local<bool>,
+    paths: Res<ExitSavePaths>,
+    scoreboard: Res<Scoreboard>,
+    lives: Res<Lives>,
+    level: Res<CurrentLevel>,
+    active_powerups: Res<ActivePowerUps>,
+    brick_query: Query<
+        (
+            Entity,
+            &Transform,
+            &ColliderSize,
+            Option<&BrickHealth>,
+            Option<&BrickPoints>,
+            Option<&Sprite>,
+            Option<&HitFlash>,
+            Has<BombBrick>,
+            Has<ExplosiveBrick>,
+            Has<EnemyBrick>,
+            Has<BossBrick>,
+            Option<&Portal>,
+            Option<&OnDestroy>,
+        ),
+        With<Brick>,
+    >,
+    ball_query: Query<(&Transform, &Velocity, Has<StuckToPaddle>), With<Ball>>,
+) {
+    if app_exit.read().next().is_none() || *saved {
+        return;
+    }
+    *saved = true;
+
+    let entities: Vec<Entity> = brick_query.iter().map(|(entity, ..)| entity).collect();
+    let bricks = brick_query
+        .iter()
+        .map(
+            |(
+                _,
+                transform,
+                size,
+                health,
+                points,
+                sprite,
+                flash,
+                bomb,
+                explosive,
+                enemy,
+                boss,
+                portal,
+                on_destroy,
+            )| {
+                let partner = portal.and_then(|portal| {
+                    entities
+                        .iter()
+                        .position(|entity| *entity == portal.link)
+                        .map(|index| (portal.uses_left, index))
+                });
+                let kind = if let Some((uses_left, partner)) = partner {
+                    SavedBrickKind::Portal { uses_left, partner }
+                } else if bomb {
+                    SavedBrickKind::Bomb
+                } else if explosive {
+                    SavedBrickKind::Explosive
+                } else if enemy {
+                    SavedBrickKind::Enemy
+                } else if boss {
+                    SavedBrickKind::Boss
+                } else {
+                    SavedBrickKind::Plain
+                };
+                // Save a flashing brick's settled color, not the white
+                let color = flash
+                    .map(|flash| flash.color)
+                    .or(sprite.map(|sprite| sprite.color));
+                let color = color.unwrap_or(Color::WHITE);
+                SavedBrick {
+                    position: transform.translation.truncate(),
+                    size: size.0,
+                    hits: health.map_or(1, |health| health.hits),
+                    points: points.map_or(POINTS_PER_BRICK, |points| points.0),
+                    color: color.to_srgba().to_f32_array(),
+                    kind,
+                    on_destroy: on_destroy.cloned(),
+                }
+            },
+        )
+        .collect();
+
+    let run = RunSave {
+        score: scoreboard.score,
+        lives: lives.count,
+        level: level.number,
+        bricks,
+        powerups: active_powerups
+            .timers
+            .iter()
+            .map(|(kind, timer)| (*kind, timer.remaining_secs()))
+            .collect(),
+        balls: ball_query
+            .iter()
+            .map(|(transform, velocity, stuck)| SavedBall {
+                position: transform.translation.truncate(),
+                velocity: velocity.0,
+                stuck,
+            })
+            .collect(),
+    };
+
+    if let Err(error) = write_run(&paths.run, &run) {
+        warn!("Could not save run: {error}");
+    }
+}
+
+/// Restores the saved run when C is pressed on the menu, landing on the pause screen.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn continue_saved_run(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut commands: Commands,
+    paths: Res<ExitSavePaths>,
+    mut saved_run: ResMut<SavedRun>,
+    mut scoreboard: ResMut<Scoreboard>,
+    mut lives: ResMut<Lives>,
+    mut level: ResMut<CurrentLevel>,
+    mut active_powerups: ResMut<ActivePowerUps>,
+    mut time_attack: ResMut<TimeAttack>,
+    config: Res<GameConfig>,
+    run_query: Query<
+        Entity,
+        Or<(
+            With<Brick>,
+            With<Ball>,
+            With<Hazard>,
+            With<PowerUp>,
+            With<ComboPopup>,
+        )>,
+    >,
+    demo_query: Query<(), With<AiPaddle>>,
+) {
+    // While the attract-mode demo runs, any key just leaves it
+    if !saved_run.available || !keyboard.just_pressed(KeyCode::KeyC) || !demo_query.is_empty() {
+        return;
+    }
+
+    let path = paths.run.as_path();
+    saved_run.available = false;
+    let run = match read_run(path) {
+        Ok(run) => run,
+        Err(error) => {
+            warn!("Could not continue saved run: {error}");
+            return;
+        }
+    };
+    // A run can only be continued once
+    if let Err(error) = std::fs::remove_file(path) {
+        warn!("Could not remove saved run: {error}");
+    }
+
+    for entity in &run_query {
+        commands.entity(entity).despawn();
+    }
+    spawn_saved_run(&mut commands, &run, config.ball_size.diameter());
+
+    scoreboard.score = run.score;
+    lives.count = run.lives;
+    level.number = run.level;
+    active_powerups.timers.clear();
+    for (kind, remaining) in run.powerups {
+        active_powerups.activate(kind);
+        if let Some((_, timer)) = active_powerups.timers.last_mut() {
+            let elapsed = (POWERUP_DURATION - remaining).max(0.0);
+            timer.set_elapsed(std::time::Duration::from_secs_f32(elapsed));
+        }
+    }
+    time_attack.active = false;
+    next_state.set(GameState::Paused);
+}
+
+/// Spawns the bricks and balls of a saved run, relinking portal pairs.
+fn spawn_saved_run(commands: &mut Commands, run: &RunSave, ball_size: f32) {
+    let entities: Vec<Entity> = run
+        .bricks
+        .iter()
+        .map(|brick| {
+            let [r, g, b, a] = brick.color;
+            let mut entity = commands.spawn((
+                Sprite {
+                    color: Color::srgba(r, g, b, a),
+                    custom_size: Some(brick.size),
+                    ..default()
+                },
+                Transform::from_translation(brick.position.extend(0.0)),
+                Brick,
+                BrickHealth { hits: brick.hits },
+                BrickPoints(brick.points),
+                Collider,
+                ColliderSize(brick.size),
+            ));
+            match brick.kind {
+                SavedBrickKind::Bomb => {
+                    entity.insert(BombBrick);
+                }
+                SavedBrickKind::Explosive => {
+                    entity.insert(ExplosiveBrick);
+                }
+                SavedBrickKind::Enemy => {
+                    entity.insert(EnemyBrick::default());
+                }
+                SavedBrickKind::Boss => {
+                    entity
+                        .insert(BossBrick)
+                        .with_child(crate::setup::boss_health_bar(brick.size));
+                }
+                SavedBrickKind::Plain | SavedBrickKind::Portal { .. } => {}
+            }
+            if let Some(trigger) = &brick.on_destroy {
+                entity.insert(trigger.clone());
+            }
+            entity.id()
+        })
+        .collect();
+
+    for (brick, entity) in run.bricks.iter().zip(&entities) {
+        if let SavedBrickKind::Portal { uses_left, partner } = brick.kind {
+            commands.entity(*entity).insert(Portal {
+                link: entities[partner],
+                uses_left,
+            });
+        }
+    }
+
+    for ball in run.balls.iter().take(ball_headroom(0)) {
+        let mut entity = commands.spawn((
+            Sprite {
+                color: BALL_COLOR,
+                custom_size: Some(Vec2::splat(ball_size)),
+                ..default()
+            },
+            Transform::from_translation(ball.position.extend(1.0)),
+            Ball,
+            ColliderSize(Vec2::splat(ball_size)),
+            Velocity(ball.velocity),
+            PositionHistory::default(),
+        ));
+        if ball.stuck {
+            entity.insert(StuckToPaddle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    fn sample_run() -> RunSave {
+        RunSave {
+            score: 1230,
+            lives: 2,
+            level: 3,
+            bricks: vec![
+                SavedBrick {
+                    position: Vec2::new(-126.5, 220.0),
+                    size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                    hits: 2,
+                    points: 30,
+                    color: [0.9, 0.3, 0.3, 1.0],
+                    kind: SavedBrickKind::Plain,
+                    on_destroy: Some(OnDestroy::SpawnBricks(vec![TriggeredBrick {
+                        offset: Vec2::new(0.0, -34.0),
+                        size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                        hits: 2,
+                        color: Color::srgb(0.3, 0.6, 0.9),
+                    }])),
+                },
+                SavedBrick {
+                    position: Vec2::new(0.0, 186.0),
+                    size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                    hits: 1,
+                    points: 10,
+                    color: [0.2, 0.2, 0.2, 1.0],
+                    kind: SavedBrickKind::Bomb,
+                    on_destroy: Some(OnDestroy::SpawnPowerUp(PowerUpType::ExtraLife)),
+                },
+                SavedBrick {
+                    position: Vec2::new(84.0, 152.0),
+                    size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                    hits: 1,
+                    points: 10,
+                    color: [0.5, 0.1, 0.9, 1.0],
+                    kind: SavedBrickKind::Portal {
+                        uses_left: 2,
+                        partner: 3,
+                    },
+                    on_destroy: None,
+                },
+                SavedBrick {
+                    position: Vec2::new(-84.0, 152.0),
+                    size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                    hits: 1,
+                    points: 10,
+                    color: [0.5, 0.1, 0.9, 1.0],
+                    kind: SavedBrickKind::Portal {
+                        uses_left: 3,
+                        partner: 2,
+                    },
+                    on_destroy: None,
+                },
+                SavedBrick {
+                    position: Vec2::new(42.0, 118.0),
+                    size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                    hits: 1,
+                    points: 10,
+                    color: [0.55, 0.1, 0.15, 1.0],
+                    kind: SavedBrickKind::Enemy,
+                    on_destroy: Some(OnDestroy::AddLife),
+                },
+                SavedBrick {
+                    position: Vec2::new(0.0, 80.0),
+                    size: Vec2::new(BRICK_WIDTH * 4.0, BRICK_HEIGHT * 2.0),
+                    hits: 12,
+                    points: BOSS_POINTS,
+                    color: [0.6, 0.1, 0.4, 1.0],
+                    kind: SavedBrickKind::Boss,
+                    on_destroy: None,
+                },
+            ],
+            powerups: vec![(PowerUpType::WiderPaddle, 4.25)],
+            balls: vec![SavedBall {
+                position: Vec2::new(12.5, -40.0),
+                velocity: Vec2::new(-210.0, 280.0),
+                stuck: false,
+            }],
+        }
+    }
+
+    // --- RunSave ---
+
+    #[test]
+    fn run_save_round_trips_through_text() {
+        let run = sample_run();
+        assert_eq!(RunSave::parse(&run.to_text()), Ok(run));
+    }
+
+    #[test]
+    fn run_save_round_trips_through_file() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-run")
+            .join("run.txt");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(read_run(&path), Err(SaveError::Missing));
+        assert!(write_run(&path, &sample_run()).is_ok());
+        assert_eq!(read_run(&path), Ok(sample_run()));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn rejects_outdated_save() {
+        let text = sample_run().to_text().replacen(
+            &format!("breakout-save {RUN_SAVE_VERSION}"),
+            "breakout-save 1",
+            1,
+        );
+        assert!(matches!(RunSave::parse(&text), Err(SaveError::Outdated(_))));
+    }
+
+    #[test]
+    fn rejects_malformed_saves() {
+        let valid = sample_run().to_text();
+        let broken = [
+            "not a save at all".to_string(),
+            valid.replace("score 1230", "score lots"),
+            valid.replace("lives 2\n", ""),
+            valid.replace(" plain", " glass"),
+            valid.replace("wider_paddle", "jetpack"),
+            valid.replace("portal 2 3", "portal 2 1"),
+            valid.replace("-40 -210 280 0", "-40 -210 NaN 0"),
+            valid.replace("level 3", "level 3 extra"),
+            valid.replace("trigger life", "trigger life\ntrigger life"),
+            valid.replace("trigger bricks 1", "trigger bricks 2"),
+            valid.replace("trigger powerup extra_life", "trigger powerup jetpack"),
+            valid.replacen("score 1230", "trigger life\nscore 1230", 1),
+            format!("{valid}garbage line\n"),
+        ];
+        for text in broken {
+            assert!(
+                RunSave::parse(&text).is_err(),
+                "Should reject malformed save:\n{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn continue_rebuilds_saved_bricks() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let run = sample_run();
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_saved_run(&mut commands, &run, BALL_SIZE)
+            })
+            .unwrap();
+
+        let mut q = app
+            .world_mut()
+            .query::<(&Transform, &BrickHealth, Has<BombBrick>)>();
+        let mut bricks: Vec<_> = q
+            .iter(app.world())
+            .map(|(transform, health, bomb)| (transform.translation.y, health.hits, bomb))
+            .collect();
+        bricks.sort_by(|a, b| b.0.total_cmp(&a.0));
+        assert_eq!(
+            bricks,
+            vec![
+                (220.0, 2, false),
+                (186.0, 1, true),
+                (152.0, 1, false),
+                (152.0, 1, false),
+                (118.0, 1, false),
+                (80.0, 12, false)
+            ]
+        );
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&Children, (With<BossBrick>, With<BrickHealth>)>();
+        let boss_children = q.single(app.world()).unwrap();
+        assert!(app.world().get::<BossHealthBar>(boss_children[0]).is_some());
+
+        let mut q = app.world_mut().query::<(Entity, &Portal)>();
+        let portals: Vec<_> = q.iter(app.world()).collect();
+        assert_eq!(portals.len(), 2);
+        assert_eq!(portals[0].1.link, portals[1].0);
+        assert_eq!(portals[1].1.link, portals[0].0);
+    }
+
+    #[test]
+    fn continue_caps_saved_balls() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let mut run = sample_run();
+        run.balls = (0..MAX_BALLS + 2)
+            .map(|i| SavedBall {
+                position: Vec2::new(i as f32 * 20.0, 0.0),
+                velocity: Vec2::new(0.0, BALL_SPEED),
+                stuck: false,
+            })
+            .collect();
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_saved_run(&mut commands, &run, BALL_SIZE)
+            })
+            .unwrap();
+
+        let mut q = app.world_mut().query_filtered::<(), With<Ball>>();
+        assert_eq!(q.iter(app.world()).count(), MAX_BALLS);
+    }
+
+    // --- save_run_on_exit ---
+
+    #[test]
+    fn exit_writes_the_run_save_once() {
+        let dir = std::env::temp_dir().join("breakout-rust-test-exit-run");
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = ExitSavePaths {
+            run: dir.join("run.txt"),
+            session_log: dir.join("sessions.log"),
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<AppExit>();
+        app.insert_resource(paths.clone());
+        app.insert_resource(Scoreboard {
+            score: 450,
+            ..default()
+        });
+        app.init_resource::<Lives>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<ActivePowerUps>();
+        app.add_systems(Last, save_run_on_exit);
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 200.0, 0.0),
+            Brick,
+            ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.0, 1.0),
+            Ball,
+            Velocity(Vec2::new(0.0, BALL_SPEED)),
+        ));
+
+        app.update();
+        assert_eq!(read_run(&paths.run), Err(SaveError::Missing));
+
+        // Window close button
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        let run = read_run(&paths.run).expect("Exit should write the run save");
+        assert_eq!(run.score, 450);
+        assert_eq!(run.bricks.len(), 1);
+        assert_eq!(run.balls.len(), 1);
+
+        // A second exit in the same session, e.g. Quit plus the close button, keeps the first save
+        app.world_mut().resource_mut::<Scoreboard>().score = 9999;
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        assert_eq!(read_run(&paths.run).map(|run| run.score), Ok(450));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn brick_points_survive_save_and_continue() {
+        let dir = std::env::temp_dir().join("breakout-rust-test-brick-points");
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = ExitSavePaths {
+            run: dir.join("run.txt"),
+            session_log: dir.join("sessions.log"),
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<AppExit>();
+        app.insert_resource(paths.clone());
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Lives>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<ActivePowerUps>();
+        app.add_systems(Last, save_run_on_exit);
+        for (x, points) in [(-60.0, 25), (60.0, BOSS_POINTS)] {
+            app.world_mut().spawn((
+                Transform::from_xyz(x, 200.0, 0.0),
+                Brick,
+                BrickPoints(points),
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ));
+        }
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+
+        let run = read_run(&paths.run).expect("Exit should write the run save");
+        let mut restored = App::new();
+        restored.add_plugins(MinimalPlugins);
+        restored
+            .world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_saved_run(&mut commands, &run, BALL_SIZE)
+            })
+            .unwrap();
+
+        let mut q = restored.world_mut().query::<(&Transform, &BrickPoints)>();
+        let mut points: Vec<_> = q
+            .iter(restored.world())
+            .map(|(transform, points)| (transform.translation.x, points.0))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(points, vec![(-60.0, 25), (60.0, BOSS_POINTS)]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn brick_triggers_survive_save_and_continue() {
+        let dir = std::env::temp_dir().join("breakout-rust-test-brick-triggers");
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = ExitSavePaths {
+            run: dir.join("run.txt"),
+            session_log: dir.join("sessions.log"),
+        };
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<AppExit>();
+        app.insert_resource(paths.clone());
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Lives>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<ActivePowerUps>();
+        app.add_systems(Last, save_run_on_exit);
+        let spawned = OnDestroy::SpawnBricks(vec![TriggeredBrick {
+            offset: Vec2::new(40.0, -20.0),
+            size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+            hits: 3,
+            color: Color::srgb(0.1, 0.8, 0.4),
+        }]);
+        let triggers = [
+            (-120.0, Some(OnDestroy::AddLife)),
+            (0.0, None),
+            (120.0, Some(OnDestroy::SpawnPowerUp(PowerUpType::MultiBall))),
+            (240.0, Some(spawned.clone())),
+        ];
+        for (x, trigger) in triggers.clone() {
+            let mut brick = app.world_mut().spawn((
+                Transform::from_xyz(x, 200.0, 0.0),
+                Brick,
+                ColliderSize(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
+            ));
+            if let Some(trigger) = trigger {
+                brick.insert(trigger);
+            }
+        }
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+
+        let run = read_run(&paths.run).expect("Exit should write the run save");
+        let mut restored = App::new();
+        restored.add_plugins(MinimalPlugins);
+        restored
+            .world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_saved_run(&mut commands, &run, BALL_SIZE)
+            })
+            .unwrap();
+
+        let mut q = restored
+            .world_mut()
+            .query_filtered::<(&Transform, Option<&OnDestroy>), With<Brick>>();
+        let mut restored_triggers: Vec<_> = q
+            .iter(restored.world())
+            .map(|(transform, trigger)| (transform.translation.x, trigger.cloned()))
+            .collect();
+        restored_triggers.sort_by(|a, b| a.0.total_cmp(&b.0));
+        assert_eq!(restored_triggers, triggers.to_vec());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn configured_run_save_is_offered_and_continued() {
+        let dir = std::env::temp_dir().join("breakout-rust-test-configured-run");
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = ExitSavePaths {
+            run: dir.join("run.txt"),
+            session_log: dir.join("sessions.log"),
+        };
+        assert!(write_run(&paths.run, &sample_run()).is_ok());
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_state(GameState::Menu);
+        app.insert_resource(paths.clone());
+        app.init_resource::<SavedRun>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Lives>();
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<ActivePowerUps>();
+        app.init_resource::<TimeAttack>();
+        app.init_resource::<GameConfig>();
+
+        app.world_mut().run_system_once(refresh_saved_run).unwrap();
+        assert!(app.world().resource::<SavedRun>().available);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyC);
+        app.world_mut().run_system_once(continue_saved_run).unwrap();
+        assert_eq!(app.world().resource::<Scoreboard>().score, 1230);
+        assert_eq!(read_run(&paths.run), Err(SaveError::Missing));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn flag_round_trips_through_file() {
+        let dir = std::env::temp_dir().join("breakout-rust-test-flag");
+        let path = dir.join("nested").join("flag");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(!read_flag(&path), "Missing file should read as unset");
+        assert!(write_flag(&path).is_ok());
+        assert!(read_flag(&path), "Written flag should read as set");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
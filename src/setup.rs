@@ -1,115 +1,225 @@
+use bevy::camera::{ScalingMode, Viewport};
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 
 use crate::components::*;
 
-/// Spawns the camera.
+/// Spawns the camera with a fixed projection covering exactly the logical playfield.
 pub fn spawn_camera(mut commands: Commands) {
-    commands.spawn(Camera2d);
+    commands.spawn((
+        Camera2d,
+        Camera {
+            // The configured backdrop fills the bars around the letterboxed viewport
+            clear_color: ClearColorConfig::Default,
+            ..default()
+        },
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::Fixed {
+                width: WINDOW_WIDTH,
+                height: WINDOW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+    ));
+}
+
+/// Letterboxes the camera viewport so the playfield keeps its aspect ratio in any window size.
+pub fn fit_camera_to_window(
+    window_query: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut camera_query: Query<&mut Camera, With<Camera2d>>,
+) {
+    let Ok(window) = window_query.single() else {
+        return;
+    };
+    let Ok(mut camera) = camera_query.single_mut() else {
+        return;
+    };
+
+    let letterbox = Letterbox::fit(window.physical_size().as_vec2());
+    camera.viewport = Some(Viewport {
+        physical_position: letterbox.offset.as_uvec2(),
+        physical_size: letterbox.size().as_uvec2().max(UVec2::ONE),
+        ..default()
+    });
+}
+
+/// Shrinks a configured brick layout that would overflow the playfield, warning about it.
+pub fn validate_brick_layout(mut config: ResMut<GameConfig>) {
+    // An empty grid would leave nothing to play
+    if config.bricks.rows == 0 || config.bricks.cols == 0 {
+        warn!(
+            "Brick layout {:?} has no bricks; using the default grid",
+            config.bricks
+        );
+        config.bricks = BrickLayoutConfig::default();
+    }
+    let fitted = config.fitted_bricks();
+    if fitted != config.bricks {
+        warn!(
+            "Brick layout {:?} does not fit the playfield; scaled to {:?}",
+            config.bricks, fitted
+        );
+        config.bricks = fitted;
+    }
 }
 
 /// Spawns the paddle, ball, bricks, and walls.
-pub fn spawn_game(mut commands: Commands) {
+pub fn spawn_game(mut commands: Commands, level: Res<CurrentLevel>, config: Res<GameConfig>) {
     // Paddle
+    let paddle_size = Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT);
     commands.spawn((
         Sprite {
             color: PADDLE_COLOR,
-            custom_size: Some(Vec2::new(PADDLE_WIDTH, PADDLE_HEIGHT)),
+            custom_size: Some(paddle_size),
             ..default()
         },
         Transform::from_xyz(0.0, PADDLE_Y, 0.0),
         Paddle,
         Collider,
+        ColliderSize(paddle_size),
     ));
 
     // Ball (starts just above paddle)
-    let ball_start_y = PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 + 1.0;
-    commands.spawn((
-        Sprite {
-            color: BALL_COLOR,
-            custom_size: Some(Vec2::splat(BALL_SIZE)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, ball_start_y, 1.0),
-        Ball {
-            velocity: Vec2::new(BALL_SPEED * 0.7, BALL_SPEED),
-        },
-    ));
+    spawn_ball(&mut commands, config.ball_size.diameter(), 0.0);
 
-    // Bricks
-    let grid_width = BRICK_COLS as f32 * (BRICK_WIDTH + BRICK_GAP) - BRICK_GAP;
-    let grid_start_x = -grid_width / 2.0 + BRICK_WIDTH / 2.0;
-    let grid_start_y = WINDOW_HEIGHT / 2.0 - 80.0;
+    let bounds = PlayfieldBounds::from_config(&config);
 
-    for (row, &color) in BRICK_COLORS.iter().enumerate().take(BRICK_ROWS) {
-        for col in 0..BRICK_COLS {
-            let x = grid_start_x + col as f32 * (BRICK_WIDTH + BRICK_GAP);
-            let y = grid_start_y - row as f32 * (BRICK_HEIGHT + BRICK_GAP);
+    // Bricks (layout shrinks to fit between the side walls; rows hang from the window top, not
+    // the top wall, so they line up with the editor's grid)
+    let bricks = config.fitted_bricks();
+    let layout = BrickGridLayout::from_config(&bricks);
+    let grid_start_y = WINDOW_HEIGHT / 2.0 - bricks.top_margin;
+    let brick_size = Vec2::new(layout.brick_width, bricks.brick_height);
+    let row_y = |row: usize| grid_start_y - row as f32 * (bricks.brick_height + bricks.gap);
 
-            commands.spawn((
-                Sprite {
-                    color,
-                    custom_size: Some(Vec2::new(BRICK_WIDTH, BRICK_HEIGHT)),
-                    ..default()
-                },
-                Transform::from_xyz(x, y, 0.0),
-                Brick,
-                Collider,
-            ));
+    // The boss takes over a block of cells in the middle of the grid
+    let boss = boss_cells(bricks.rows, layout.cols)
+        .filter(|_| config.boss && config.practice_row.is_none());
+    if let Some((rows, cols)) = &boss {
+        let first = Vec2::new(layout.column_x(cols.start), row_y(rows.start));
+        let last = Vec2::new(layout.column_x(cols.end - 1), row_y(rows.end - 1));
+        let size = Vec2::new(
+            BOSS_COLS as f32 * (layout.brick_width + bricks.gap) - bricks.gap,
+            BOSS_ROWS as f32 * (bricks.brick_height + bricks.gap) - bricks.gap,
+        );
+        spawn_boss(&mut commands, (first + last) / 2.0, size, BOSS_HEALTH);
+    }
+
+    for row in 0..bricks.rows {
+        // Practice drills a single row
+        if config.practice_row.is_some_and(|practice| practice != row) {
+            continue;
+        }
+        for col in 0..layout.cols {
+            if boss
+                .as_ref()
+                .is_some_and(|(rows, cols)| rows.contains(&row) && cols.contains(&col))
+            {
+                continue;
+            }
+            let spec = BrickSpec {
+                row,
+                position: Vec2::new(layout.column_x(col), row_y(row)),
+                size: brick_size,
+                hits: brick_health(level.number, row, bricks.rows),
+                kind: BrickKind::Plain,
+            };
+            let brick = spawn_brick(&mut commands, &spec, config.row_points);
+            if let Some((rows, cols)) = &boss
+                && let Some(trigger) = boss_flank_trigger(row, col, rows, cols, brick_size)
+            {
+                commands.entity(brick).insert(trigger);
+            }
         }
     }
 
-    // Walls (top, left, right — bottom is the death zone)
-    let half_w = WINDOW_WIDTH / 2.0;
-    let half_h = WINDOW_HEIGHT / 2.0;
+    // Walls (top, left, right — the bottom depends on `BottomMode`)
+    let thickness = bounds.wall_thickness;
+    let top_wall_size = Vec2::new(bounds.size().x + thickness * 2.0, thickness);
+    let side_wall_size = Vec2::new(thickness, bounds.size().y + thickness * 2.0);
+    let side_wall_y = (bounds.top + bounds.bottom) / 2.0;
 
     // Top wall
     commands.spawn((
         Sprite {
             color: WALL_COLOR,
-            custom_size: Some(Vec2::new(
-                WINDOW_WIDTH + WALL_THICKNESS * 2.0,
-                WALL_THICKNESS,
-            )),
+            custom_size: Some(top_wall_size),
             ..default()
         },
-        Transform::from_xyz(0.0, half_h + WALL_THICKNESS / 2.0, 0.0),
+        Transform::from_xyz(0.0, bounds.top + thickness / 2.0, 0.0),
         Wall,
         Collider,
+        ColliderSize(top_wall_size),
     ));
 
     // Left wall
     commands.spawn((
         Sprite {
             color: WALL_COLOR,
-            custom_size: Some(Vec2::new(
-                WALL_THICKNESS,
-                WINDOW_HEIGHT + WALL_THICKNESS * 2.0,
-            )),
+            custom_size: Some(side_wall_size),
             ..default()
         },
-        Transform::from_xyz(-half_w - WALL_THICKNESS / 2.0, 0.0, 0.0),
+        Transform::from_xyz(bounds.left - thickness / 2.0, side_wall_y, 0.0),
         Wall,
         Collider,
+        ColliderSize(side_wall_size),
     ));
 
     // Right wall
     commands.spawn((
         Sprite {
             color: WALL_COLOR,
-            custom_size: Some(Vec2::new(
-                WALL_THICKNESS,
-                WINDOW_HEIGHT + WALL_THICKNESS * 2.0,
-            )),
+            custom_size: Some(side_wall_size),
+            ..default()
+        },
+        Transform::from_xyz(bounds.right + thickness / 2.0, side_wall_y, 0.0),
+        Wall,
+        Collider,
+        ColliderSize(side_wall_size),
+    ));
+
+    spawn_bottom_wall(&mut commands, config.bottom_mode, bounds);
+
+    // Danger line (shown by `sync_danger_line` when the setting is on)
+    commands.spawn((
+        Sprite {
+            color: DANGER_LINE_COLOR,
+            custom_size: Some(Vec2::new(bounds.size().x, DANGER_LINE_HEIGHT)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, bounds.danger_line_y(), -0.5),
+        Visibility::Hidden,
+        DangerLine,
+    ));
+}
+
+/// Spawns the bottom wall (or one-bounce shield) the mode calls for; the death zone has none.
+pub fn spawn_bottom_wall(commands: &mut Commands, mode: BottomMode, bounds: PlayfieldBounds) {
+    if mode == BottomMode::DeathZone {
+        return;
+    }
+
+    let thickness = bounds.wall_thickness;
+    let size = Vec2::new(bounds.size().x + thickness * 2.0, thickness);
+    let mut wall = commands.spawn((
+        Sprite {
+            color: WALL_COLOR,
+            custom_size: Some(size),
             ..default()
         },
-        Transform::from_xyz(half_w + WALL_THICKNESS / 2.0, 0.0, 0.0),
+        Transform::from_xyz(0.0, bounds.bottom - thickness / 2.0, 0.0),
         Wall,
+        BottomWall,
         Collider,
+        ColliderSize(size),
     ));
+    if mode == BottomMode::OneBounceShield {
+        wall.insert(BottomShield);
+    }
 }
 
-/// Spawns the HUD: score (top-left) and lives (top-right).
-pub fn spawn_ui(mut commands: Commands) {
+/// Spawns the HUD: score (top-left), lives (top-right), and the hidden landing and aim markers.
+pub fn spawn_ui(mut commands: Commands, config: Res<GameConfig>) {
     // Score text
     commands.spawn((
         Text::new("Score: 0"),
@@ -143,12 +253,410 @@ pub fn spawn_ui(mut commands: Commands) {
         },
         LivesUi,
     ));
+
+    // Time attack countdown (empty outside time attack)
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        UI_Z_COUNTDOWN,
+        TimerUi,
+    ));
+
+    // Brick color-to-points legend (shown only when enabled in settings)
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(44.0),
+                left: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            ScoreLegendUi,
+        ))
+        .with_children(|parent| {
+            for (row, (color, points)) in score_legend_entries(config.row_points)
+                .into_iter()
+                .enumerate()
+            {
+                parent
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(6.0),
+                        ..default()
+                    })
+                    .with_children(|entry| {
+                        entry.spawn((
+                            Node {
+                                width: Val::Px(16.0),
+                                height: Val::Px(8.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ));
+                        entry.spawn((
+                            Text::new(points.to_string()),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            ScoreLegendPoints(row),
+                        ));
+                    });
+            }
+        });
+
+    // Landing indicator (assist, shown only when enabled in settings)
+    commands.spawn((
+        Sprite {
+            color: LANDING_INDICATOR_COLOR,
+            custom_size: Some(Vec2::new(BALL_SIZE, PADDLE_HEIGHT)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, PADDLE_Y, 0.1),
+        Visibility::Hidden,
+        LandingIndicator,
+    ));
+
+    // Serve aim arrow (shown while the ball waits on the paddle)
+    commands.spawn((
+        Sprite {
+            color: AIM_ARROW_COLOR,
+            custom_size: Some(Vec2::new(3.0, AIM_ARROW_LENGTH)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, PADDLE_Y, 1.0),
+        Visibility::Hidden,
+        AimArrow,
+    ));
+}
+
+/// Pairs each brick row color with the base points its bricks are worth.
+pub fn score_legend_entries(row_points: bool) -> Vec<(Color, u32)> {
+    BRICK_COLORS
+        .into_iter()
+        .enumerate()
+        .map(|(row, color)| (color, brick_row_points(row, row_points)))
+        .collect()
+}
+
+/// Kind of brick a `BrickSpec` places.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BrickKind {
+    #[default]
+    Plain,
+    Bomb,
+    Explosive,
+    Enemy,
+    /// Linked to the next portal spawned with it, in order.
+    Portal,
+}
+
+impl BrickKind {
+    /// Returns the color of a full-health brick of this kind in `row`.
+    pub fn color(self, row: usize) -> Color {
+        match self {
+            BrickKind::Plain => BRICK_COLORS[row % BRICK_COLORS.len()],
+            BrickKind::Bomb => BOMB_BRICK_COLOR,
+            BrickKind::Explosive => EXPLOSIVE_BRICK_COLOR,
+            BrickKind::Enemy => ENEMY_BRICK_COLOR,
+            BrickKind::Portal => PORTAL_BRICK_COLOR,
+        }
+    }
+}
+
+/// One brick of a layout, generated or loaded from a level file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrickSpec {
+    /// Grid row, which picks the brick's color and points.
+    pub row: usize,
+    pub position: Vec2,
+    pub size: Vec2,
+    pub hits: u32,
+    pub kind: BrickKind,
+}
+
+/// Spawns the brick a spec describes; portals are left for `link_portals` to pair up.
+pub fn spawn_brick(commands: &mut Commands, spec: &BrickSpec, row_points: bool) -> Entity {
+    let mut brick = commands.spawn((
+        Sprite {
+            color: brick_health_color(spec.kind.color(spec.row), spec.hits),
+            custom_size: Some(spec.size),
+            ..default()
+        },
+        Transform::from_translation(spec.position.extend(0.0)),
+        Brick,
+        BrickPoints(brick_row_points(spec.row, row_points)),
+        BrickHealth { hits: spec.hits },
+        Collider,
+        ColliderSize(spec.size),
+    ));
+    match spec.kind {
+        BrickKind::Bomb => {
+            brick.insert(BombBrick);
+        }
+        BrickKind::Explosive => {
+            brick.insert(ExplosiveBrick);
+        }
+        BrickKind::Enemy => {
+            brick.insert(EnemyBrick::default());
+        }
+        BrickKind::Plain | BrickKind::Portal => {}
+    }
+    brick.id()
+}
+
+/// Spawns every brick of a layout, linking its portals in pairs.
+pub fn spawn_bricks(commands: &mut Commands, specs: &[BrickSpec], row_points: bool) {
+    let portals: Vec<Entity> = specs
+        .iter()
+        .filter_map(|spec| {
+            let brick = spawn_brick(commands, spec, row_points);
+            (spec.kind == BrickKind::Portal).then_some(brick)
+        })
+        .collect();
+    link_portals(commands, &portals);
+}
+
+/// Links portal bricks to each other in pairs, in order; an odd one out stays a plain brick.
+pub fn link_portals(commands: &mut Commands, portals: &[Entity]) {
+    for pair in portals.chunks(2) {
+        if let [a, b] = *pair {
+            commands.entity(a).insert(Portal {
+                link: b,
+                uses_left: PORTAL_USES,
+            });
+            commands.entity(b).insert(Portal {
+                link: a,
+                uses_left: PORTAL_USES,
+            });
+        }
+    }
+}
+
+/// Returns the chance a grid cell holds a brick and the chance a brick takes extra hits.
+fn random_layout_odds(difficulty: Difficulty) -> (f32, f32) {
+    match difficulty {
+        Difficulty::Easy => (0.55, 0.05),
+        Difficulty::Normal => (0.7, 0.12),
+        Difficulty::Hard => (0.85, 0.25),
+    }
+}
+
+/// Generates a brick layout on the configured grid from `rng`, denser and tougher on harder
+/// difficulties, with the odd empty column. Bricks stay between the walls and clear of the
+/// paddle, and there is always at least one.
+pub fn generate_random_layout(
+    rng: &mut SeededRng,
+    difficulty: Difficulty,
+    config: &GameConfig,
+) -> Vec<BrickSpec> {
+    let bricks = config.fitted_bricks();
+    let layout = BrickGridLayout::from_config(&bricks);
+    let size = Vec2::new(layout.brick_width, bricks.brick_height);
+    let (fill_chance, tough_chance) = random_layout_odds(difficulty);
+    let cell = |row: usize, col: usize| {
+        Vec2::new(
+            layout.column_x(col),
+            WINDOW_HEIGHT / 2.0
+                - bricks.top_margin
+                - row as f32 * (bricks.brick_height + bricks.gap),
+        )
+    };
+
+    let mut specs = Vec::new();
+    for col in 0..layout.cols {
+        if rng.chance(RANDOM_LAYOUT_GAP_CHANCE) {
+            continue;
+        }
+        for row in 0..bricks.rows {
+            if !rng.chance(fill_chance) {
+                continue;
+            }
+            let hits = if rng.chance(tough_chance) {
+                rng.range_u32(2..=MAX_BRICK_HEALTH)
+            } else {
+                1
+            };
+            specs.push(BrickSpec {
+                row,
+                position: cell(row, col),
+                size,
+                hits,
+                kind: BrickKind::Plain,
+            });
+        }
+    }
+
+    if specs.is_empty() && bricks.rows > 0 && layout.cols > 0 {
+        specs.push(BrickSpec {
+            row: bricks.rows - 1,
+            position: cell(bricks.rows - 1, layout.cols / 2),
+            size,
+            hits: 1,
+            kind: BrickKind::Plain,
+        });
+    }
+    specs
+}
+
+/// Swaps the level's bricks for a layout generated from the run's seed when the random layout
+/// option is on; runs as play starts, after the seed is rolled.
+pub fn spawn_random_layout(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    settings: Res<Settings>,
+    mut game_rng: ResMut<GameRng>,
+    brick_query: Query<Entity, With<Brick>>,
+) {
+    if !config.random_layout || config.practice_row.is_some() {
+        return;
+    }
+    for entity in &brick_query {
+        commands.entity(entity).despawn();
+    }
+
+    let specs = generate_random_layout(&mut game_rng.rng, settings.difficulty, &config);
+    spawn_bricks(&mut commands, &specs, config.row_points);
+}
+
+/// Returns the grid rows and columns the boss covers, or `None` if the grid is too small for it.
+fn boss_cells(
+    rows: usize,
+    cols: usize,
+) -> Option<(std::ops::Range<usize>, std::ops::Range<usize>)> {
+    if rows < BOSS_FIRST_ROW + BOSS_ROWS || cols < BOSS_COLS {
+        return None;
+    }
+    let first_col = (cols - BOSS_COLS) / 2;
+    Some((
+        BOSS_FIRST_ROW..BOSS_FIRST_ROW + BOSS_ROWS,
+        first_col..first_col + BOSS_COLS,
+    ))
+}
+
+/// Returns the scripted effect of the brick at (`row`, `col`) beside a boss covering `rows` and
+/// `cols`: the top flankers drop a wider paddle (left) and an extra life (right), and the bottom
+/// flankers rebuild once as a single-hit brick.
+fn boss_flank_trigger(
+    row: usize,
+    col: usize,
+    rows: &std::ops::Range<usize>,
+    cols: &std::ops::Range<usize>,
+    brick_size: Vec2,
+) -> Option<OnDestroy> {
+    let left = col + 1 == cols.start;
+    if !left && col != cols.end {
+        return None;
+    }
+    if row == rows.start {
+        Some(if left {
+            OnDestroy::SpawnPowerUp(PowerUpType::WiderPaddle)
+        } else {
+            OnDestroy::AddLife
+        })
+    } else if row + 1 == rows.end {
+        Some(OnDestroy::SpawnBricks(vec![TriggeredBrick {
+            offset: Vec2::ZERO,
+            size: brick_size,
+            hits: 1,
+            color: REGEN_BRICK_COLOR,
+        }]))
+    } else {
+        None
+    }
+}
+
+/// Spawns a boss brick of the given size with `hits` health left, with its health bar above it.
+pub fn spawn_boss(commands: &mut Commands, position: Vec2, size: Vec2, hits: u32) -> Entity {
+    commands
+        .spawn((
+            Sprite {
+                color: BOSS_COLOR,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_translation(position.extend(0.0)),
+            Brick,
+            BossBrick,
+            BrickPoints(BOSS_POINTS),
+            BrickHealth { hits },
+            Collider,
+            ColliderSize(size),
+        ))
+        .with_child(boss_health_bar(size))
+        .id()
+}
+
+/// Returns a full health bar to attach as a child of a boss of the given size.
+pub fn boss_health_bar(boss_size: Vec2) -> impl Bundle {
+    (
+        Sprite {
+            color: BOSS_BAR_COLOR,
+            custom_size: Some(Vec2::new(boss_size.x, BOSS_BAR_HEIGHT)),
+            ..default()
+        },
+        Transform::from_xyz(
+            0.0,
+            boss_size.y / 2.0 + BOSS_BAR_GAP + BOSS_BAR_HEIGHT / 2.0,
+            0.1,
+        ),
+        BossHealthBar,
+    )
+}
+
+/// Spawns a ball of the given diameter just above the paddle line at `x`, heading up and right.
+pub fn spawn_ball(commands: &mut Commands, diameter: f32, x: f32) -> Entity {
+    let ball_size = Vec2::splat(diameter);
+    let ball_start_y = PADDLE_Y + PADDLE_HEIGHT / 2.0 + ball_size.y / 2.0 + 1.0;
+    commands
+        .spawn((
+            Sprite {
+                color: BALL_COLOR,
+                custom_size: Some(ball_size),
+                ..default()
+            },
+            Transform::from_xyz(x, ball_start_y, 1.0),
+            Ball,
+            ColliderSize(ball_size),
+            Velocity(Vec2::new(BALL_SPEED * 0.7, BALL_SPEED)),
+            PositionHistory::default(),
+        ))
+        .id()
 }
 
 /// Spawns the menu overlay text.
-pub fn spawn_menu(mut commands: Commands) {
+pub fn spawn_menu(
+    mut commands: Commands,
+    saved_run: Res<SavedRun>,
+    settings: Res<Settings>,
+    config: Res<GameConfig>,
+    leaderboard: Res<Leaderboard>,
+) {
+    let mut text =
+        "BREAKOUT\n\nPress SPACE to start\nPress T for time attack\nPress E for level editor"
+            .to_string();
+    if saved_run.available {
+        text.push_str("\nPress C to continue");
+    }
+
     commands.spawn((
-        Text::new("BREAKOUT\n\nPress SPACE to start"),
+        Text::new(text),
         TextFont {
             font_size: 40.0,
             ..default()
@@ -156,14 +664,97 @@ pub fn spawn_menu(mut commands: Commands) {
         TextColor(Color::WHITE),
         TextLayout::new_with_justify(Justify::Center),
         Node {
-            position_type: PositionType::Absolute,
             top: Val::Percent(35.0),
-            width: Val::Percent(100.0),
-            justify_content: JustifyContent::Center,
+            ..overlay_node()
+        },
+        UI_Z_OVERLAY,
+        OverlayUi,
+    ));
+
+    commands.spawn((
+        Text::new(frame_limit_text(settings.frame_limit)),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            bottom: Val::Px(30.0),
+            ..overlay_node()
+        },
+        FrameLimitUi,
+        UI_Z_OVERLAY,
+        OverlayUi,
+    ));
+
+    commands.spawn((
+        Text::new(practice_row_text(config.practice_row)),
+        TextFont {
+            font_size: 20.0,
             ..default()
         },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            bottom: Val::Px(60.0),
+            ..overlay_node()
+        },
+        PracticeRowUi,
+        UI_Z_OVERLAY,
         OverlayUi,
     ));
+
+    if !leaderboard.entries.is_empty() {
+        commands.spawn((
+            Text::new(crate::stats::leaderboard_table(
+                &leaderboard,
+                &settings.score_format,
+            )),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            TextLayout::new_with_justify(Justify::Center),
+            Node {
+                top: Val::Px(12.0),
+                ..overlay_node()
+            },
+            LeaderboardUi,
+            UI_Z_OVERLAY,
+            OverlayUi,
+        ));
+    }
+}
+
+/// Returns a horizontally centred, absolutely positioned overlay node with a bounded width and
+/// side padding, so its text wraps instead of clipping on narrow windows.
+pub fn overlay_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.0),
+        right: Val::Px(0.0),
+        width: Val::Percent(100.0),
+        max_width: Val::Px(OVERLAY_MAX_WIDTH),
+        margin: UiRect::horizontal(Val::Auto),
+        padding: UiRect::horizontal(Val::Px(OVERLAY_SIDE_PADDING)),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }
+}
+
+/// Returns the menu line describing the frame limit setting.
+pub fn frame_limit_text(frame_limit: FrameLimit) -> String {
+    format!("Frame limit: {} (press V to change)", frame_limit.label())
+}
+
+/// Returns the menu line describing the practice row setting, counting rows from 1.
+pub fn practice_row_text(practice_row: Option<usize>) -> String {
+    match practice_row {
+        Some(row) => format!("Practice row: {} (press P to change)", row + 1),
+        None => "Practice row: off (press P to change)".to_string(),
+    }
 }
 
 /// Removes the overlay UI (used on state transitions).
@@ -173,32 +764,159 @@ pub fn despawn_overlay(mut commands: Commands, query: Query<Entity, With<Overlay
     }
 }
 
-/// Resets ball and paddle positions when entering Playing state.
+/// Resets ball and paddle positions and holds the ball for a serve when entering Playing state.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn reset_ball_and_paddle(
+    mut commands: Commands,
+    config: Res<GameConfig>,
+    mut game_rng: ResMut<GameRng>,
+    mut aim: ResMut<AimAngle>,
+    mut serve: ResMut<Serve>,
+    mut combo: ResMut<Combo>,
     mut paddle_query: Query<&mut Transform, With<Paddle>>,
-    mut ball_query: Query<(&mut Transform, &mut Ball), Without<Paddle>>,
+    mut ball_query: Query<
+        (Entity, &mut Transform, &mut Velocity, &ColliderSize),
+        (With<Ball>, Without<Paddle>),
+    >,
 ) {
     if let Ok(mut paddle_transform) = paddle_query.single_mut() {
         paddle_transform.translation.x = 0.0;
     }
 
-    if let Ok((mut ball_transform, mut ball)) = ball_query.single_mut() {
+    if let Ok((ball, mut ball_transform, mut ball_velocity, ball_collider)) =
+        ball_query.single_mut()
+    {
         ball_transform.translation.x = 0.0;
-        ball_transform.translation.y = PADDLE_Y + PADDLE_HEIGHT / 2.0 + BALL_SIZE / 2.0 + 1.0;
-        ball.velocity = Vec2::new(BALL_SPEED * 0.7, BALL_SPEED);
+        ball_transform.translation.y =
+            PADDLE_Y + PADDLE_HEIGHT / 2.0 + ball_collider.0.y / 2.0 + 1.0;
+        ball_velocity.0 = Vec2::new(BALL_SPEED * 0.7, BALL_SPEED);
+        commands.entity(ball).insert(StuckToPaddle);
+        *serve = Serve::default();
+        *aim = AimAngle::for_serve(config.random_serve, &mut game_rng);
+        combo.streak = 0;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy::ecs::system::RunSystemOnce;
 
     fn test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        app.init_resource::<CurrentLevel>();
+        app.init_resource::<GameConfig>();
+        app.init_resource::<Serve>();
+        app.init_resource::<Combo>();
         app
     }
 
+    // --- validate_brick_layout ---
+
+    #[test]
+    fn oversized_layout_is_scaled_into_playfield() {
+        let mut app = test_app();
+        app.insert_resource(GameConfig {
+            bricks: BrickLayoutConfig {
+                cols: 16,
+                rows: 14,
+                brick_width: 120.0,
+                brick_height: 40.0,
+                gap: 10.0,
+                top_margin: BRICK_GRID_TOP_MARGIN,
+                offset_x: 0.0,
+            },
+            ..default()
+        });
+        app.add_systems(Startup, (validate_brick_layout, spawn_game).chain());
+        app.update();
+
+        let config = app.world().resource::<GameConfig>().bricks;
+        assert!(config.brick_width < 120.0, "Bricks should shrink to fit");
+
+        let half_width = config.brick_width / 2.0;
+        let half_height = config.brick_height / 2.0;
+        let mut q = app.world_mut().query_filtered::<&Transform, With<Brick>>();
+        let positions: Vec<Vec3> = q.iter(app.world()).map(|t| t.translation).collect();
+        assert_eq!(positions.len(), 16 * 14);
+        for position in positions {
+            assert!(position.x - half_width >= -WINDOW_WIDTH / 2.0 + WALL_THICKNESS - 0.01);
+            assert!(position.x + half_width <= WINDOW_WIDTH / 2.0 - WALL_THICKNESS + 0.01);
+            assert!(position.y + half_height <= WINDOW_HEIGHT / 2.0);
+            assert!(position.y - half_height >= PADDLE_Y + BRICK_PADDLE_CLEARANCE - 0.01);
+        }
+    }
+
+    #[test]
+    fn grid_offset_shifts_bricks_but_stays_inside_walls() {
+        let brick_xs = |offset_x: f32| {
+            let mut app = test_app();
+            app.insert_resource(GameConfig {
+                bricks: BrickLayoutConfig {
+                    offset_x,
+                    ..default()
+                },
+                ..default()
+            });
+            app.add_systems(Startup, (validate_brick_layout, spawn_game).chain());
+            app.update();
+            let mut q = app
+                .world_mut()
+                .query_filtered::<&Transform, (With<Brick>, Without<BossBrick>)>();
+            let mut xs: Vec<f32> = q.iter(app.world()).map(|t| t.translation.x).collect();
+            xs.sort_by(f32::total_cmp);
+            xs
+        };
+        let centered = brick_xs(0.0);
+        let layout = BrickGridLayout::from_config(&BrickLayoutConfig::default());
+        let max_offset = (WINDOW_WIDTH - WALL_THICKNESS * 2.0 - layout.width()) / 2.0;
+
+        let shifted = brick_xs(max_offset / 2.0);
+        for (x, centered_x) in shifted.iter().zip(&centered) {
+            assert!((x - centered_x - max_offset / 2.0).abs() < 0.01);
+        }
+
+        // An offset past the wall is clamped to leave the grid against it
+        let right_wall = WINDOW_WIDTH / 2.0 - WALL_THICKNESS;
+        let clamped = brick_xs(WINDOW_WIDTH);
+        let rightmost = clamped.last().unwrap() + layout.brick_width / 2.0;
+        assert!((rightmost - right_wall).abs() < 0.01, "got {rightmost}");
+    }
+
+    #[test]
+    fn empty_layout_falls_back_to_the_default_grid() {
+        let mut app = test_app();
+        app.insert_resource(GameConfig {
+            bricks: BrickLayoutConfig {
+                rows: 0,
+                ..default()
+            },
+            ..default()
+        });
+        app.add_systems(Startup, (validate_brick_layout, spawn_game).chain());
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<GameConfig>().bricks,
+            BrickLayoutConfig::default()
+        );
+        let mut q = app.world_mut().query_filtered::<(), With<Brick>>();
+        assert_eq!(q.iter(app.world()).count(), BRICK_COLS * BRICK_ROWS);
+    }
+
+    #[test]
+    fn fitting_layout_is_left_unchanged() {
+        let mut app = test_app();
+        app.add_systems(Startup, validate_brick_layout);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<GameConfig>().bricks,
+            BrickLayoutConfig::default()
+        );
+    }
+
     // --- spawn_game ---
 
     #[test]
@@ -238,6 +956,173 @@ mod tests {
         );
     }
 
+    #[test]
+    fn practice_mode_spawns_only_the_selected_row() {
+        let mut app = test_app();
+        app.insert_resource(GameConfig {
+            practice_row: Some(2),
+            boss: true,
+            ..default()
+        });
+        app.add_systems(Startup, spawn_game);
+        app.update();
+
+        let row_y = WINDOW_HEIGHT / 2.0 - BRICK_GRID_TOP_MARGIN - 2.0 * (BRICK_HEIGHT + BRICK_GAP);
+        let mut q = app.world_mut().query_filtered::<&Transform, With<Brick>>();
+        let ys: Vec<f32> = q.iter(app.world()).map(|t| t.translation.y).collect();
+        assert_eq!(ys.len(), BRICK_COLS, "Only one row of bricks, and no boss");
+        assert!(ys.iter().all(|y| (y - row_y).abs() < 0.01));
+    }
+
+    // --- generate_random_layout ---
+
+    #[test]
+    fn same_seed_generates_the_same_layout() {
+        let config = GameConfig::default();
+        let layout = |seed: u64| {
+            generate_random_layout(&mut GameRng::new(seed).rng, Difficulty::Normal, &config)
+        };
+        assert_eq!(layout(7), layout(7));
+        assert!((0..20).any(|seed| layout(seed) != layout(7)));
+    }
+
+    #[test]
+    fn random_bricks_stay_inside_the_playfield() {
+        let config = GameConfig {
+            wall_thickness: WallThickness(WALL_THICKNESS + 20.0),
+            ..default()
+        };
+        let bounds = PlayfieldBounds::from_config(&config);
+        for seed in 0..50 {
+            for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+                let specs =
+                    generate_random_layout(&mut GameRng::new(seed).rng, difficulty, &config);
+                assert!(!specs.is_empty());
+                for spec in specs {
+                    let rect = Rect::from_center_size(spec.position, spec.size);
+                    assert!(rect.min.x >= bounds.left - 0.01 && rect.max.x <= bounds.right + 0.01);
+                    assert!(rect.max.y <= bounds.top + 0.01);
+                    assert!(rect.min.y >= PADDLE_Y + BRICK_PADDLE_CLEARANCE - 0.01);
+                    assert!((1..=MAX_BRICK_HEALTH).contains(&spec.hits));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enemy_bricks_come_only_from_the_layout() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_game);
+        app.update();
+        let mut q = app.world_mut().query::<(&Brick, &EnemyBrick)>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "The default grid has no enemies"
+        );
+
+        let mut app = spawn_kinds(&[BrickKind::Enemy, BrickKind::Plain, BrickKind::Enemy]);
+        let mut q = app.world_mut().query::<(&Brick, &EnemyBrick)>();
+        assert_eq!(q.iter(app.world()).count(), 2);
+    }
+
+    /// Spawns one brick of each kind in `kinds` through `spawn_bricks`.
+    fn spawn_kinds(kinds: &[BrickKind]) -> App {
+        let mut app = test_app();
+        let specs: Vec<BrickSpec> = kinds
+            .iter()
+            .enumerate()
+            .map(|(i, &kind)| BrickSpec {
+                row: 0,
+                position: Vec2::new(i as f32 * 80.0, 200.0),
+                size: Vec2::new(BRICK_WIDTH, BRICK_HEIGHT),
+                hits: 1,
+                kind,
+            })
+            .collect();
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_bricks(&mut commands, &specs, false)
+            })
+            .unwrap();
+        app
+    }
+
+    #[test]
+    fn bomb_bricks_come_only_from_the_layout() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_game);
+        app.update();
+        let mut q = app.world_mut().query::<(&Brick, &BombBrick)>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "The default grid has no bombs"
+        );
+
+        let mut app = spawn_kinds(&[BrickKind::Plain, BrickKind::Bomb]);
+        let mut q = app.world_mut().query::<(&Brick, &BombBrick)>();
+        assert_eq!(q.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn explosive_bricks_come_only_from_the_layout() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_game);
+        app.update();
+        let mut q = app.world_mut().query::<(&Brick, &ExplosiveBrick)>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "The default grid has no explosives"
+        );
+
+        let mut app = spawn_kinds(&[BrickKind::Explosive, BrickKind::Plain]);
+        let mut q = app.world_mut().query::<(&Brick, &ExplosiveBrick)>();
+        assert_eq!(q.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn layout_portals_link_in_pairs() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_game);
+        app.update();
+        let mut q = app.world_mut().query::<&Portal>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            0,
+            "The default grid has no portals"
+        );
+
+        let mut app = spawn_kinds(&[
+            BrickKind::Portal,
+            BrickKind::Plain,
+            BrickKind::Portal,
+            BrickKind::Portal,
+        ]);
+        let mut q = app.world_mut().query::<(Entity, &Portal)>();
+        let portals: Vec<_> = q.iter(app.world()).collect();
+        assert_eq!(portals.len(), 2, "The odd portal out stays unlinked");
+        let (a, portal_a) = portals[0];
+        let (b, portal_b) = portals[1];
+        assert_eq!(portal_a.link, b, "Portals should link to each other");
+        assert_eq!(portal_b.link, a, "Portals should link to each other");
+    }
+
+    #[test]
+    fn spawn_game_brick_health_follows_level() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<CurrentLevel>().number = 3;
+        app.add_systems(Startup, spawn_game);
+        app.update();
+
+        let mut q = app.world_mut().query::<(&Brick, &BrickHealth)>();
+        assert!(
+            q.iter(app.world()).all(|(_, health)| health.hits > 1),
+            "Level 3 bricks should need more than one hit"
+        );
+    }
+
     #[test]
     fn spawn_game_creates_three_walls() {
         let mut app = test_app();
@@ -272,6 +1157,202 @@ mod tests {
         assert_eq!(wall_colliders, 3, "All walls should have Collider");
     }
 
+    #[test]
+    fn collider_sizes_match_sprite_sizes() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_game);
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Sprite, &ColliderSize), With<Collider>>();
+        for (sprite, collider_size) in q.iter(app.world()) {
+            assert_eq!(sprite.custom_size, Some(collider_size.0));
+        }
+
+        // Top wall is wide and thin, side walls are thin and tall
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &ColliderSize), With<Wall>>();
+        for (transform, collider_size) in q.iter(app.world()) {
+            if transform.translation.y > 0.0 {
+                assert!(collider_size.0.x > collider_size.0.y);
+            } else {
+                assert!(collider_size.0.y > collider_size.0.x);
+            }
+        }
+    }
+
+    #[test]
+    fn boss_level_replaces_the_middle_cells_with_one_boss() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<GameConfig>().boss = true;
+        app.add_systems(Startup, spawn_game);
+        app.update();
+
+        let mut bosses = app
+            .world_mut()
+            .query_filtered::<(&BrickHealth, &ColliderSize), With<BossBrick>>();
+        let (health, size) = bosses.single(app.world()).unwrap();
+        assert_eq!(health.hits, BOSS_HEALTH);
+        assert!(size.0.x > BRICK_WIDTH * 3.0 && size.0.y > BRICK_HEIGHT);
+
+        let mut bricks = app.world_mut().query_filtered::<(), With<Brick>>();
+        assert_eq!(
+            bricks.iter(app.world()).count(),
+            BRICK_COLS * BRICK_ROWS - BOSS_COLS * BOSS_ROWS + 1
+        );
+    }
+
+    #[test]
+    fn boss_flankers_carry_scripted_triggers() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<GameConfig>().boss = true;
+        app.add_systems(Startup, spawn_game);
+        app.update();
+
+        let mut q = app.world_mut().query::<(&Transform, &OnDestroy)>();
+        let mut triggers: Vec<_> = q
+            .iter(app.world())
+            .map(|(transform, trigger)| (transform.translation.truncate(), trigger.clone()))
+            .collect();
+        assert_eq!(triggers.len(), 4);
+
+        // Top row first, then left to right
+        triggers.sort_by(|a, b| b.0.y.total_cmp(&a.0.y).then(a.0.x.total_cmp(&b.0.x)));
+        assert_eq!(
+            triggers[0].1,
+            OnDestroy::SpawnPowerUp(PowerUpType::WiderPaddle)
+        );
+        assert_eq!(triggers[1].1, OnDestroy::AddLife);
+        for (_, trigger) in &triggers[2..] {
+            assert!(matches!(trigger, OnDestroy::SpawnBricks(specs) if specs.len() == 1));
+        }
+    }
+
+    #[test]
+    fn thicker_walls_collide_where_thin_walls_would_not_reach() {
+        let ball_x = WINDOW_WIDTH / 2.0 - BALL_SIZE / 2.0 - 5.0;
+        let bounced = |thickness: f32| {
+            let mut app = test_app();
+            app.add_message::<BounceEvent>();
+            app.insert_resource(GameConfig {
+                wall_thickness: WallThickness(thickness),
+                ..default()
+            });
+            app.add_systems(Startup, spawn_game);
+            app.update();
+
+            let mut balls = app.world_mut().query_filtered::<Entity, With<Ball>>();
+            let ball = balls.single(app.world()).unwrap();
+            app.world_mut()
+                .entity_mut(ball)
+                .remove::<StuckToPaddle>()
+                .insert((
+                    Transform::from_xyz(ball_x, 0.0, 1.0),
+                    Velocity(Vec2::new(BALL_SPEED, 0.0)),
+                ));
+            app.world_mut()
+                .run_system_once(crate::collision::ball_collision_walls_and_paddle)
+                .unwrap();
+            app.world().get::<Velocity>(ball).unwrap().0.x < 0.0
+        };
+
+        assert!(
+            !bounced(WALL_THICKNESS),
+            "Default wall should not reach the ball"
+        );
+        assert!(
+            bounced(WALL_THICKNESS + 20.0),
+            "Thick wall should bounce the ball"
+        );
+    }
+
+    // --- spawn_ui ---
+
+    #[test]
+    fn score_legend_matches_row_points() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<GameConfig>().row_points = true;
+        app.add_systems(Startup, spawn_ui);
+        app.update();
+
+        let mut legend = app
+            .world_mut()
+            .query_filtered::<&Children, With<ScoreLegendUi>>();
+        let rows: Vec<Entity> = legend.single(app.world()).unwrap().to_vec();
+        let entries: Vec<(Color, u32)> = rows
+            .into_iter()
+            .map(|row| {
+                let parts = app.world().get::<Children>(row).unwrap();
+                let color = app.world().get::<BackgroundColor>(parts[0]).unwrap().0;
+                let text = &app.world().get::<Text>(parts[1]).unwrap().0;
+                (color, text.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(entries, score_legend_entries(true));
+        assert_eq!(entries[0], (BRICK_COLORS[0], BRICK_ROW_POINTS[0]));
+        assert_eq!(entries.len(), BRICK_COLORS.len());
+    }
+
+    // --- fit_camera_to_window ---
+
+    #[test]
+    fn camera_viewport_is_letterboxed_in_wide_window() {
+        let mut app = test_app();
+        app.add_systems(Update, fit_camera_to_window);
+
+        app.world_mut().spawn((
+            Window {
+                resolution: (1800, 600).into(),
+                ..default()
+            },
+            PrimaryWindow,
+        ));
+        app.world_mut().spawn((Camera2d, Camera::default()));
+
+        app.update();
+
+        let mut q = app.world_mut().query::<&Camera>();
+        let viewport = q
+            .iter(app.world())
+            .next()
+            .unwrap()
+            .viewport
+            .clone()
+            .unwrap();
+        assert_eq!(viewport.physical_position, UVec2::new(450, 0));
+        assert_eq!(viewport.physical_size, UVec2::new(900, 600));
+    }
+
+    // --- spawn_menu ---
+
+    #[test]
+    fn menu_overlays_have_bounded_width_and_side_padding() {
+        let mut app = test_app();
+        app.init_resource::<SavedRun>();
+        app.init_resource::<Settings>();
+        app.insert_resource(Leaderboard {
+            entries: vec![LeaderboardEntry {
+                initials: "ACE".to_string(),
+                score: 1200,
+                timestamp: 0,
+            }],
+        });
+        app.add_systems(Update, spawn_menu);
+        app.update();
+
+        let mut q = app.world_mut().query_filtered::<&Node, With<OverlayUi>>();
+        let nodes: Vec<&Node> = q.iter(app.world()).collect();
+        assert!(!nodes.is_empty());
+        for node in nodes {
+            assert_eq!(node.max_width, Val::Px(OVERLAY_MAX_WIDTH));
+            assert_eq!(node.padding.left, Val::Px(OVERLAY_SIDE_PADDING));
+            assert_eq!(node.padding.right, Val::Px(OVERLAY_SIDE_PADDING));
+        }
+    }
+
     // --- despawn_overlay ---
 
     #[test]
@@ -298,6 +1379,8 @@ mod tests {
     #[test]
     fn reset_ball_and_paddle_resets_positions() {
         let mut app = test_app();
+        app.init_resource::<GameRng>();
+        app.init_resource::<AimAngle>();
         app.add_systems(Update, reset_ball_and_paddle);
 
         // Spawn paddle at off-center position
@@ -307,9 +1390,8 @@ mod tests {
         // Spawn ball at off-center position with different velocity
         app.world_mut().spawn((
             Transform::from_xyz(100.0, 50.0, 1.0),
-            Ball {
-                velocity: Vec2::new(-100.0, -200.0),
-            },
+            Ball,
+            Velocity(Vec2::new(-100.0, -200.0)),
         ));
 
         app.update();
@@ -318,15 +1400,26 @@ mod tests {
         let paddle_x = q.iter(app.world()).next().unwrap().0.translation.x;
         assert!((paddle_x).abs() < 0.01, "Paddle x should reset to 0");
 
-        let mut q = app.world_mut().query::<(&Transform, &Ball)>();
-        let (ball_transform, ball) = q.iter(app.world()).next().unwrap();
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Ball>>();
+        let (ball_transform, ball_velocity) = q.iter(app.world()).next().unwrap();
         assert!(
             (ball_transform.translation.x).abs() < 0.01,
             "Ball x should reset to 0"
         );
         assert!(
-            ball.velocity.y > 0.0,
+            ball_velocity.0.y > 0.0,
             "Ball should be moving upward after reset"
         );
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(), (With<Ball>, With<StuckToPaddle>)>();
+        assert_eq!(
+            q.iter(app.world()).count(),
+            1,
+            "Ball should wait on the paddle after reset"
+        );
     }
 }
@@ -0,0 +1,778 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use crate::components::*;
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameStats>()
+            .init_resource::<HighScore>()
+            .init_resource::<Leaderboard>()
+            .init_resource::<ExitSavePaths>()
+            .init_resource::<InitialsEntry>()
+            .add_message::<BrickHitEvent>()
+            .add_message::<ScoreChanged>()
+            .add_systems(Startup, load_leaderboard)
+            .add_systems(OnEnter(GameState::Menu), spawn_session_summary)
+            .add_systems(OnEnter(GameState::EnterInitials), spawn_initials_prompt)
+            .add_systems(
+                OnExit(GameState::EnterInitials),
+                crate::setup::despawn_overlay,
+            )
+            .add_systems(
+                Update,
+                type_initials.run_if(in_state(GameState::EnterInitials)),
+            )
+            .add_systems(Last, log_session_on_exit)
+            .add_systems(OnExit(GameState::Menu), reset_stats)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (record_score, record_high_score).chain(),
+            )
+            .add_systems(
+                OnEnter(GameState::Victory),
+                (record_clear, record_score, record_high_score).chain(),
+            )
+            .add_systems(Update, record_brick_hits)
+            .add_systems(
+                Update,
+                (record_score, record_combo, record_lost_balls, tick_playtime)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Starts every run (new or continued) with empty statistics.
+fn reset_stats(mut stats: ResMut<GameStats>) {
+    *stats = GameStats::default();
+}
+
+/// Counts bricks destroyed during play; hits from the menu demo are ignored.
+fn record_brick_hits(
+    state: Res<State<GameState>>,
+    mut stats: ResMut<GameStats>,
+    mut brick_hits: MessageReader<BrickHitEvent>,
+) {
+    let playing = *state.get() == GameState::Playing;
+    for hit in brick_hits.read() {
+        if playing && hit.destroyed {
+            stats.bricks_destroyed += 1;
+        }
+    }
+}
+
+/// Mirrors the scoreboard into the stats and announces each change.
+fn record_score(
+    scoreboard: Res<Scoreboard>,
+    mut stats: ResMut<GameStats>,
+    mut score_changed: MessageWriter<ScoreChanged>,
+) {
+    if scoreboard.score == stats.score {
+        return;
+    }
+    stats.score = scoreboard.score;
+    score_changed.write(ScoreChanged);
+}
+
+/// Keeps the longest combo streak of the run.
+fn record_combo(combo: Res<Combo>, mut stats: ResMut<GameStats>) {
+    if combo.streak > stats.max_combo {
+        stats.max_combo = combo.streak;
+    }
+}
+
+/// Counts lives lost since the previous frame.
+fn record_lost_balls(
+    lives: Res<Lives>,
+    mut stats: ResMut<GameStats>,
+    mut previous: Local<Option<u32>>,
+) {
+    if let Some(previous) = *previous
+        && lives.count < previous
+    {
+        stats.balls_lost += previous - lives.count;
+    }
+    *previous = Some(lives.count);
+}
+
+/// Raises the session high score to the final score of the run.
+fn record_high_score(stats: Res<GameStats>, mut high_score: ResMut<HighScore>) {
+    if stats.score > high_score.score {
+        high_score.score = stats.score;
+    }
+}
+
+fn tick_playtime(time: Res<Time>, mut stats: ResMut<GameStats>) {
+    stats.playtime += time.delta_secs();
+}
+
+fn record_clear(mut stats: ResMut<GameStats>) {
+    stats.cleared = true;
+}
+
+/// One line of the session log: how the last run of a play session ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SessionEntry {
+    /// Seconds since the Unix epoch when the game was closed.
+    timestamp: u64,
+    score: u32,
+    level: u32,
+    /// Seconds spent in the Playing state.
+    playtime: f32,
+}
+
+impl SessionEntry {
+    /// Returns the entry as one log line, including its newline.
+    fn to_line(self) -> String {
+        format!(
+            "{} {} {} {:.1}\n",
+            self.timestamp, self.score, self.level, self.playtime
+        )
+    }
+
+    /// Parses a line written by `to_line`; anything else is `None`.
+    fn parse(line: &str) -> Option<SessionEntry> {
+        let mut fields = line.split_whitespace();
+        let entry = SessionEntry {
+            timestamp: fields.next()?.parse().ok()?,
+            score: fields.next()?.parse().ok()?,
+            level: fields.next()?.parse().ok()?,
+            playtime: fields.next()?.parse().ok()?,
+        };
+        fields.next().is_none().then_some(entry)
+    }
+}
+
+/// Totals across every session in the log.
+#[derive(Debug, Default, PartialEq)]
+struct SessionSummary {
+    games: u32,
+    best_score: u32,
+    average_score: f32,
+}
+
+/// Summarizes the session log, skipping lines that do not parse.
+fn summarize_sessions(contents: &str) -> SessionSummary {
+    let scores: Vec<u32> = contents
+        .lines()
+        .filter_map(SessionEntry::parse)
+        .map(|entry| entry.score)
+        .collect();
+    if scores.is_empty() {
+        return SessionSummary::default();
+    }
+    SessionSummary {
+        games: scores.len() as u32,
+        best_score: scores.iter().copied().max().unwrap_or(0),
+        average_score: scores.iter().map(|&score| score as f32).sum::<f32>() / scores.len() as f32,
+    }
+}
+
+/// Reads and summarizes the session log; a missing or unreadable log counts as empty.
+fn read_session_summary(path: &std::path::Path) -> SessionSummary {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => summarize_sessions(&contents),
+        Err(error) => {
+            if error.kind() != std::io::ErrorKind::NotFound {
+                warn!("Could not read session log: {error}");
+            }
+            SessionSummary::default()
+        }
+    }
+}
+
+/// Appends an entry to the session log, creating it if needed. The line goes out in a single
+/// append-mode write, so entries from games closing at the same time never interleave.
+fn append_session_entry(path: &std::path::Path, entry: SessionEntry) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    std::io::Write::write_all(&mut file, entry.to_line().as_bytes())
+}
+
+/// Returns the menu line totalling past sessions, or `None` before the first logged game.
+fn session_summary_text(summary: &SessionSummary, format: &ScoreFormat) -> Option<String> {
+    (summary.games > 0).then(|| {
+        format!(
+            "{} games played - best {} - average {}",
+            format.number(summary.games),
+            format.number(summary.best_score),
+            format.number(summary.average_score.round() as u32)
+        )
+    })
+}
+
+/// Shows the totals of past sessions on the menu.
+fn spawn_session_summary(
+    mut commands: Commands,
+    paths: Res<ExitSavePaths>,
+    settings: Res<Settings>,
+) {
+    let summary = read_session_summary(&paths.session_log);
+    let Some(text) = session_summary_text(&summary, &settings.score_format) else {
+        return;
+    };
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            bottom: Val::Px(90.0),
+            ..crate::setup::overlay_node()
+        },
+        SessionSummaryUi,
+        UI_Z_OVERLAY,
+        OverlayUi,
+    ));
+}
+
+/// Records how the session ended in the session log when the game closes, once per session
+/// however many exit requests arrive. A session that never played a run logs nothing, so it
+/// doesn't count as a scoreless game.
+fn log_session_on_exit(
+    mut app_exit: MessageReader<AppExit>,
+    mut logged: Local<bool>,
+    paths: Res<ExitSavePaths>,
+    stats: Res<GameStats>,
+    level: Res<CurrentLevel>,
+) {
+    if app_exit.read().next().is_none() || *logged {
+        return;
+    }
+    *logged = true;
+    if stats.playtime == 0.0 {
+        return;
+    }
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let entry = SessionEntry {
+        timestamp,
+        score: stats.score,
+        level: level.number,
+        playtime: stats.playtime,
+    };
+    if let Err(error) = append_session_entry(&paths.session_log, entry) {
+        warn!("Could not write session log: {error}");
+    }
+}
+
+/// Returns a leaderboard entry as one line of the leaderboard file, including its newline.
+fn leaderboard_line(entry: &LeaderboardEntry) -> String {
+    format!("{} {} {}\n", entry.score, entry.initials, entry.timestamp)
+}
+
+/// Parses a line written by `leaderboard_line`; anything else, including initials that could
+/// not have been typed, is `None`.
+fn parse_leaderboard_line(line: &str) -> Option<LeaderboardEntry> {
+    let mut fields = line.split_whitespace();
+    let entry = LeaderboardEntry {
+        score: fields.next()?.parse().ok()?,
+        initials: fields.next()?.to_string(),
+        timestamp: fields.next()?.parse().ok()?,
+    };
+    let valid_initials = entry.initials.len() <= LEADERBOARD_INITIALS_MAX
+        && entry
+            .initials
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    (valid_initials && fields.next().is_none()).then_some(entry)
+}
+
+/// Builds the leaderboard from the file contents, keeping the valid rows in score order.
+fn parse_leaderboard(contents: &str) -> Leaderboard {
+    let mut leaderboard = Leaderboard::default();
+    for entry in contents.lines().filter_map(parse_leaderboard_line) {
+        leaderboard.insert(entry);
+    }
+    leaderboard
+}
+
+/// Reads the leaderboard file; a missing or unreadable file counts as empty.
+fn read_leaderboard(path: &std::path::Path) -> Leaderboard {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => parse_leaderboard(&contents),
+        Err(error) => {
+            if error.kind() != std::io::ErrorKind::NotFound {
+                warn!("Could not read leaderboard: {error}");
+            }
+            Leaderboard::default()
+        }
+    }
+}
+
+/// Writes the leaderboard to `path`, including missing parent directories.
+fn write_leaderboard(path: &std::path::Path, leaderboard: &Leaderboard) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let contents: String = leaderboard.entries.iter().map(leaderboard_line).collect();
+    std::fs::write(path, contents)
+}
+
+/// Loads the saved leaderboard at startup, unless scores are kept for the session only, and
+/// carries its best score over as the high score.
+fn load_leaderboard(
+    settings: Res<Settings>,
+    mut leaderboard: ResMut<Leaderboard>,
+    mut high_score: ResMut<HighScore>,
+) {
+    if settings.session_scores {
+        return;
+    }
+    *leaderboard = read_leaderboard(std::path::Path::new(LEADERBOARD_PATH));
+    if let Some(best) = leaderboard.entries.first() {
+        high_score.score = high_score.score.max(best.score);
+    }
+}
+
+/// Formats seconds since the Unix epoch as a `YYYY-MM-DD` UTC date.
+fn format_date(timestamp: u64) -> String {
+    // Civil-from-days, counting in 400-year eras that start on March 1st
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Returns the leaderboard as one numbered line per entry.
+pub fn leaderboard_table(leaderboard: &Leaderboard, format: &ScoreFormat) -> String {
+    leaderboard
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(rank, entry)| {
+            format!(
+                "{:>2}. {:<3}  {:>11}  {}",
+                rank + 1,
+                entry.initials,
+                format.number(entry.score),
+                format_date(entry.timestamp)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the initials prompt, padding the initials still to type with underscores.
+fn initials_prompt_text(entry: &InitialsEntry, format: &ScoreFormat) -> String {
+    let typed = format!(
+        "{:_<width$}",
+        entry.initials,
+        width = LEADERBOARD_INITIALS_MAX
+    );
+    format!(
+        "NEW HIGH SCORE!\n{}\n\nEnter your initials: {typed}\nENTER to save, ESC to skip",
+        format.score(entry.score)
+    )
+}
+
+/// Shows the initials prompt above the current table.
+fn spawn_initials_prompt(
+    mut commands: Commands,
+    entry: Res<InitialsEntry>,
+    leaderboard: Res<Leaderboard>,
+    settings: Res<Settings>,
+) {
+    commands
+        .spawn((
+            Node {
+                top: Val::Percent(15.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(30.0),
+                ..crate::setup::overlay_node()
+            },
+            UI_Z_OVERLAY,
+            OverlayUi,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(initials_prompt_text(&entry, &settings.score_format)),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextLayout::new_with_justify(Justify::Center),
+                InitialsUi,
+            ));
+            parent.spawn((
+                Text::new(leaderboard_table(&leaderboard, &settings.score_format)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// Collects typed initials, adding the entry to the leaderboard on Enter (and saving it unless
+/// scores are kept for the session only); Escape skips the entry.
+fn type_initials(
+    mut keyboard_input: MessageReader<KeyboardInput>,
+    mut entry: ResMut<InitialsEntry>,
+    mut leaderboard: ResMut<Leaderboard>,
+    settings: Res<Settings>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut text_query: Query<&mut Text, With<InitialsUi>>,
+) {
+    for input in keyboard_input.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        match &input.logical_key {
+            Key::Enter if !entry.initials.is_empty() => {
+                let timestamp = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_secs());
+                leaderboard.insert(LeaderboardEntry {
+                    initials: entry.initials.clone(),
+                    score: entry.score,
+                    timestamp,
+                });
+                if !settings.session_scores
+                    && let Err(error) =
+                        write_leaderboard(std::path::Path::new(LEADERBOARD_PATH), &leaderboard)
+                {
+                    warn!("Could not save leaderboard: {error}");
+                }
+                next_state.set(GameState::Menu);
+                return;
+            }
+            Key::Escape => {
+                next_state.set(GameState::Menu);
+                return;
+            }
+            Key::Backspace => {
+                entry.initials.pop();
+            }
+            Key::Character(text) => {
+                for c in text.chars().filter(char::is_ascii_alphanumeric) {
+                    if entry.initials.len() < LEADERBOARD_INITIALS_MAX {
+                        entry.initials.push(c.to_ascii_uppercase());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if entry.is_changed() {
+        for mut text in &mut text_query {
+            **text = initials_prompt_text(&entry, &settings.score_format);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_state(GameState::Playing);
+        app.init_resource::<Scoreboard>();
+        app.init_resource::<Combo>();
+        app.init_resource::<Lives>();
+        app.init_resource::<CurrentLevel>();
+        // Keeps the tests off the real leaderboard file
+        app.insert_resource(Settings {
+            session_scores: true,
+            ..default()
+        });
+        app.add_plugins(StatsPlugin);
+        app
+    }
+
+    // --- record_brick_hits ---
+
+    #[test]
+    fn only_destroyed_bricks_are_counted() {
+        let mut app = test_app();
+        for destroyed in [true, false, true] {
+            app.world_mut().write_message(BrickHitEvent {
+                destroyed,
+                color: Color::WHITE,
+            });
+        }
+        app.update();
+
+        assert_eq!(app.world().resource::<GameStats>().bricks_destroyed, 2);
+    }
+
+    // --- record_lost_balls ---
+
+    #[test]
+    fn losing_a_life_counts_a_lost_ball() {
+        let mut app = test_app();
+        app.update();
+
+        app.world_mut().resource_mut::<Lives>().count -= 1;
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<GameStats>().balls_lost, 1);
+    }
+
+    // --- session log ---
+
+    fn entry(timestamp: u64, score: u32) -> SessionEntry {
+        SessionEntry {
+            timestamp,
+            score,
+            level: 2,
+            playtime: 95.5,
+        }
+    }
+
+    #[test]
+    fn appended_entries_are_read_back_in_order() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-sessions")
+            .join("sessions.log");
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(read_session_summary(&path), SessionSummary::default());
+        assert!(append_session_entry(&path, entry(100, 400)).is_ok());
+        assert!(append_session_entry(&path, entry(200, 900)).is_ok());
+
+        let contents = std::fs::read_to_string(&path).unwrap_or_default();
+        let entries: Vec<_> = contents.lines().filter_map(SessionEntry::parse).collect();
+        assert_eq!(entries, vec![entry(100, 400), entry(200, 900)]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn exit_appends_one_session_entry() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-exit-sessions")
+            .join("sessions.log");
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = test_app();
+        app.add_message::<AppExit>();
+        app.insert_resource(ExitSavePaths {
+            run: path.with_file_name("run.txt"),
+            session_log: path.clone(),
+        });
+        app.world_mut().resource_mut::<Scoreboard>().score = 700;
+        app.update();
+        app.world_mut().resource_mut::<GameStats>().playtime += 30.0;
+        assert!(!path.exists(), "Nothing should be logged before exit");
+
+        for _ in 0..2 {
+            app.world_mut().write_message(AppExit::Success);
+            app.update();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap_or_default();
+        let entries: Vec<_> = contents.lines().filter_map(SessionEntry::parse).collect();
+        assert_eq!(
+            entries.len(),
+            1,
+            "Exit should log exactly once:\n{contents}"
+        );
+        assert_eq!(entries[0].score, 700);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn exit_from_the_menu_without_playing_logs_nothing() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-menu-exit-sessions")
+            .join("sessions.log");
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = test_app();
+        app.add_message::<AppExit>();
+        app.insert_resource(ExitSavePaths {
+            run: path.with_file_name("run.txt"),
+            session_log: path.clone(),
+        });
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Menu);
+        app.update();
+
+        app.world_mut().write_message(AppExit::Success);
+        app.update();
+        assert!(
+            !path.exists(),
+            "A session without a run should not be logged"
+        );
+    }
+
+    #[test]
+    fn summary_totals_games_and_skips_bad_lines() {
+        let contents = [
+            entry(1, 300).to_line(),
+            "garbage line\n".to_string(),
+            entry(2, 1200).to_line(),
+            entry(3, 0).to_line(),
+        ]
+        .concat();
+
+        assert_eq!(
+            summarize_sessions(&contents),
+            SessionSummary {
+                games: 3,
+                best_score: 1200,
+                average_score: 500.0,
+            }
+        );
+        assert_eq!(summarize_sessions(""), SessionSummary::default());
+    }
+
+    #[test]
+    fn menu_shows_the_session_summary_once_games_are_logged() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-menu-sessions")
+            .join("sessions.log");
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = test_app();
+        app.insert_resource(ExitSavePaths {
+            run: path.with_file_name("run.txt"),
+            session_log: path.clone(),
+        });
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Menu);
+        app.update();
+        let mut summaries = app
+            .world_mut()
+            .query_filtered::<&Text, With<SessionSummaryUi>>();
+        assert_eq!(
+            summaries.iter(app.world()).count(),
+            0,
+            "An empty log has nothing to summarize"
+        );
+
+        append_session_entry(&path, entry(1, 300)).unwrap();
+        append_session_entry(&path, entry(2, 1200)).unwrap();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Ready);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Menu);
+        app.update();
+
+        let texts: Vec<String> = summaries
+            .iter(app.world())
+            .map(|text| text.0.clone())
+            .collect();
+        assert_eq!(texts, vec!["2 games played - best 1,200 - average 750"]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    // --- leaderboard ---
+
+    fn ranked(initials: &str, score: u32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            initials: initials.to_string(),
+            score,
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn inserting_into_a_full_table_evicts_the_lowest_score() {
+        let mut leaderboard = Leaderboard::default();
+        for score in (1..=LEADERBOARD_SIZE as u32).map(|n| n * 100) {
+            assert!(leaderboard.qualifies(score));
+            leaderboard.insert(ranked("AAA", score));
+        }
+        assert!(!leaderboard.qualifies(100), "Ties with the lowest stay out");
+
+        assert_eq!(leaderboard.insert(ranked("NEW", 550)), Some(5));
+        let scores: Vec<u32> = leaderboard.entries.iter().map(|e| e.score).collect();
+        assert_eq!(
+            scores,
+            vec![1000, 900, 800, 700, 600, 550, 500, 400, 300, 200]
+        );
+        assert_eq!(leaderboard.insert(ranked("LOW", 50)), None);
+        assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+    }
+
+    #[test]
+    fn equal_scores_rank_below_earlier_entries() {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.insert(ranked("OLD", 300));
+        assert_eq!(leaderboard.insert(ranked("NEW", 300)), Some(1));
+        assert!(!leaderboard.qualifies(0), "Empty runs never place");
+    }
+
+    #[test]
+    fn partially_corrupt_leaderboard_keeps_the_valid_rows() {
+        let contents = [
+            leaderboard_line(&ranked("ABC", 200)),
+            "not a row\n".to_string(),
+            "300 toolong 5\n".to_string(),
+            "-5 XYZ 5\n".to_string(),
+            "400 XYZ 5 extra\n".to_string(),
+            leaderboard_line(&ranked("Q7", 900)),
+        ]
+        .concat();
+
+        let leaderboard = parse_leaderboard(&contents);
+        assert_eq!(
+            leaderboard.entries,
+            vec![ranked("Q7", 900), ranked("ABC", 200)]
+        );
+        assert_eq!(parse_leaderboard(""), Leaderboard::default());
+    }
+
+    #[test]
+    fn saved_leaderboard_is_read_back_in_order() {
+        let path = std::env::temp_dir()
+            .join("breakout-rust-test-leaderboard")
+            .join("leaderboard.txt");
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.insert(ranked("BBB", 100));
+        leaderboard.insert(ranked("AAA", 700));
+
+        assert!(write_leaderboard(&path, &leaderboard).is_ok());
+        assert_eq!(read_leaderboard(&path), leaderboard);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn dates_format_as_utc_calendar_days() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_700_000_000), "2023-11-14");
+    }
+}